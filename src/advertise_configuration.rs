@@ -0,0 +1,226 @@
+use proc_macro2::{Ident, Span};
+use quote::quote;
+use syn::{
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    spanned::Spanned,
+    Error, Expr, FieldValue, Member, Token, Visibility,
+};
+
+use crate::{
+    diagnostic::{emit, emit_err, once_per_crate, unknown_field, SeenFields},
+    literal::{expected_one_of, parse_byte_array, parse_ident, parse_int_in},
+    options::{Options, Sdk},
+    units::parse_duration,
+};
+
+/// Advertising intervals are given in 0.625 ms slots.
+const SLOT_US: u64 = 625;
+/// Advertising interval limits of the BLE specification, 20 ms to 10.24 s.
+const INTERVAL_MIN: u64 = 0x20;
+const INTERVAL_MAX: u64 = 0x4000;
+
+/// Primary advertising channels, enabled by bits 0 to 2 of the channel map.
+const ADV_CHANNELS: std::ops::RangeInclusive<u8> = 37..=39;
+
+const FIELDS: [&str; 7] = [
+    "mode",
+    "interval_min",
+    "interval_max",
+    "channel_map",
+    "filter_policy",
+    "peer_addr",
+    "peer_addr_type",
+];
+const MODES: [&str; 4] = [
+    "NonDiscoverable",
+    "GeneralDiscoverable",
+    "LimitedDiscoverable",
+    "Broadcaster",
+];
+const FILTER_POLICIES: [&str; 4] = [
+    "AllowScanAnyConAny",
+    "AllowScanWlstConAny",
+    "AllowScanAnyConWlst",
+    "AllowScanWlstConWlst",
+];
+const PEER_ADDR_TYPES: [&str; 2] = ["Public", "Random"];
+
+#[derive(Debug)]
+pub struct AdvertiseConfiguration {
+    sdk: Sdk,
+    vis: Visibility,
+    mode: proc_macro2::TokenStream,
+    interval_min: u16,
+    interval_max: u16,
+    channel_map: u8,
+    filter_policy: proc_macro2::TokenStream,
+    peer_addr: [u8; 6],
+    peer_addr_type: proc_macro2::TokenStream,
+    interval_min_span: Span,
+}
+
+impl Parse for AdvertiseConfiguration {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let Options { sdk, vis } = Options::parse(input)?;
+        let fields: Punctuated<FieldValue, Token![,]> =
+            input.parse_terminated(FieldValue::parse)?;
+
+        // Defaults of the SDK's user_config.h templates: 687.5 ms on all channels.
+        let mut adv_conf = Self {
+            mode: quote!(#sdk::ble_stack::host::gap::gapm::task::GAP_GEN_DISCOVERABLE),
+            interval_min: 1100,
+            interval_max: 1100,
+            channel_map: 0x07,
+            filter_policy: quote!(
+                #sdk::platform::core_modules::common::ADV_ALLOW_SCAN_ANY_CON_ANY
+            ),
+            peer_addr: [0; 6],
+            peer_addr_type: quote!(#sdk::platform::core_modules::common::ADDR_PUBLIC),
+            interval_min_span: Span::call_site(),
+            sdk,
+            vis,
+        };
+
+        let mut seen = SeenFields::default();
+
+        for field in fields {
+            let key = match field.member {
+                Member::Named(name) => name,
+                Member::Unnamed(unnamed) => {
+                    emit(Error::new(unnamed.span(), "unexpected unnamed field"));
+                    continue;
+                }
+            };
+            if emit_err(seen.insert(&key)).is_some() {
+                emit_err(adv_conf.parse_field(&key, field.expr));
+            }
+        }
+
+        if adv_conf.interval_min > adv_conf.interval_max {
+            emit(Error::new(
+                adv_conf.interval_min_span,
+                "`interval_min` must not exceed `interval_max`",
+            ));
+        }
+
+        Ok(adv_conf)
+    }
+}
+
+fn parse_interval(expr: &Expr) -> syn::Result<u16> {
+    Ok(parse_duration(expr, SLOT_US, INTERVAL_MIN..=INTERVAL_MAX)? as u16)
+}
+
+/// Channel map as bit mask or list of channels, e.g. `[37, 38, 39]`.
+fn parse_channel_map(expr: &Expr) -> syn::Result<u8> {
+    let channel_map = match expr {
+        Expr::Array(array) => {
+            let mut channel_map = 0;
+            for elem in &array.elems {
+                let bit = 1 << (parse_int_in(elem, ADV_CHANNELS)? - ADV_CHANNELS.start());
+                if channel_map & bit != 0 {
+                    return Err(Error::new(elem.span(), "duplicate channel"));
+                }
+                channel_map |= bit;
+            }
+            channel_map
+        }
+        _ => parse_int_in(expr, 0..=0x07)?,
+    };
+
+    if channel_map == 0 {
+        return Err(Error::new(
+            expr.span(),
+            "at least one advertising channel must be enabled",
+        ));
+    }
+
+    Ok(channel_map)
+}
+
+impl AdvertiseConfiguration {
+    fn parse_field(&mut self, key: &Ident, value: Expr) -> syn::Result<()> {
+        let sdk = &self.sdk;
+
+        match key.to_string().as_str() {
+            "mode" => {
+                let mode = match parse_ident(&value)?.as_str() {
+                    "NonDiscoverable" => quote!(GAP_NON_DISCOVERABLE),
+                    "GeneralDiscoverable" => quote!(GAP_GEN_DISCOVERABLE),
+                    "LimitedDiscoverable" => quote!(GAP_LIM_DISCOVERABLE),
+                    "Broadcaster" => quote!(GAP_BROADCASTER_MODE),
+                    _ => return Err(expected_one_of(&value, &MODES)),
+                };
+                self.mode = quote!(#sdk::ble_stack::host::gap::gapm::task::#mode);
+            }
+            "interval_min" => {
+                self.interval_min = parse_interval(&value)?;
+                self.interval_min_span = value.span();
+            }
+            "interval_max" => {
+                self.interval_max = parse_interval(&value)?;
+            }
+            "channel_map" => {
+                self.channel_map = parse_channel_map(&value)?;
+            }
+            "filter_policy" => {
+                let filter_policy = match parse_ident(&value)?.as_str() {
+                    "AllowScanAnyConAny" => quote!(ADV_ALLOW_SCAN_ANY_CON_ANY),
+                    "AllowScanWlstConAny" => quote!(ADV_ALLOW_SCAN_WLST_CON_ANY),
+                    "AllowScanAnyConWlst" => quote!(ADV_ALLOW_SCAN_ANY_CON_WLST),
+                    "AllowScanWlstConWlst" => quote!(ADV_ALLOW_SCAN_WLST_CON_WLST),
+                    _ => return Err(expected_one_of(&value, &FILTER_POLICIES)),
+                };
+                self.filter_policy = quote!(#sdk::platform::core_modules::common::#filter_policy);
+            }
+            "peer_addr" => {
+                self.peer_addr = parse_byte_array(&value)?;
+            }
+            "peer_addr_type" => {
+                let peer_addr_type = match parse_ident(&value)?.as_str() {
+                    "Public" => quote!(ADDR_PUBLIC),
+                    "Random" => quote!(ADDR_RAND),
+                    _ => return Err(expected_one_of(&value, &PEER_ADDR_TYPES)),
+                };
+                self.peer_addr_type = quote!(#sdk::platform::core_modules::common::#peer_addr_type);
+            }
+            _ => return Err(unknown_field(key, &FIELDS)),
+        }
+
+        Ok(())
+    }
+
+    pub fn generate(&self) -> proc_macro2::TokenStream {
+        let Self {
+            sdk,
+            vis,
+            mode,
+            interval_min,
+            interval_max,
+            channel_map,
+            filter_policy,
+            peer_addr,
+            peer_addr_type,
+            ..
+        } = self;
+
+        let guard = once_per_crate("user_adv_conf");
+
+        quote!(
+            #guard
+
+            #[export_name = "user_adv_conf"]
+            #vis static USER_ADV_CONF: #sdk::app_modules::AdvertiseConfiguration =
+                #sdk::app_modules::AdvertiseConfiguration {
+                intv_min: #interval_min,
+                intv_max: #interval_max,
+                channel_map: #channel_map,
+                mode: #mode as _,
+                adv_filt_policy: #filter_policy as _,
+                peer_addr: [#(#peer_addr),*],
+                peer_addr_type: #peer_addr_type as _,
+            };
+        )
+    }
+}
@@ -0,0 +1,433 @@
+use proc_macro2::{Ident, Span};
+use quote::{format_ident, quote, quote_spanned};
+use syn::{
+    braced,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    spanned::Spanned,
+    Error, Expr, FieldValue, Lit, Member, Path, Token, Visibility,
+};
+
+use crate::{
+    diagnostic::{emit, emit_err, once_per_crate, unknown_field, SeenFields},
+    literal::{parse_int, parse_str},
+    options::Options,
+};
+
+const FIELDS: [&str; 2] = ["advertise_data", "scan_response_data"];
+const AD_FIELDS: [&str; 8] = [
+    "complete_name",
+    "short_name",
+    "uuid16_list",
+    "uuid128_list",
+    "manufacturer_data",
+    "tx_power",
+    "appearance",
+    "raw",
+];
+
+/// Maximum length of legacy advertising and scan response data.
+const LEGACY_ADV_DATA_LEN_MAX: usize = 31;
+
+/// Maximum length of the advertising data, which the SDK prefixes with a 3 byte flags AD
+/// structure (`APP_ADV_DATA_MAX_SIZE`).
+const ADV_DATA_LEN_MAX: usize = LEGACY_ADV_DATA_LEN_MAX - 3;
+
+/// AD types from the Bluetooth SIG assigned numbers.
+pub const AD_TYPE_COMPLETE_LIST_16BIT_SERVICE_IDS: u8 = 0x03;
+pub const AD_TYPE_COMPLETE_LIST_128BIT_SERVICE_IDS: u8 = 0x07;
+const AD_TYPE_SHORTENED_NAME: u8 = 0x08;
+const AD_TYPE_COMPLETE_NAME: u8 = 0x09;
+const AD_TYPE_TRANSMIT_POWER: u8 = 0x0A;
+const AD_TYPE_APPEARANCE: u8 = 0x19;
+const AD_TYPE_MANUFACTURER_SPECIFIC_DATA: u8 = 0xFF;
+
+#[derive(Debug)]
+enum AdElement {
+    /// A single AD structure: length, AD type and data.
+    Data {
+        ad_type: u8,
+        data: Vec<u8>,
+        span: Span,
+    },
+    /// Constant byte array of complete AD structures, e.g. `ADV_SERVICE_UUIDS`.
+    Raw(Path),
+}
+
+/// Consecutive bytes of advertising data, either known to the macro or from a constant.
+enum AdPart {
+    Bytes(Vec<u8>),
+    Raw(Path),
+}
+
+/// Braced list of AD elements, e.g. `{ complete_name: "Tag", tx_power: 0 }`.
+#[derive(Debug, Default)]
+struct AdData {
+    elements: Vec<AdElement>,
+}
+
+impl Parse for AdData {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        braced!(content in input);
+        let fields: Punctuated<FieldValue, Token![,]> =
+            content.parse_terminated(FieldValue::parse)?;
+
+        let mut ad_data = Self::default();
+        let mut seen = SeenFields::default();
+
+        for field in fields {
+            let key = match field.member {
+                Member::Named(name) => name,
+                Member::Unnamed(unnamed) => {
+                    emit(Error::new(unnamed.span(), "unexpected unnamed field"));
+                    continue;
+                }
+            };
+            if emit_err(seen.insert(&key)).is_some() {
+                emit_err(ad_data.parse_field(&key, field.expr));
+            }
+        }
+
+        Ok(ad_data)
+    }
+}
+
+impl AdData {
+    fn parse_field(&mut self, key: &Ident, value: Expr) -> syn::Result<()> {
+        let span = value.span();
+
+        if key == "raw" {
+            let raw: Vec<Path> = match value {
+                Expr::Path(path) => vec![path.path],
+                Expr::Array(array) => array
+                    .elems
+                    .into_iter()
+                    .map(|elem| match elem {
+                        Expr::Path(path) => Ok(path.path),
+                        _ => Err(Error::new(elem.span(), "expected path")),
+                    })
+                    .collect::<syn::Result<_>>()?,
+                _ => return Err(Error::new(span, "expected path or list of paths")),
+            };
+            self.elements.extend(raw.into_iter().map(AdElement::Raw));
+            return Ok(());
+        }
+
+        let (ad_type, data) = match key.to_string().as_str() {
+            "flags" => {
+                return Err(Error::new(
+                    key.span(),
+                    "the SDK adds the flags to the advertising data itself",
+                ))
+            }
+            "complete_name" => (AD_TYPE_COMPLETE_NAME, parse_str(&value)?.into_bytes()),
+            "short_name" => (AD_TYPE_SHORTENED_NAME, parse_str(&value)?.into_bytes()),
+            "uuid16_list" => {
+                let mut data = Vec::new();
+                for uuid in parse_list(&value)? {
+                    data.extend(parse_int::<u16>(uuid)?.to_le_bytes());
+                }
+                (AD_TYPE_COMPLETE_LIST_16BIT_SERVICE_IDS, data)
+            }
+            "uuid128_list" => {
+                let mut data = Vec::new();
+                for uuid in parse_list(&value)? {
+                    data.extend(parse_uuid128(uuid)?.to_le_bytes());
+                }
+                (AD_TYPE_COMPLETE_LIST_128BIT_SERVICE_IDS, data)
+            }
+            "manufacturer_data" => match &value {
+                Expr::Tuple(tuple) if tuple.elems.len() == 2 => {
+                    let company_id = parse_int::<u16>(&tuple.elems[0])?;
+                    let mut data = company_id.to_le_bytes().to_vec();
+                    data.extend(parse_bytes(&tuple.elems[1])?);
+                    (AD_TYPE_MANUFACTURER_SPECIFIC_DATA, data)
+                }
+                _ => return Err(Error::new(span, "expected `(company_id, data)`")),
+            },
+            "tx_power" => (AD_TYPE_TRANSMIT_POWER, vec![parse_int::<i8>(&value)? as u8]),
+            "appearance" => (
+                AD_TYPE_APPEARANCE,
+                parse_int::<u16>(&value)?.to_le_bytes().to_vec(),
+            ),
+            _ => return Err(unknown_field(key, &AD_FIELDS)),
+        };
+
+        self.elements.push(AdElement::Data {
+            ad_type,
+            data,
+            span,
+        });
+
+        Ok(())
+    }
+
+    /// Splits the data into parts, checking the length of what is known to the macro against
+    /// `limit`.
+    fn to_parts(&self, limit: &DataLimit) -> syn::Result<Vec<AdPart>> {
+        let mut parts = Vec::new();
+        let mut bytes = Vec::new();
+        let mut len = 0;
+
+        for element in &self.elements {
+            match element {
+                AdElement::Data {
+                    ad_type,
+                    data,
+                    span,
+                } => {
+                    len += data.len() + 2;
+                    if len > limit.max_len {
+                        return Err(Error::new(
+                            *span,
+                            format!("{} ({len} bytes)", limit.message()),
+                        ));
+                    }
+
+                    // Fits into the length byte, as the total is within the limit.
+                    bytes.push(data.len() as u8 + 1);
+                    bytes.push(*ad_type);
+                    bytes.extend(data);
+                }
+                AdElement::Raw(path) => {
+                    if !bytes.is_empty() {
+                        parts.push(AdPart::Bytes(std::mem::take(&mut bytes)));
+                    }
+                    parts.push(AdPart::Raw(path.clone()));
+                }
+            }
+        }
+        if !bytes.is_empty() {
+            parts.push(AdPart::Bytes(bytes));
+        }
+
+        Ok(parts)
+    }
+}
+
+/// Room for the advertising or scan response data.
+struct DataLimit {
+    description: &'static str,
+    max_len: usize,
+    reason: &'static str,
+}
+
+impl DataLimit {
+    const ADVERTISE_DATA: Self = Self {
+        description: "advertising data",
+        max_len: ADV_DATA_LEN_MAX,
+        reason: "left next to the flags added by the SDK",
+    };
+    const SCAN_RESPONSE_DATA: Self = Self {
+        description: "scan response data",
+        max_len: LEGACY_ADV_DATA_LEN_MAX,
+        reason: "of legacy advertising",
+    };
+
+    fn message(&self) -> String {
+        format!(
+            "{} exceeds the {} byte limit {}",
+            self.description, self.max_len, self.reason
+        )
+    }
+}
+
+fn parse_list(expr: &Expr) -> syn::Result<impl Iterator<Item = &Expr>> {
+    match expr {
+        Expr::Array(array) if !array.elems.is_empty() => Ok(array.elems.iter()),
+        _ => Err(Error::new(expr.span(), "expected non-empty list")),
+    }
+}
+
+/// Byte string literal or list of byte literals.
+fn parse_bytes(expr: &Expr) -> syn::Result<Vec<u8>> {
+    match expr {
+        Expr::Lit(literal) => match &literal.lit {
+            Lit::ByteStr(lit_byte_str) => Ok(lit_byte_str.value()),
+            _ => Err(Error::new(
+                expr.span(),
+                "expected byte string or list of bytes",
+            )),
+        },
+        Expr::Array(array) => array.elems.iter().map(parse_int::<u8>).collect(),
+        _ => Err(Error::new(
+            expr.span(),
+            "expected byte string or list of bytes",
+        )),
+    }
+}
+
+/// 128-bit UUID, either as integer or as `"xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx"` string literal.
+fn parse_uuid128(expr: &Expr) -> syn::Result<u128> {
+    if let Expr::Lit(literal) = expr {
+        if let Lit::Str(lit_str) = &literal.lit {
+            let uuid = lit_str.value();
+            let hex: String = uuid.chars().filter(|c| *c != '-').collect();
+
+            let groups: Vec<usize> = uuid.split('-').map(str::len).collect();
+            if groups != [8, 4, 4, 4, 12] {
+                return Err(Error::new(lit_str.span(), "malformed UUID"));
+            }
+
+            return u128::from_str_radix(&hex, 16)
+                .map_err(|_| Error::new(lit_str.span(), "malformed UUID"));
+        }
+    }
+
+    parse_int(expr)
+}
+
+#[derive(Debug)]
+pub struct AdvertisingConfiguration {
+    vis: Visibility,
+    advertise_data: AdData,
+    scan_response_data: AdData,
+}
+
+impl Parse for AdvertisingConfiguration {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        // `crate` is accepted like by the other macros, but the advertising data does not refer
+        // to the SDK.
+        let Options { vis, .. } = Options::parse(input)?;
+
+        let mut advertise_data = None;
+        let mut scan_response_data = None;
+
+        while !input.is_empty() {
+            let key: Ident = input.parse()?;
+            let _colon_token: Token![:] = input.parse()?;
+
+            let target = match key.to_string().as_str() {
+                "advertise_data" => &mut advertise_data,
+                "scan_response_data" => &mut scan_response_data,
+                _ => return Err(unknown_field(&key, &FIELDS)),
+            };
+            if target.is_some() {
+                emit(Error::new(key.span(), format!("duplicate field `{key}`")));
+            }
+            *target = Some(input.parse()?);
+
+            if input.is_empty() {
+                break;
+            }
+            let _comma_token: Token![,] = input.parse()?;
+        }
+
+        Ok(Self {
+            vis,
+            advertise_data: advertise_data.unwrap_or_default(),
+            scan_response_data: scan_response_data.unwrap_or_default(),
+        })
+    }
+}
+
+impl AdvertisingConfiguration {
+    pub fn generate(&self) -> syn::Result<proc_macro2::TokenStream> {
+        let advertise_data = generate_data(
+            &self.vis,
+            "USER_ADVERTISE_DATA",
+            &DataLimit::ADVERTISE_DATA,
+            self.advertise_data.to_parts(&DataLimit::ADVERTISE_DATA)?,
+        );
+        let scan_response_data = generate_data(
+            &self.vis,
+            "USER_ADVERTISE_SCAN_RESPONSE_DATA",
+            &DataLimit::SCAN_RESPONSE_DATA,
+            self.scan_response_data
+                .to_parts(&DataLimit::SCAN_RESPONSE_DATA)?,
+        );
+
+        let guard = once_per_crate("user_advertise_data");
+
+        Ok(quote!(
+            #guard
+
+            #advertise_data
+            #scan_response_data
+        ))
+    }
+}
+
+fn generate_data(
+    vis: &Visibility,
+    name: &str,
+    limit: &DataLimit,
+    parts: Vec<AdPart>,
+) -> proc_macro2::TokenStream {
+    let data_name = format_ident!("{}", name);
+    let len_name = format_ident!("{}_LEN", name);
+
+    let bytes = match parts.as_slice() {
+        [] => Some(Vec::new()),
+        [AdPart::Bytes(bytes)] => Some(bytes.clone()),
+        _ => None,
+    };
+
+    if let Some(bytes) = bytes {
+        let len = bytes.len();
+        let len_u8 = len as u8;
+
+        return quote!(
+            #[no_mangle]
+            #vis static #data_name: [u8; #len] = [#(#bytes),*];
+
+            #[no_mangle]
+            #vis static #len_name: u8 = #len_u8;
+        );
+    }
+
+    // Constants included with `raw` are only known to the compiler, so the data is concatenated
+    // and its length checked in const context.
+    let mut part_lens = Vec::new();
+    let mut part_slices = Vec::new();
+    let mut len_checks = Vec::new();
+    let size_name = format_ident!("__{}_SIZE", name);
+    let max_len = limit.max_len;
+    let message = limit.message();
+
+    for part in &parts {
+        match part {
+            AdPart::Bytes(bytes) => {
+                let len = bytes.len();
+                part_lens.push(quote!(#len));
+                part_slices.push(quote!(&[#(#bytes),*]));
+            }
+            AdPart::Raw(path) => {
+                part_lens.push(quote!(#path.len()));
+                part_slices.push(quote!(&#path));
+                len_checks.push(quote_spanned!(path.span()=>
+                    const _: () = assert!(#size_name <= #max_len, #message);
+                ));
+            }
+        }
+    }
+
+    quote!(
+        const #size_name: usize = #(#part_lens)+*;
+        #(#len_checks)*
+
+        #[no_mangle]
+        #vis static #data_name: [u8; #size_name] = {
+            const PARTS: &[&[u8]] = &[#(#part_slices),*];
+
+            let mut data = [0; #size_name];
+            let mut offset = 0;
+            let mut part = 0;
+            while part < PARTS.len() {
+                let mut idx = 0;
+                while idx < PARTS[part].len() {
+                    data[offset] = PARTS[part][idx];
+                    offset += 1;
+                    idx += 1;
+                }
+                part += 1;
+            }
+
+            data
+        };
+
+        #[no_mangle]
+        #vis static #len_name: u8 = #size_name as u8;
+    )
+}
@@ -0,0 +1,317 @@
+use proc_macro2::Ident;
+use quote::quote;
+use syn::{
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    spanned::Spanned,
+    Error, Expr, FieldValue, Member, Path, Token, Visibility,
+};
+
+use crate::{
+    diagnostic::{emit, emit_err, once_per_crate, unknown_field, SeenFields},
+    literal::parse_bool,
+    options::{Options, Sdk},
+    profile::{generate_create_db, generate_process_handler, ProcessHandlerEntry},
+};
+
+/// Maximum number of battery service instances supported by the `bass` task.
+const BASS_NB_BAS_INSTANCES_MAX: usize = 2;
+
+/// Bluetooth SIG assigned numbers used in the characteristic presentation format descriptor,
+/// which the specification requires as soon as more than one instance is exposed.
+const PRF_FORMAT_UINT8: u8 = 0x04;
+const UNIT_PERCENTAGE: u16 = 0x27AD;
+const NAMESPACE_BLUETOOTH_SIG: u8 = 0x01;
+
+const FIELDS: [&str; 3] = ["battery_level", "notification", "on_notification_config"];
+
+#[derive(Debug)]
+pub struct BatteryServiceConfiguration {
+    sdk: Sdk,
+    vis: Visibility,
+    battery_level: Vec<Path>,
+    notification: bool,
+    on_notification_config: Option<Path>,
+}
+
+impl Parse for BatteryServiceConfiguration {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let span = input.span();
+        let Options { sdk, vis } = Options::parse(input)?;
+        let fields: Punctuated<FieldValue, Token![,]> =
+            input.parse_terminated(FieldValue::parse)?;
+
+        let mut app_bass_values = Self {
+            sdk,
+            vis,
+            battery_level: Vec::new(),
+            notification: false,
+            on_notification_config: None,
+        };
+
+        let mut seen = SeenFields::default();
+
+        for field in fields {
+            let key = match field.member {
+                Member::Named(name) => name,
+                Member::Unnamed(unnamed) => {
+                    emit(Error::new(unnamed.span(), "unexpected unnamed field"));
+                    continue;
+                }
+            };
+            if emit_err(seen.insert(&key)).is_some() {
+                emit_err(app_bass_values.parse_field(&key, field.expr));
+            }
+        }
+
+        // A `battery_level` given with an invalid value has been reported already.
+        if !seen.contains("battery_level") {
+            emit(Error::new(span, "missing `battery_level`"));
+        }
+
+        if let Some(on_notification_config) = &app_bass_values.on_notification_config {
+            if !app_bass_values.notification {
+                emit(Error::new(
+                    on_notification_config.span(),
+                    "`on_notification_config` requires `notification: true`",
+                ));
+            }
+        }
+
+        Ok(app_bass_values)
+    }
+}
+
+impl BatteryServiceConfiguration {
+    fn parse_field(&mut self, key: &Ident, value: Expr) -> syn::Result<()> {
+        match key.to_string().as_str() {
+            "battery_level" => match value {
+                Expr::Path(path) => {
+                    self.battery_level = vec![path.path];
+                }
+                Expr::Array(array) => {
+                    if array.elems.is_empty() || array.elems.len() > BASS_NB_BAS_INSTANCES_MAX {
+                        return Err(Error::new(
+                            array.span(),
+                            format!(
+                                "expected between 1 and {BASS_NB_BAS_INSTANCES_MAX} battery level sources"
+                            ),
+                        ));
+                    }
+
+                    for elem in array.elems {
+                        match elem {
+                            Expr::Path(path) => self.battery_level.push(path.path),
+                            _ => return Err(Error::new(elem.span(), "expected path")),
+                        }
+                    }
+                }
+                _ => {
+                    return Err(Error::new(value.span(), "expected path or list of paths"));
+                }
+            },
+            "notification" => {
+                self.notification = parse_bool(&value)?;
+            }
+            "on_notification_config" => match value {
+                Expr::Path(path) => {
+                    self.on_notification_config = Some(path.path);
+                }
+                _ => return Err(Error::new(value.span(), "expected path")),
+            },
+            _ => return Err(unknown_field(key, &FIELDS)),
+        }
+
+        Ok(())
+    }
+
+    pub fn generate(&self) -> proc_macro2::TokenStream {
+        let Self { sdk, vis, .. } = self;
+        let bas_nb = self.battery_level.len() as u8;
+
+        let features = if self.notification {
+            quote!(#sdk::ble_stack::profiles::bas::bass::BAS_BATT_LVL_NTF_SUP)
+        } else {
+            quote!(#sdk::ble_stack::profiles::bas::bass::BAS_BATT_LVL_NTF_NOT_SUP)
+        };
+
+        let mut instance_configs = Vec::new();
+        let mut level_updates = Vec::new();
+        let mut old_levels = Vec::new();
+
+        for (instance, battery_level) in self.battery_level.iter().enumerate() {
+            let instance = instance as u8;
+            let description = instance as u16 + 1;
+
+            instance_configs.push(quote!(
+                db_cfg.features[#instance as usize] = #features as u8;
+            ));
+
+            if bas_nb > 1 {
+                instance_configs.push(quote!(
+                    db_cfg.batt_level_pres_format[#instance as usize] = #sdk::ble_stack::profiles::PrfCharPresFmt {
+                        unit: #UNIT_PERCENTAGE,
+                        description: #description,
+                        format: #PRF_FORMAT_UINT8,
+                        exponent: 0,
+                        name_space: #NAMESPACE_BLUETOOTH_SIG,
+                    };
+                ));
+            }
+
+            level_updates.push(quote!(
+                let mut msg = KeMsgBassBattLevelUpdReq::new(
+                    TASK_APP as u16,
+                    prf_get_task_from_id(TASK_ID_BASS as u16),
+                );
+
+                msg.fields().bas_instance = #instance;
+                msg.fields().batt_level = #battery_level();
+
+                msg.send();
+            ));
+
+            old_levels.push(quote!(
+                msg.fields().old_batt_lvl[#instance as usize] = #battery_level();
+            ));
+        }
+
+        let ntf_cfg_handler = match &self.on_notification_config {
+            Some(on_notification_config) => quote!(
+                let param = param as *const #sdk::ble_stack::profiles::bas::bass::task::BassBattLevelNtfCfgInd;
+                let param = unsafe { &*param };
+
+                #on_notification_config(
+                    param.conidx,
+                    param.ntf_cfg != #sdk::ble_stack::profiles::PRF_CLI_STOP_NTFIND as u8,
+                );
+            ),
+            None => quote!(
+                let _ = param;
+            ),
+        };
+
+        let process_handler = generate_process_handler(
+            sdk,
+            "bass",
+            &[
+                ProcessHandlerEntry {
+                    msg_id: quote!(BASS_BATT_LEVEL_NTF_CFG_IND),
+                    func: quote!(bass_batt_level_ntf_cfg_ind_handler),
+                },
+                ProcessHandlerEntry {
+                    msg_id: quote!(BASS_BATT_LEVEL_UPD_RSP),
+                    func: quote!(bass_consume_handler),
+                },
+                ProcessHandlerEntry {
+                    msg_id: quote!(BASS_ENABLE_RSP),
+                    func: quote!(bass_consume_handler),
+                },
+            ],
+        );
+
+        let create_db = generate_create_db(
+            sdk,
+            "bass",
+            &quote!(TASK_ID_BASS),
+            &quote!(BassDbCfg),
+            0,
+            quote!(
+                db_cfg.bas_nb = #bas_nb;
+
+                #(#instance_configs)*
+            ),
+        );
+
+        let guard = once_per_crate("app_bass");
+
+        quote!(
+            #guard
+
+            mod app_bass_task {
+                use #sdk::{
+                    ble_stack::profiles::bas::bass::task::{
+                        BASS_BATT_LEVEL_NTF_CFG_IND, BASS_BATT_LEVEL_UPD_RSP, BASS_ENABLE_RSP,
+                    },
+                    platform::core_modules::ke::{
+                        msg::{KeMsgId, KE_MSG_CONSUMED},
+                        task::KeTaskId,
+                    },
+                };
+
+                extern "C" fn bass_batt_level_ntf_cfg_ind_handler(
+                    _msg_id: KeMsgId,
+                    param: *const core::ffi::c_void,
+                    _dest_id: KeTaskId,
+                    _src_id: KeTaskId,
+                ) -> i32 {
+                    #ntf_cfg_handler
+
+                    KE_MSG_CONSUMED as i32
+                }
+
+                extern "C" fn bass_consume_handler(
+                    _msg_id: KeMsgId,
+                    _param: *const core::ffi::c_void,
+                    _dest_id: KeTaskId,
+                    _src_id: KeTaskId,
+                ) -> i32 {
+                    KE_MSG_CONSUMED as i32
+                }
+
+                #process_handler
+            }
+
+            #vis mod app_bass {
+                use #sdk::{
+                    ble_stack::profiles::{
+                        bas::bass::{
+                            task::{KeMsgBassBattLevelUpdReq, KeMsgBassEnableReq},
+                            BassDbCfg,
+                        },
+                        prf_get_task_from_id, PRF_CLI_STOP_NTFIND,
+                    },
+                    platform::core_modules::rwip::{TASK_APP, TASK_ID_BASS},
+                };
+
+                #[no_mangle]
+                extern "C" fn app_bass_init() {
+                    // Nothing to do
+                }
+
+                #create_db
+
+                /// Enables the battery service through `app_prf_enable`, with notifications stopped as
+                /// for a peer that did not subscribe yet.
+                #[export_name = "app_bass_enable"]
+                extern "C" fn app_bass_enable_unsubscribed(conidx: u8) {
+                    app_bass_enable(conidx, PRF_CLI_STOP_NTFIND as u8);
+                }
+
+                /// Enables the battery service for a new connection, reporting the current levels.
+                ///
+                /// `ntf_cfg` is the client characteristic configuration stored for a bonded peer,
+                /// call this instead of `app_prf_enable` to restore its subscription.
+                pub fn app_bass_enable(conidx: u8, ntf_cfg: u8) {
+                    let mut msg = KeMsgBassEnableReq::new(
+                        TASK_APP as u16,
+                        prf_get_task_from_id(TASK_ID_BASS as u16),
+                    );
+
+                    msg.fields().conidx = conidx;
+                    msg.fields().ntf_cfg = ntf_cfg;
+
+                    #(#old_levels)*
+
+                    msg.send();
+                }
+
+                /// Reads every battery level source and pushes the values to the `bass` task, which
+                /// serves them to read requests and notifies subscribed peers.
+                pub fn app_bass_batt_level_update() {
+                    #({ #level_updates })*
+                }
+            }
+        )
+    }
+}
@@ -1,23 +1,44 @@
-use std::{
-    collections::{HashMap, HashSet},
-    fmt::format,
-    ops::Deref,
-};
+use std::{collections::BTreeSet, ops::Deref};
 
 use indexmap::IndexMap;
 use proc_macro2::{Group, Ident, Span};
-use quote::{format_ident, quote, ToTokens, TokenStreamExt};
+use quote::{format_ident, quote, ToTokens};
 use syn::{
     parse::{discouraged::Speculative, Parse, ParseStream, Parser},
     punctuated::Punctuated,
     spanned::Spanned,
-    token, Error, LitInt, LitStr, Path, Token,
+    token, Error, Expr, LitBool, LitInt, LitStr, Path, Token, Visibility,
+};
+
+use crate::{
+    advertising::AD_TYPE_COMPLETE_LIST_16BIT_SERVICE_IDS,
+    diagnostic::{emit, emit_err, once_per_crate, unknown_field, SeenFields},
+    literal::parse_int,
+    options::{Options, Sdk},
+    profile::generate_create_db_fn,
 };
 
+const SERVICE_FIELDS: [&str; 3] = ["uuid", "characteristics", "advertise"];
+const CHARACTERISTIC_FIELDS: [&str; 6] = [
+    "uuid",
+    "permissions",
+    "length",
+    "user_description",
+    "read_handler",
+    "write_handler",
+];
+
 #[derive(Debug)]
 pub enum Uuid {
     Uuid16(u16),
-    Uuid128([u8; 16]),
+}
+
+impl Uuid {
+    pub fn to_le_bytes(&self) -> Vec<u8> {
+        match self {
+            Uuid::Uuid16(uuid16) => uuid16.to_le_bytes().to_vec(),
+        }
+    }
 }
 
 impl ToTokens for Uuid {
@@ -28,11 +49,6 @@ impl ToTokens for Uuid {
                     #uuid16
                 ));
             }
-            Uuid::Uuid128(uuid128) => {
-                tokens.extend(quote!(
-                    [ #(#uuid128),* ]
-                ));
-            }
         }
     }
 }
@@ -53,6 +69,8 @@ impl Default for PermissionVariants {
     }
 }
 
+/// `PERM_MASK_UUID_LEN` values of the SDK, most of which the macro does not generate yet.
+#[allow(dead_code, clippy::upper_case_acronyms)]
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
 #[repr(u8)]
 pub enum UuidLength {
@@ -113,10 +131,6 @@ impl Permissions {
     pub fn has_indication(&self) -> bool {
         self.indication != PermissionVariants::Disabled
     }
-
-    pub fn has_notification(&self) -> bool {
-        self.notification != PermissionVariants::Disabled
-    }
 }
 
 impl ToTokens for Permissions {
@@ -170,12 +184,12 @@ impl Characteristic {
             if let RecordValueData::LitInt(uuid) = &uuid.data {
                 let uuid: u16 = uuid.base10_parse()?;
 
-                return Ok(Uuid::Uuid16(uuid));
+                Ok(Uuid::Uuid16(uuid))
             } else {
-                return Err(Error::new(uuid.span, "expected integer literal"));
+                Err(Error::new(uuid.span, "expected integer literal"))
             }
         } else {
-            return Err(Error::new(records.span, "missing `uuid`"));
+            Err(Error::new(records.span, "missing `uuid`"))
         }
     }
 
@@ -184,6 +198,11 @@ impl Characteristic {
             if let RecordValueData::Flags(permission_flags) = &permissions.data {
                 let span = permissions.span;
                 let permission_flags = permission_flags.deref();
+                let flags = permission_flags
+                    .iter()
+                    .map(String::as_str)
+                    .collect::<Vec<_>>()
+                    .join(" | ");
                 let mut permissions = Permissions::default();
 
                 if permission_flags
@@ -194,7 +213,7 @@ impl Characteristic {
                 {
                     return Err(Error::new(
                         span,
-                        &format!("defined multiple read permissions: {permission_flags:?}"),
+                        format!("defined multiple read permissions: {flags}"),
                     ));
                 }
 
@@ -206,7 +225,7 @@ impl Characteristic {
                 {
                     return Err(Error::new(
                         span,
-                        &format!("defined multiple write permissions: {permission_flags:?}"),
+                        format!("defined multiple write permissions: {flags}"),
                     ));
                 }
 
@@ -218,7 +237,7 @@ impl Characteristic {
                 {
                     return Err(Error::new(
                         span,
-                        &format!("defined multiple indication permissions: {permission_flags:?}"),
+                        format!("defined multiple indication permissions: {flags}"),
                     ));
                 }
 
@@ -230,7 +249,7 @@ impl Characteristic {
                 {
                     return Err(Error::new(
                         span,
-                        &format!("defined multiple notification permissions: {permission_flags:?}"),
+                        format!("defined multiple notification permissions: {flags}"),
                     ));
                 }
 
@@ -293,18 +312,18 @@ impl Characteristic {
                         _ => {
                             return Err(Error::new(
                                 span,
-                                &format!("unknown flag: {permission_flag}"),
+                                format!("unknown flag: {permission_flag}"),
                             ));
                         }
                     }
                 }
 
-                return Ok(permissions);
+                Ok(permissions)
             } else {
-                return Err(Error::new(permissions.span, "expected flags"));
+                Err(Error::new(permissions.span, "expected flags"))
             }
         } else {
-            return Err(Error::new(records.span, "missing `permissions`"));
+            Err(Error::new(records.span, "missing `permissions`"))
         }
     }
 
@@ -314,64 +333,72 @@ impl Characteristic {
                 RecordValueData::LitInt(length) => {
                     let length: u16 = length.base10_parse()?;
 
-                    return Ok(CharacteristicLength::Int(length));
-                }
-                RecordValueData::Path(path) => {
-                    return Ok(CharacteristicLength::Path(path.clone()));
+                    Ok(CharacteristicLength::Int(length))
                 }
-                _ => return Err(Error::new(length.span, "expected integer literal")),
+                RecordValueData::Path(path) => Ok(CharacteristicLength::Path(path.clone())),
+                _ => Err(Error::new(length.span, "expected integer literal")),
             }
         } else {
-            return Err(Error::new(records.span, "missing `length`"));
+            Err(Error::new(records.span, "missing `length`"))
         }
     }
 
     fn parse_user_description(records: &Records) -> syn::Result<Option<String>> {
         if let Some(user_description) = records.get("user_description") {
             if let RecordValueData::LitStr(user_description) = &user_description.data {
-                return Ok(Some(user_description.token().to_string()));
+                Ok(Some(user_description.token().to_string()))
             } else {
-                return Err(Error::new(user_description.span, "expected string literal"));
+                Err(Error::new(user_description.span, "expected string literal"))
             }
         } else {
-            return Ok(None);
+            Ok(None)
         }
     }
 
     fn parse_read_handler(records: &Records) -> syn::Result<Option<Path>> {
         if let Some(read_handler) = records.get("read_handler") {
             if let RecordValueData::Path(read_handler) = &read_handler.data {
-                return Ok(Some(read_handler.clone()));
+                Ok(Some(read_handler.clone()))
             } else {
-                return Err(Error::new(read_handler.span, "expected path"));
+                Err(Error::new(read_handler.span, "expected path"))
             }
         } else {
-            return Ok(None);
+            Ok(None)
         }
     }
 
     fn parse_write_handler(records: &Records) -> syn::Result<Option<Path>> {
         if let Some(write_handler) = records.get("write_handler") {
             if let RecordValueData::Path(write_handler) = &write_handler.data {
-                return Ok(Some(write_handler.clone()));
+                Ok(Some(write_handler.clone()))
             } else {
-                return Err(Error::new(write_handler.span, "expected path"));
+                Err(Error::new(write_handler.span, "expected path"))
             }
         } else {
-            return Ok(None);
+            Ok(None)
         }
     }
 
-    fn parse(span: Span, name: &str, records: &Records) -> syn::Result<Self> {
-        Ok(Self {
+    /// Parses a characteristic, reporting all of its errors.
+    fn parse(span: Span, name: &str, records: &Records) -> Option<Self> {
+        records.check_keys(&CHARACTERISTIC_FIELDS);
+
+        let permissions = emit_err(Self::parse_permissions(records));
+        let uuid = emit_err(Self::parse_uuid(records));
+        let length = emit_err(Self::parse_length(records));
+        let user_description = emit_err(Self::parse_user_description(records));
+        let write_handler = emit_err(Self::parse_write_handler(records));
+        let read_handler = emit_err(Self::parse_read_handler(records));
+
+        Some(Self {
             span,
             name: name.into(),
-            permissions: Self::parse_permissions(records)?,
-            uuid: Self::parse_uuid(records)?,
-            length: Self::parse_length(records)?,
-            user_description: Self::parse_user_description(records)?,
-            write_handler: Self::parse_write_handler(records)?,
-            read_handler: Self::parse_read_handler(records)?,
+            permissions: permissions?,
+            uuid: uuid?,
+            length: length?,
+            user_description: user_description?,
+            write_handler: write_handler?,
+            read_handler: read_handler?,
         })
     }
 }
@@ -381,6 +408,7 @@ pub struct Service {
     name: String,
     characteristics: Vec<Characteristic>,
     uuid: Uuid,
+    advertise: bool,
 }
 
 impl Service {
@@ -389,55 +417,75 @@ impl Service {
             if let RecordValueData::LitInt(uuid) = &uuid.data {
                 let uuid: u16 = uuid.base10_parse()?;
 
-                return Ok(Uuid::Uuid16(uuid));
+                Ok(Uuid::Uuid16(uuid))
             } else {
-                return Err(Error::new(uuid.span, "expected integer literal"));
+                Err(Error::new(uuid.span, "expected integer literal"))
             }
         } else {
-            return Err(Error::new(records.span, "missing `uuid`"));
+            Err(Error::new(records.span, "missing `uuid`"))
         }
     }
 
     fn parse_characteristics(records: &Records) -> syn::Result<Vec<Characteristic>> {
         if let Some(characteristics) = records.get("characteristics") {
             if let RecordValueData::Records(characteristics) = &characteristics.data {
-                return Ok(characteristics
+                Ok(characteristics
                     .iter()
-                    .map(|(name, records)| match &records.data {
+                    .filter_map(|(name, records)| match &records.data {
                         RecordValueData::Records(records) => {
                             Characteristic::parse(records.span, name, records)
                         }
-                        _ => Err(Error::new(records.span, "expected record")),
+                        _ => {
+                            emit(Error::new(records.span, "expected record"));
+                            None
+                        }
                     })
-                    .try_collect()?);
+                    .collect())
             } else {
-                return Err(Error::new(
+                Err(Error::new(
                     characteristics.span,
                     "expected characteristics records",
-                ));
+                ))
             }
         } else {
-            return Err(Error::new(records.span, "missing `characteristics`"));
+            Err(Error::new(records.span, "missing `characteristics`"))
         }
     }
 
-    fn parse(name: &str, records: &Records) -> syn::Result<Self> {
-        let uuid = Self::parse_uuid(records)?;
-        let characteristics = Self::parse_characteristics(records)?;
+    fn parse_advertise(records: &Records) -> syn::Result<bool> {
+        if let Some(advertise) = records.get("advertise") {
+            if let RecordValueData::LitBool(advertise) = &advertise.data {
+                Ok(advertise.value)
+            } else {
+                Err(Error::new(advertise.span, "expected bool literal"))
+            }
+        } else {
+            Ok(false)
+        }
+    }
 
-        Ok(Self {
+    /// Parses a service, reporting all errors of the service and its characteristics.
+    fn parse(name: &str, records: &Records) -> Option<Self> {
+        records.check_keys(&SERVICE_FIELDS);
+
+        let uuid = emit_err(Self::parse_uuid(records));
+        let characteristics = emit_err(Self::parse_characteristics(records));
+        let advertise = emit_err(Self::parse_advertise(records));
+
+        Some(Self {
             name: name.to_string(),
-            characteristics,
-            uuid,
+            characteristics: characteristics?,
+            uuid: uuid?,
+            advertise: advertise?,
         })
     }
 }
 
 #[derive(Debug)]
-pub struct Flags(HashSet<String>);
+pub struct Flags(BTreeSet<String>);
 
 impl std::ops::Deref for Flags {
-    type Target = HashSet<String>;
+    type Target = BTreeSet<String>;
 
     fn deref(&self) -> &Self::Target {
         &self.0
@@ -459,6 +507,7 @@ impl Parse for Flags {
 pub enum RecordValueData {
     Records(Records),
     Flags(Flags),
+    LitBool(LitBool),
     LitInt(LitInt),
     LitStr(LitStr),
     Path(Path),
@@ -485,11 +534,9 @@ impl Parse for RecordValue {
             {
                 input.advance_to(&forked_input);
 
-                let records = fields.into_iter().map(|r| (r.key.key, r.value)).collect();
-
                 return Ok(Self {
                     span,
-                    data: RecordValueData::Records(Records { span, map: records }),
+                    data: RecordValueData::Records(Records::new(span, fields)),
                 });
             }
         }
@@ -515,9 +562,19 @@ impl Parse for RecordValue {
         }
 
         let forked_input = input.fork();
+        if let Ok(lit_bool) = forked_input.parse::<LitBool>() {
+            input.advance_to(&forked_input);
+            let span = lit_bool.span();
+            return Ok(Self {
+                span,
+                data: RecordValueData::LitBool(lit_bool),
+            });
+        }
+
+        let forked_input = input.fork();
+        let span = forked_input.span();
         if let Ok(flags) = forked_input.parse::<Flags>() {
             input.advance_to(&forked_input);
-            let span = forked_input.span();
             return Ok(Self {
                 span,
                 data: RecordValueData::Flags(flags),
@@ -536,7 +593,7 @@ impl Parse for RecordValue {
 
         Err(Error::new(
             input.span(),
-            &format!("unexpected input: {input:?}"),
+            "expected record, flags, literal or path",
         ))
     }
 }
@@ -558,18 +615,16 @@ impl Parse for RecordKey {
 
 #[derive(Debug)]
 pub struct Record {
-    span: Span,
     key: RecordKey,
     value: RecordValue,
 }
 
 impl Parse for Record {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        let span = input.span();
         let key: RecordKey = input.parse()?;
         let value: RecordValue = input.parse()?;
 
-        Ok(Self { span, key, value })
+        Ok(Self { key, value })
     }
 }
 
@@ -577,6 +632,34 @@ impl Parse for Record {
 pub struct Records {
     span: Span,
     map: IndexMap<String, RecordValue>,
+    keys: Vec<Ident>,
+}
+
+impl Records {
+    fn new(span: Span, fields: Punctuated<Record, token::Comma>) -> Self {
+        let mut map = IndexMap::new();
+        let mut keys = Vec::new();
+        let mut seen = SeenFields::default();
+
+        for record in fields {
+            let key = Ident::new(&record.key.key, record.key.span);
+            if emit_err(seen.insert(&key)).is_some() {
+                map.insert(record.key.key, record.value);
+                keys.push(key);
+            }
+        }
+
+        Self { span, map, keys }
+    }
+
+    /// Reports keys that are not one of `expected`.
+    fn check_keys(&self, expected: &[&str]) {
+        for key in &self.keys {
+            if !expected.iter().any(|expected| key == expected) {
+                emit(unknown_field(key, expected));
+            }
+        }
+    }
 }
 
 impl std::ops::Deref for Records {
@@ -592,65 +675,253 @@ impl Parse for Records {
         let span = input.span();
         let fields: Punctuated<Record, token::Comma> = input.parse_terminated(Record::parse)?;
 
-        Ok(Records {
-            span,
-            map: fields.into_iter().map(|r| (r.key.key, r.value)).collect(),
+        Ok(Records::new(span, fields))
+    }
+}
+
+/// FNV-1a hash over the attribute layout of the generated database.
+///
+/// Only the parts that a peer can observe are fed into the hash (handles, UUIDs, permissions,
+/// lengths and descriptor values), so the value changes exactly when bonded peers need a
+/// Service Changed indication. Lengths given as paths are only known once the invoking crate is
+/// compiled, so the generated code mixes them in after everything else.
+#[derive(Debug)]
+pub struct AttDbHasher {
+    hash: u32,
+    lengths: Vec<Path>,
+}
+
+impl AttDbHasher {
+    const OFFSET_BASIS: u32 = 0x811c_9dc5;
+    const PRIME: u32 = 0x0100_0193;
+
+    pub fn new() -> Self {
+        Self {
+            hash: Self::OFFSET_BASIS,
+            lengths: Vec::new(),
+        }
+    }
+
+    pub fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.hash ^= *byte as u32;
+            self.hash = self.hash.wrapping_mul(Self::PRIME);
+        }
+    }
+
+    pub fn write_length(&mut self, length: &CharacteristicLength) {
+        match length {
+            CharacteristicLength::Int(int) => self.write(&int.to_le_bytes()),
+            CharacteristicLength::Path(path) => {
+                self.write(b"length");
+                self.lengths.push(path.clone());
+            }
+        }
+    }
+
+    /// Constant expression of the hash.
+    pub fn finish(&self) -> proc_macro2::TokenStream {
+        let Self { hash, lengths } = self;
+
+        if lengths.is_empty() {
+            return quote!(#hash);
+        }
+
+        let prime = Self::PRIME;
+
+        quote!({
+            let lengths = [#(#lengths as u16),*];
+            let mut hash = #hash;
+            let mut i = 0;
+            while i < lengths.len() {
+                let bytes = lengths[i].to_le_bytes();
+                hash = (hash ^ bytes[0] as u32).wrapping_mul(#prime);
+                hash = (hash ^ bytes[1] as u32).wrapping_mul(#prime);
+                i += 1;
+            }
+            hash
         })
     }
 }
 
 #[derive(Debug)]
 pub struct CustomServer1ServiceConfiguration {
+    sdk: Sdk,
+    vis: Visibility,
+    /// Whether to generate for the `host_sim!` stand-in of the SDK, see the `host-sim` feature.
+    pub host_sim: bool,
+    /// Handle of the first attribute and the span of its value.
+    start_handle: Option<(u16, Span)>,
+    att_db_hash: proc_macro2::TokenStream,
     services: Vec<Service>,
     service_idxs: Vec<u8>,
-    char_idx_map: HashMap<String, usize>,
+    char_idx_map: IndexMap<String, usize>,
     write_handlers: Vec<(u16, Path)>,
     read_handlers: Vec<(u16, Path)>,
 }
 
 impl Parse for CustomServer1ServiceConfiguration {
     fn parse(input: ParseStream) -> syn::Result<Self> {
+        let Options { sdk, vis } = Options::parse(input)?;
+        let start_handle = parse_start_handle(input)?;
         let records: Records = input.parse()?;
 
+        // Services are parsed independently, so that the errors of all of them are reported.
         let services = records
             .iter()
-            .map(|(name, records)| match &records.data {
+            .filter_map(|(name, records)| match &records.data {
                 RecordValueData::Records(records) => Service::parse(name, records),
-                _ => Err(Error::new(records.span, "expected record")),
+                _ => {
+                    emit(Error::new(records.span, "expected record"));
+                    None
+                }
             })
-            .try_collect()?;
+            .collect();
 
         Ok(Self {
+            sdk,
+            vis,
+            host_sim: cfg!(feature = "host-sim"),
+            start_handle,
+            att_db_hash: quote!(),
             services,
             service_idxs: Vec::new(),
-            char_idx_map: HashMap::new(),
+            char_idx_map: IndexMap::new(),
             write_handlers: Vec::new(),
             read_handlers: Vec::new(),
         })
     }
 }
 
+/// Value of an attribute in the database.
+enum AttValue<'a> {
+    None,
+    Uuid(&'a Uuid),
+    Bytes(&'a [u8]),
+}
+
+/// Attribute of the database, as `AttmDesc128` or as the `AttDesc` of `host_sim!`.
+///
+/// `uuid` is a 16-bit UUID expression.
+fn att_desc(
+    host_sim: bool,
+    sdk: &proc_macro2::TokenStream,
+    uuid: proc_macro2::TokenStream,
+    perm: &Permissions,
+    max_length: proc_macro2::TokenStream,
+    length: proc_macro2::TokenStream,
+    value: AttValue,
+) -> proc_macro2::TokenStream {
+    if host_sim {
+        let value = match value {
+            AttValue::None => Vec::new(),
+            AttValue::Uuid(uuid) => uuid.to_le_bytes(),
+            AttValue::Bytes(bytes) => bytes.to_vec(),
+        };
+
+        return quote!(
+            #sdk::AttDesc {
+                uuid: #uuid,
+                perm: #perm,
+                max_length: #max_length,
+                length: #length,
+                value: &[#(#value),*],
+            }
+        );
+    }
+
+    let value = match value {
+        AttValue::None => quote!(core::ptr::null()),
+        AttValue::Uuid(uuid) => quote!(&(#uuid) as *const _ as *const u8),
+        AttValue::Bytes(bytes) => quote!(&[#(#bytes),*] as *const _ as *const u8),
+    };
+
+    quote!(
+        #sdk::ble_stack::host::att::attm::AttmDesc128 {
+            uuid: &#uuid as *const _ as *const u8,
+            uuid_size: #sdk::ble_stack::host::att::ATT_UUID_16_LEN as u8,
+            perm: #perm,
+            max_length: #max_length,
+            length: #length,
+            value: #value,
+        }
+    )
+}
+
+/// Parses the optional `start_handle: 0x30` between the options and the services.
+///
+/// Errors in its value are reported, so that the services are still checked.
+fn parse_start_handle(input: ParseStream) -> syn::Result<Option<(u16, Span)>> {
+    let fork = input.fork();
+    if !matches!(fork.parse::<Ident>(), Ok(ident) if ident == "start_handle" && fork.peek(Token![:]))
+    {
+        return Ok(None);
+    }
+
+    input.parse::<Ident>()?;
+    input.parse::<Token![:]>()?;
+    let value: Expr = input.parse()?;
+    if !input.is_empty() {
+        input.parse::<Token![,]>()?;
+    }
+
+    let start_handle = emit_err(parse_int::<u16>(&value).and_then(|start_handle| {
+        if start_handle == 0 {
+            return Err(Error::new(
+                value.span(),
+                "`start_handle` must not be 0, omit it to let the stack assign one",
+            ));
+        }
+        Ok(start_handle)
+    }));
+
+    Ok(start_handle.map(|start_handle| (start_handle, value.span())))
+}
+
 impl CustomServer1ServiceConfiguration {
+    /// Handle of the first attribute, `0` if the stack assigns it.
+    fn start_handle(&self) -> u16 {
+        self.start_handle
+            .map_or(0, |(start_handle, _)| start_handle)
+    }
+
+    /// Root path of the SDK, or of the `host_sim!` stand-in with the `host-sim` feature.
+    fn sdk_path(&self) -> proc_macro2::TokenStream {
+        if self.host_sim {
+            quote!(crate::host_sim)
+        } else {
+            self.sdk.to_token_stream()
+        }
+    }
+
     fn generate_att_db_records(&mut self) -> syn::Result<Vec<proc_macro2::TokenStream>> {
+        let sdk = self.sdk_path();
+        let att = quote!(#sdk::ble_stack::host::att);
         let mut records = Vec::new();
+        let mut hasher = AttDbHasher::new();
+
+        hasher.write(&self.start_handle().to_le_bytes());
 
         for service in &self.services {
             let uuid = &service.uuid;
             self.service_idxs.push(records.len() as u8);
 
-            let mut read_permission = Permissions::default();
-
-            read_permission.read = PermissionVariants::Enabled;
-
-            records.push(quote!(
-                da14531_sdk::ble_stack::host::att::attm::AttmDesc128 {
-                    uuid: &da14531_sdk::ble_stack::host::att::ATT_DECL_PRIMARY_SERVICE as *const _ as *const u8,
-                    uuid_size: da14531_sdk::ble_stack::host::att::ATT_UUID_16_LEN as u8,
-                    perm: #read_permission,
-                    max_length: da14531_sdk::ble_stack::host::att::ATT_UUID_16_LEN as u16,
-                    length: da14531_sdk::ble_stack::host::att::ATT_UUID_16_LEN as u16,
-                    value: &(#uuid) as *const _ as *const u8,
-                }
+            let read_permission = Permissions {
+                read: PermissionVariants::Enabled,
+                ..Default::default()
+            };
+
+            hasher.write(b"service");
+            hasher.write(&uuid.to_le_bytes());
+
+            records.push(att_desc(
+                self.host_sim,
+                &sdk,
+                quote!(#att::ATT_DECL_PRIMARY_SERVICE),
+                &read_permission,
+                quote!(#att::ATT_UUID_16_LEN as u16),
+                quote!(#att::ATT_UUID_16_LEN as u16),
+                AttValue::Uuid(uuid),
             ));
 
             for characteristic in &service.characteristics {
@@ -660,16 +931,19 @@ impl CustomServer1ServiceConfiguration {
                 let uuid = &characteristic.uuid;
                 let length = &characteristic.length;
                 let mut trigger_read_indication = quote!();
-                records.push(quote!(
-                    da14531_sdk::ble_stack::host::att::attm::AttmDesc128 {
-                        uuid: &da14531_sdk::ble_stack::host::att::ATT_DECL_CHARACTERISTIC
-                            as *const _ as *const u8,
-                        uuid_size: da14531_sdk::ble_stack::host::att::ATT_UUID_16_LEN as u8,
-                        perm: #read_permission,
-                        max_length: 0,
-                        length: 0,
-                        value: core::ptr::null(),
-                    }
+
+                hasher.write(b"characteristic");
+                hasher.write(&uuid.to_le_bytes());
+                hasher.write(&perm.get_bits().to_le_bytes());
+                hasher.write_length(length);
+                records.push(att_desc(
+                    self.host_sim,
+                    &sdk,
+                    quote!(#att::ATT_DECL_CHARACTERISTIC),
+                    &read_permission,
+                    quote!(0),
+                    quote!(0),
+                    AttValue::None,
                 ));
                 self.char_idx_map
                     .insert(characteristic.name.to_uppercase(), records.len());
@@ -701,36 +975,39 @@ impl CustomServer1ServiceConfiguration {
                         ));
                     }
                 }
+                let value_desc = att_desc(
+                    self.host_sim,
+                    &sdk,
+                    quote!(#uuid),
+                    perm,
+                    quote!(#trigger_read_indication #length),
+                    quote!(0),
+                    AttValue::None,
+                );
                 records.push(quote!(
                     #[doc = #perm_dbg]
                     #[doc = #perm_dbg_bits]
-                    da14531_sdk::ble_stack::host::att::attm::AttmDesc128 {
-                        uuid: &(#uuid) as *const _ as *const u8,
-                        uuid_size: da14531_sdk::ble_stack::host::att::ATT_UUID_16_LEN as u8,
-                        perm: #perm,
-                        max_length: #trigger_read_indication #length,
-                        length: 0,
-                        value: core::ptr::null(),
-                    }
+                    #value_desc
                 ));
 
                 if perm.has_indication() {
-                    let mut indication_cccd_permission = Permissions::default();
-
-                    indication_cccd_permission.read = PermissionVariants::Enabled;
-                    indication_cccd_permission.write = PermissionVariants::Enabled;
-                    indication_cccd_permission.write_request_accepted = true;
-
-                    records.push(quote!(
-                        da14531_sdk::ble_stack::host::att::attm::AttmDesc128 {
-                            uuid: &da14531_sdk::ble_stack::host::att::ATT_DESC_CLIENT_CHAR_CFG
-                                as *const _ as *const u8,
-                            uuid_size: da14531_sdk::ble_stack::host::att::ATT_UUID_16_LEN as u8,
-                            perm: #indication_cccd_permission,
-                            max_length: 0,
-                            length: 0,
-                            value: core::ptr::null(),
-                        }
+                    hasher.write(b"cccd");
+
+                    let indication_cccd_permission = Permissions {
+                        read: PermissionVariants::Enabled,
+                        write: PermissionVariants::Enabled,
+                        write_request_accepted: true,
+                        ..Default::default()
+                    };
+
+                    records.push(att_desc(
+                        self.host_sim,
+                        &sdk,
+                        quote!(#att::ATT_DESC_CLIENT_CHAR_CFG),
+                        &indication_cccd_permission,
+                        quote!(0),
+                        quote!(0),
+                        AttValue::None,
                     ));
                 }
 
@@ -738,29 +1015,70 @@ impl CustomServer1ServiceConfiguration {
                     let user_description = &user_description[1..(user_description.len() - 1)];
                     let user_description = user_description.as_bytes();
                     let user_description_len = user_description.len() as u16;
-                    records.push(quote!(
-                        da14531_sdk::ble_stack::host::att::attm::AttmDesc128 {
-                            uuid: &da14531_sdk::ble_stack::host::att::ATT_DESC_CHAR_USER_DESCRIPTION as *const _ as *const u8,
-                            uuid_size: da14531_sdk::ble_stack::host::att::ATT_UUID_16_LEN as u8,
-                            perm: #read_permission,
-                            max_length: #user_description_len,
-                            length: #user_description_len,
-                            value: &[#(#user_description),*] as *const _ as *const u8,
-                        }
+
+                    hasher.write(b"user_description");
+                    hasher.write(user_description);
+                    records.push(att_desc(
+                        self.host_sim,
+                        &sdk,
+                        quote!(#att::ATT_DESC_CHAR_USER_DESCRIPTION),
+                        &read_permission,
+                        quote!(#user_description_len),
+                        quote!(#user_description_len),
+                        AttValue::Bytes(user_description),
                     ));
                 }
             }
         }
 
+        if let Some((start_handle, span)) = self.start_handle {
+            if start_handle as usize + records.len() > u16::MAX as usize {
+                return Err(Error::new(
+                    span,
+                    format!(
+                        "`start_handle` {start_handle:#06x} leaves no room for {} attributes",
+                        records.len()
+                    ),
+                ));
+            }
+        }
+
+        self.att_db_hash = hasher.finish();
+
         Ok(records)
     }
 
     fn generate_att_db(&mut self) -> syn::Result<proc_macro2::TokenStream> {
+        let sdk = self.sdk_path();
         let records = self.generate_att_db_records()?;
         let record_count = records.len();
         let record_count_u8 = record_count as u8;
         let service_idxs = &self.service_idxs;
         let services_len = service_idxs.len();
+        let start_handle = self.start_handle();
+        let att_db_hash = &self.att_db_hash;
+
+        let vis = &self.vis;
+        let service_uuids = self.services.iter().map(|service| {
+            let name = format_ident!("SERVICE_{}_UUID", service.name.to_uppercase());
+            match &service.uuid {
+                Uuid::Uuid16(uuid16) => quote!(#vis const #name: u16 = #uuid16;),
+            }
+        });
+
+        let adv_uuid16_list: Vec<u8> = self
+            .services
+            .iter()
+            .filter(|service| service.advertise)
+            .flat_map(|service| service.uuid.to_le_bytes())
+            .collect();
+        let mut adv_service_uuids = Vec::new();
+        if !adv_uuid16_list.is_empty() {
+            adv_service_uuids.push(adv_uuid16_list.len() as u8 + 1);
+            adv_service_uuids.push(AD_TYPE_COMPLETE_LIST_16BIT_SERVICE_IDS);
+            adv_service_uuids.extend(adv_uuid16_list);
+        }
+        let adv_service_uuids_len = adv_service_uuids.len();
 
         let char_idx_map: Vec<_> = self
             .char_idx_map
@@ -774,9 +1092,64 @@ impl CustomServer1ServiceConfiguration {
             })
             .collect();
 
+        let constants = quote!(
+        /// Handle of the first attribute, `0` if the stack assigns it.
+        #vis const CUSTS1_START_HANDLE: u16 = #start_handle;
+
+        /// Hash of the attribute layout. Store it alongside the bonding data and send a
+        /// Service Changed indication to bonded peers when it differs after an update.
+        #vis const CUSTS1_ATT_DB_HASH: u32 = #att_db_hash;
+
+        #(#service_uuids)*
+
+        /// Complete list of the service UUIDs marked `advertise: true`, as an AD structure
+        /// ready to be included in the advertising data.
+        #vis const ADV_SERVICE_UUIDS: [u8; #adv_service_uuids_len] = [#(#adv_service_uuids),*];
+
+        #vis mod char_idx_map {
+            #(#char_idx_map)*
+        }
+        );
+
+        if self.host_sim {
+            return Ok(quote!(
+                /// Attribute database of the custom service.
+                #vis static CUSTS1_ATT_DB: [#sdk::AttDesc; #record_count] = [
+                    #(#records),*
+                ];
+
+                #constants
+            ));
+        }
+
+        let (db_create_func, create_db) = if self.start_handle.is_some() {
+            let create_db = generate_create_db_fn(
+                &self.sdk,
+                &format_ident!("__app_custs1_create_db"),
+                &quote!(#sdk::platform::core_modules::rwip::TASK_ID_CUSTS1),
+                &quote!(#sdk::ble_stack::profiles::custom::custs::custs1::Custs1DbCfg),
+                start_handle,
+                quote!(
+                    db_cfg.att_tbl = CUSTS1_ATT_DB.as_ptr() as *const _;
+                    db_cfg.cfg_flag = 0;
+                    db_cfg.max_nb_att = CUSTS1_ATT_DB_LEN as u16;
+                ),
+            );
+
+            (
+                quote!(__app_custs1_create_db),
+                quote!(
+                    /// Creates the custom profile database at `CUSTS1_START_HANDLE`.
+                    #create_db
+                ),
+            )
+        } else {
+            (quote!(#sdk::bindings::app_custs1_create_db), quote!())
+        };
+
         Ok(quote!(
             #[export_name = "custs1_att_db"]
-            pub(crate) static CUSTS1_ATT_DB: [da14531_sdk::ble_stack::host::att::attm::AttmDesc128;
+            #vis static CUSTS1_ATT_DB: [#sdk::ble_stack::host::att::attm::AttmDesc128;
                 #record_count] = [
                     #(#records),*
             ];
@@ -786,17 +1159,15 @@ impl CustomServer1ServiceConfiguration {
             #[export_name = "custs1_services_size"]
             static CUSTS1_SERVICES_SIZE: u32 = #services_len as u32;
 
-            pub mod char_idx_map {
-                #(#char_idx_map)*
-            }
+            #constants
 
             /// Setup custom profile funcs
-            #[no_mangle]
-            pub static CUST_PRF_FUNCS: [CustPrfFuncCallbacks; 1] = [CustPrfFuncCallbacks {
-                task_id: TASK_ID_CUSTS1,
-                att_db: &CUSTS1_ATT_DB as *const _ as *const da14531_sdk::bindings::attm_desc_128,
+            static CUST_PRF_FUNCS: [#sdk::app_modules::app_custs::CustPrfFuncCallbacks; 1] =
+                [#sdk::app_modules::app_custs::CustPrfFuncCallbacks {
+                task_id: #sdk::platform::core_modules::rwip::TASK_ID_CUSTS1,
+                att_db: &CUSTS1_ATT_DB as *const _ as *const #sdk::bindings::attm_desc_128,
                 max_nb_att: CUSTS1_ATT_DB_LEN,
-                db_create_func: Some(app_custs1_create_db),
+                db_create_func: Some(#db_create_func),
                 enable_func: None,
                 init_func: None,
                 value_wr_validation_func: None,
@@ -804,14 +1175,14 @@ impl CustomServer1ServiceConfiguration {
 
 
             #[no_mangle]
-            pub extern "C" fn custs_get_func_callbacks(task_id: da14531_sdk::platform::core_modules::rwip::KeApiId) -> *const da14531_sdk::app_modules::app_custs::CustPrfFuncCallbacks {
+            extern "C" fn custs_get_func_callbacks(task_id: #sdk::platform::core_modules::rwip::KeApiId) -> *const #sdk::app_modules::app_custs::CustPrfFuncCallbacks {
                 for pfcb in &CUST_PRF_FUNCS {
                     if pfcb.task_id == task_id {
 
-                        let pfcb_ptr = pfcb as *const _ as *const da14531_sdk::app_modules::app_custs::CustPrfFuncCallbacks;
+                        let pfcb_ptr = pfcb as *const _ as *const #sdk::app_modules::app_custs::CustPrfFuncCallbacks;
 
                         return pfcb_ptr;
-                    } else if pfcb.task_id == da14531_sdk::platform::core_modules::rwip::TASK_ID_INVALID {
+                    } else if pfcb.task_id == #sdk::platform::core_modules::rwip::TASK_ID_INVALID {
                         break;
                     }
                 }
@@ -819,8 +1190,8 @@ impl CustomServer1ServiceConfiguration {
             }
 
             #[export_name = "rom_cust_prf_cfg"]
-            static ROM_CUST_PRF_CFG: da14531_sdk::ble_stack::profiles::custom::custs::RomCustPrfCfg =
-                da14531_sdk::ble_stack::profiles::custom::custs::RomCustPrfCfg {
+            static ROM_CUST_PRF_CFG: #sdk::ble_stack::profiles::custom::custs::RomCustPrfCfg =
+                #sdk::ble_stack::profiles::custom::custs::RomCustPrfCfg {
                     custs1_services: CUSTS1_SERVICES.as_ptr(),
                     custs1_services_size: &(#services_len as u8),
                     custs1_att_db: CUSTS1_ATT_DB.as_ptr() as *mut _,
@@ -829,6 +1200,8 @@ impl CustomServer1ServiceConfiguration {
                     ),
                 };
 
+            #create_db
+
         ))
     }
 
@@ -844,6 +1217,14 @@ impl CustomServer1ServiceConfiguration {
             )
         });
 
+        let sdk = self.sdk_path();
+        // `host_sim!` test binaries link against no SDK, so the handler is called directly there.
+        let attrs = if self.host_sim {
+            quote!()
+        } else {
+            quote!(#[no_mangle])
+        };
+
         Ok(quote!(
             /// Handles the messages that are not handled by the SDK internal mechanisms.
             ///
@@ -852,32 +1233,32 @@ impl CustomServer1ServiceConfiguration {
             /// * `param` - Pointer to the parameters of the message.
             /// * `dest_id` - ID of the receiving task instance.
             /// * `src_id` - ID of the sending task instance.
-            #[no_mangle]
-            pub fn user_catch_rest_hndl(
-                msg_id: da14531_sdk::platform::core_modules::ke::msg::KeMsgId,
-                param: *const cty::c_void,
-                dest_id: da14531_sdk::platform::core_modules::ke::task::KeTaskId,
-                src_id: da14531_sdk::platform::core_modules::ke::task::KeTaskId,
+            #attrs
+            extern "C" fn user_catch_rest_hndl(
+                msg_id: #sdk::platform::core_modules::ke::msg::KeMsgId,
+                param: *const core::ffi::c_void,
+                dest_id: #sdk::platform::core_modules::ke::task::KeTaskId,
+                src_id: #sdk::platform::core_modules::ke::task::KeTaskId,
             ) {
                 match msg_id as u32 {
-                    da14531_sdk::ble_stack::profiles::custom::custs::custs1::task::CUSTS1_VAL_WRITE_IND => {
-                        let param = param as *const da14531_sdk::ble_stack::profiles::custom::custs::custs1::task::Custs1ValWriteInd;
+                    #sdk::ble_stack::profiles::custom::custs::custs1::task::CUSTS1_VAL_WRITE_IND => {
+                        let param = param as *const #sdk::ble_stack::profiles::custom::custs::custs1::task::Custs1ValWriteInd;
                         let param = unsafe { &*param };
                         match param.handle {
                             #(#write_handlers),*
                             _ => {}
                         }
                     }
-                    da14531_sdk::ble_stack::profiles::custom::custs::custs1::task::CUSTS1_ATT_INFO_REQ => {
-                        let param = param as *const da14531_sdk::ble_stack::profiles::custom::custs::custs1::task::Custs1AttInfoReq;
+                    #sdk::ble_stack::profiles::custom::custs::custs1::task::CUSTS1_ATT_INFO_REQ => {
+                        let param = param as *const #sdk::ble_stack::profiles::custom::custs::custs1::task::Custs1AttInfoReq;
                         let param = unsafe { &*param };
                         let att_idx = param.att_idx;
 
                         match att_idx {
                             _ => {
-                                let mut response = da14531_sdk::ble_stack::profiles::custom::custs::custs1::task::KeMsgCusts1AttInfoRsp::new(src_id, dest_id);
+                                let mut response = #sdk::ble_stack::profiles::custom::custs::custs1::task::KeMsgCusts1AttInfoRsp::new(src_id, dest_id);
 
-                                let conidx = da14531_sdk::app_modules::app_env_get_conidx(param.conidx);
+                                let conidx = #sdk::app_modules::app_env_get_conidx(param.conidx);
 
                                 // Provide the connection index.
                                 response.fields().conidx = conidx;
@@ -889,24 +1270,24 @@ impl CustomServer1ServiceConfiguration {
                                 response.fields().length = 0;
 
                                 // Provide the ATT error code.
-                                response.fields().status = da14531_sdk::ble_stack::rwble_hl::error::HlErr::ATT_ERR_WRITE_NOT_PERMITTED as u8;
+                                response.fields().status = #sdk::ble_stack::rwble_hl::error::HlErr::ATT_ERR_WRITE_NOT_PERMITTED as u8;
 
                                 response.send();
                             }
                         }
                     }
-                    da14531_sdk::ble_stack::profiles::custom::custs::custs1::task::CUSTS1_VALUE_REQ_IND => {
-                        let param = param as *const da14531_sdk::ble_stack::profiles::custom::custs::custs1::task::Custs1ValueReqInd;
+                    #sdk::ble_stack::profiles::custom::custs::custs1::task::CUSTS1_VALUE_REQ_IND => {
+                        let param = param as *const #sdk::ble_stack::profiles::custom::custs::custs1::task::Custs1ValueReqInd;
                         let param = unsafe { &*param };
                         let att_idx = param.att_idx;
 
                         match att_idx {
                             #(#read_handlers),*
                             _ => {
-                                let mut response = da14531_sdk::ble_stack::profiles::custom::custs::custs1::task::KeMsgCusts1ValueReqRsp::new(dest_id, src_id);
+                                let mut response = #sdk::ble_stack::profiles::custom::custs::custs1::task::KeMsgCusts1ValueReqRsp::new(dest_id, src_id);
 
                                 // Provide the connection index.
-                                response.fields().conidx = da14531_sdk::app_modules::app_env_get_conidx(param.conidx);
+                                response.fields().conidx = #sdk::app_modules::app_env_get_conidx(param.conidx);
 
                                 // Provide the attribute index.
                                 response.fields().att_idx = param.att_idx;
@@ -915,7 +1296,7 @@ impl CustomServer1ServiceConfiguration {
                                 response.fields().length = 0;
 
                                 // Provide the ATT error code.
-                                response.fields().status = da14531_sdk::ble_stack::rwble_hl::error::HlErr::ATT_ERR_APP_ERROR as u8;
+                                response.fields().status = #sdk::ble_stack::rwble_hl::error::HlErr::ATT_ERR_APP_ERROR as u8;
 
                                 response.send();
                             }
@@ -950,15 +1331,83 @@ impl CustomServer1ServiceConfiguration {
         ))
     }
 
+    /// Generates `custs1_sim`, which passes the requests of a simulated peer to
+    /// `user_catch_rest_hndl`.
+    fn generate_host_sim(&self) -> proc_macro2::TokenStream {
+        let vis = &self.vis;
+
+        quote!(
+            /// Requests of a simulated peer, see `host_sim!`.
+            ///
+            /// Each request returns the messages sent since the last `take_sent`, which include
+            /// the response.
+            #vis mod custs1_sim {
+                use crate::host_sim::{
+                    ble_stack::profiles::custom::custs::custs1::task::{
+                        Custs1AttInfoReq, Custs1ValWriteInd, Custs1ValueReqInd,
+                        CUSTS1_ATT_INFO_REQ, CUSTS1_VALUE_REQ_IND, CUSTS1_VAL_WRITE_IND,
+                    },
+                    platform::core_modules::rwip::{TASK_APP, TASK_CUSTS1},
+                    Message, Value,
+                };
+
+                /// Writes `value` to the attribute at `att_idx`.
+                pub fn write(conidx: u8, att_idx: u16, value: &[u8]) -> std::vec::Vec<Message> {
+                    let param = Custs1ValWriteInd {
+                        conidx,
+                        handle: att_idx,
+                        length: value.len() as u16,
+                        value: Value(value.to_vec()),
+                    };
+
+                    dispatch(CUSTS1_VAL_WRITE_IND, &param)
+                }
+
+                /// Reads the attribute at `att_idx`.
+                pub fn read(conidx: u8, att_idx: u16) -> std::vec::Vec<Message> {
+                    dispatch(CUSTS1_VALUE_REQ_IND, &Custs1ValueReqInd { conidx, att_idx })
+                }
+
+                /// Requests the length of the attribute at `att_idx`.
+                pub fn att_info(conidx: u8, att_idx: u16) -> std::vec::Vec<Message> {
+                    dispatch(CUSTS1_ATT_INFO_REQ, &Custs1AttInfoReq { conidx, att_idx })
+                }
+
+                fn dispatch<T>(msg_id: u32, param: &T) -> std::vec::Vec<Message> {
+                    super::user_catch_rest_hndl(
+                        msg_id as _,
+                        param as *const T as *const _,
+                        TASK_APP,
+                        TASK_CUSTS1,
+                    );
+
+                    crate::host_sim::take_sent()
+                }
+            }
+        )
+    }
+
     pub fn generate(&mut self) -> syn::Result<proc_macro2::TokenStream> {
         let att_db = self.generate_att_db()?;
 
         let user_catch_rest_handler = self.generate_user_catch_rest_handler()?;
 
+        let host_sim = if self.host_sim {
+            self.generate_host_sim()
+        } else {
+            quote!()
+        };
+
+        let guard = once_per_crate("app_custs1");
+
         Ok(quote!(
+            #guard
+
             #att_db
 
             #user_catch_rest_handler
+
+            #host_sim
         ))
     }
 }
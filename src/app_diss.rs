@@ -1,29 +1,53 @@
+use proc_macro2::Ident;
 use quote::quote;
 use syn::{
     parse::{Parse, ParseStream},
-    punctuated::Punctuated,
-    Expr, FieldValue, Lit, Member, Token,
+    spanned::Spanned,
+    Error, Expr, ExprLit, Lit, LitStr, Path, Token,
 };
 
+use crate::{
+    diagnostic::{emit, emit_err, once_per_crate, unknown_field, SeenFields},
+    options::{Options, Sdk},
+    profile::{generate_create_db, generate_process_handler, MessageHandlers, ProcessHandlerEntry},
+};
+
+const FIELDS: [&str; 11] = [
+    "manufacturer_name",
+    "model_nb_str",
+    "system_id",
+    "pnp_id",
+    "hard_rev_str",
+    "serial_nb_str",
+    "firm_rev_str",
+    "sw_rev_str",
+    "ieee",
+    "on_value_req",
+    "handlers",
+];
+
 #[derive(Debug)]
 pub struct DeviceInformationServiceConfiguration {
-    manufacturer_name: Option<Expr>,
-    model_nb_str: Option<Expr>,
-    system_id: Option<Expr>,
-    pnp_id: Option<Expr>,
-    hard_rev_str: Option<Expr>,
-    serial_nb_str: Option<Expr>,
-    firm_rev_str: Option<Expr>,
-    sw_rev_str: Option<Expr>,
-    ieee: Option<Expr>,
+    sdk: Sdk,
+    manufacturer_name: Option<LitStr>,
+    model_nb_str: Option<LitStr>,
+    system_id: Option<LitStr>,
+    pnp_id: Option<LitStr>,
+    hard_rev_str: Option<LitStr>,
+    serial_nb_str: Option<LitStr>,
+    firm_rev_str: Option<LitStr>,
+    sw_rev_str: Option<LitStr>,
+    ieee: Option<LitStr>,
+    /// Handler of `DISS_VALUE_REQ_IND`, called before the generated answer and returning whether
+    /// to send it, e.g. to log or rate-limit reads.
+    on_value_req: Option<Path>,
+    handlers: MessageHandlers,
 }
 
 impl Parse for DeviceInformationServiceConfiguration {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        let fields: Punctuated<FieldValue, Token![,]> =
-            input.parse_terminated(FieldValue::parse)?;
-
         let mut app_diss_values = Self {
+            sdk: Options::parse_sdk(input)?,
             manufacturer_name: None,
             model_nb_str: None,
             system_id: None,
@@ -33,93 +57,46 @@ impl Parse for DeviceInformationServiceConfiguration {
             firm_rev_str: None,
             sw_rev_str: None,
             ieee: None,
+            on_value_req: None,
+            handlers: MessageHandlers::default(),
         };
 
-        for field in fields {
-            let key = match field.member {
-                Member::Named(name) => name,
-                Member::Unnamed(unnamed) => {
-                    panic!("Unexpected unnamed field: {:?}", unnamed);
-                }
-            };
-            let value = field.expr;
-            match key.to_string().as_str() {
-                "manufacturer_name" => match &value {
-                    Expr::Lit(_) => {
-                        app_diss_values.manufacturer_name = Some(value);
-                    }
-                    _ => {
-                        panic!("Unextpected expression for manufacturer_name: {:?}", value);
-                    }
-                },
-                "model_nb_str" => match &value {
-                    Expr::Lit(_) => {
-                        app_diss_values.model_nb_str = Some(value);
-                    }
-                    _ => {
-                        panic!("Unextpected expression for model_nb_str: {:?}", value);
-                    }
-                },
-                "system_id" => match &value {
-                    Expr::Lit(_) => {
-                        app_diss_values.system_id = Some(value);
-                    }
-                    _ => {
-                        panic!("Unextpected expression for system_id: {:?}", value);
-                    }
-                },
-                "pnp_id" => match &value {
-                    Expr::Lit(_) => {
-                        app_diss_values.pnp_id = Some(value);
-                    }
-                    _ => {
-                        panic!("Unextpected expression for pnp_id: {:?}", value);
-                    }
-                },
-                "hard_rev_str" => match &value {
-                    Expr::Lit(_) => {
-                        app_diss_values.hard_rev_str = Some(value);
-                    }
-                    _ => {
-                        panic!("Unextpected expression for hard_rev_str: {:?}", value);
-                    }
-                },
-                "serial_nb_str" => match &value {
-                    Expr::Lit(_) => {
-                        app_diss_values.serial_nb_str = Some(value);
-                    }
-                    _ => {
-                        panic!("Unextpected expression for serial_nb_str: {:?}", value);
-                    }
-                },
-                "firm_rev_str" => match &value {
-                    Expr::Lit(_) => {
-                        app_diss_values.firm_rev_str = Some(value);
-                    }
-                    _ => {
-                        panic!("Unextpected expression for firm_rev_str: {:?}", value);
-                    }
-                },
-                "sw_rev_str" => match &value {
-                    Expr::Lit(_) => {
-                        app_diss_values.sw_rev_str = Some(value);
-                    }
-                    _ => {
-                        panic!("Unextpected expression for sw_rev_str: {:?}", value);
-                    }
-                },
-                "ieee" => match &value {
-                    Expr::Lit(_) => {
-                        app_diss_values.ieee = Some(value);
-                    }
-                    _ => {
-                        panic!("Unextpected expression for ieee: {:?}", value);
+        let mut seen = SeenFields::default();
+
+        while !input.is_empty() {
+            let key: Ident = input.parse()?;
+            let _colon_token: Token![:] = input.parse()?;
+            let is_new = emit_err(seen.insert(&key)).is_some();
+
+            if key == "handlers" {
+                if let Some(MessageHandlers(handlers)) = MessageHandlers::parse_field(input)? {
+                    // The generated task answers `DISS_VALUE_REQ_IND` itself, so a handler for it
+                    // has its own signature and goes through `on_value_req`.
+                    let (value_req, handlers): (Vec<_>, Vec<_>) =
+                        handlers.into_iter().partition(|handler| {
+                            handler.msg_id.segments.last().unwrap().ident == "DISS_VALUE_REQ_IND"
+                        });
+
+                    for handler in value_req {
+                        emit(Error::new_spanned(
+                            handler.msg_id,
+                            "`DISS_VALUE_REQ_IND` is answered by the generated task, use \
+                             `on_value_req` to handle it",
+                        ));
                     }
-                },
-                _ => {
-                    panic!("Unexpected field: {} = {:?}", key.to_string(), value);
+                    app_diss_values.handlers = MessageHandlers(handlers);
                 }
+            } else {
+                let value: Expr = input.parse()?;
+                if is_new {
+                    emit_err(app_diss_values.parse_field(&key, value));
+                }
+            }
+
+            if input.is_empty() {
+                break;
             }
+            let _comma_token: Token![,] = input.parse()?;
         }
 
         Ok(app_diss_values)
@@ -129,24 +106,8 @@ impl Parse for DeviceInformationServiceConfiguration {
 macro_rules! generate_handler {
     ($handlers: ident, $supported_chars: ident, $field: expr, $char: ident, $char_sup: ident) => {
         if let Some(field) = &$field {
-            let (field_data, field_len) = match field {
-                Expr::Lit(literal) => match &literal.lit {
-                    Lit::Str(str_lit) => (
-                        str_lit.value().as_bytes().to_vec(),
-                        str_lit.value().len() as u16,
-                    ),
-                    _ => panic!(
-                        "Invalid token for {}, expected string literal, got: {:?}",
-                        stringify!($field).replace("self.", ""),
-                        literal
-                    ),
-                },
-                _ => panic!(
-                    "Invalid token for {}, expected string literal, got: {:?}",
-                    stringify!($field).replace("self.", ""),
-                    field
-                ),
-            };
+            let field_data = field.value().into_bytes();
+            let field_len = field_data.len() as u16;
 
             $handlers.push(quote!(
                 $char => {
@@ -173,7 +134,43 @@ macro_rules! generate_handler {
 }
 
 impl DeviceInformationServiceConfiguration {
+    fn parse_field(&mut self, key: &Ident, value: Expr) -> syn::Result<()> {
+        let field = match key.to_string().as_str() {
+            "manufacturer_name" => &mut self.manufacturer_name,
+            "model_nb_str" => &mut self.model_nb_str,
+            "system_id" => &mut self.system_id,
+            "pnp_id" => &mut self.pnp_id,
+            "hard_rev_str" => &mut self.hard_rev_str,
+            "serial_nb_str" => &mut self.serial_nb_str,
+            "firm_rev_str" => &mut self.firm_rev_str,
+            "sw_rev_str" => &mut self.sw_rev_str,
+            "ieee" => &mut self.ieee,
+            "on_value_req" => {
+                return match value {
+                    Expr::Path(path) => {
+                        self.on_value_req = Some(path.path);
+                        Ok(())
+                    }
+                    _ => Err(Error::new(value.span(), "expected path")),
+                };
+            }
+            _ => return Err(unknown_field(key, &FIELDS)),
+        };
+
+        match value {
+            Expr::Lit(ExprLit {
+                lit: Lit::Str(lit_str),
+                ..
+            }) => {
+                *field = Some(lit_str);
+                Ok(())
+            }
+            _ => Err(Error::new(value.span(), "expected string literal")),
+        }
+    }
+
     pub fn generate(&self) -> proc_macro2::TokenStream {
+        let sdk = &self.sdk;
         let mut handlers = Vec::new();
         let mut supported_chars = Vec::new();
 
@@ -241,11 +238,49 @@ impl DeviceInformationServiceConfiguration {
             DIS_SYSTEM_ID_CHAR_SUP
         );
 
+        let (handler_wrappers, handler_entries) = self.handlers.generate_wrappers(sdk, "diss");
+
+        // If the handler vetoes the generated answer, it has to answer the request itself.
+        let value_req_handler = self.on_value_req.as_ref().map(|handler| {
+            quote!(
+                let answer: bool = #handler(param, dest_id, src_id);
+                if !answer {
+                    return KE_MSG_CONSUMED as i32;
+                }
+            )
+        });
+
+        let mut process_handler_entries = vec![ProcessHandlerEntry {
+            msg_id: quote!(DISS_VALUE_REQ_IND),
+            func: quote!(diss_value_req_ind_handler),
+        }];
+        process_handler_entries.extend(handler_entries);
+
+        let process_handler = generate_process_handler(sdk, "diss", &process_handler_entries);
+
+        let create_db = generate_create_db(
+            sdk,
+            "diss",
+            &quote!(TASK_ID_DISS),
+            &quote!(DissDbCfg),
+            0,
+            quote!(
+                db_cfg.features = 0;
+
+                #(#supported_chars)*
+            ),
+        );
+
+        let guard = once_per_crate("app_diss");
+
         quote!(
+            #guard
+
             mod app_diss_task {
-                use da14531_sdk::{
-                    bindings,
-                    app_modules::ProcessEventResponse,
+                #[allow(unused_imports)]
+                use super::*;
+
+                use #sdk::{
                     ble_stack::profiles::dis::diss::{
                         task::{DissValueReqInd, KeMsgDynDissValueCfm, DISS_VALUE_REQ_IND},
                         DIS_MANUFACTURER_NAME_CHAR, DIS_MODEL_NB_STR_CHAR, DIS_PNP_ID_CHAR,
@@ -254,21 +289,23 @@ impl DeviceInformationServiceConfiguration {
 
                     },
                     platform::core_modules::ke::{
-                        msg::{KeMsgHandler, KeMsgId, KeMsgStatusTag, KE_MSG_CONSUMED},
+                        msg::{KeMsgId, KE_MSG_CONSUMED},
                         task::KeTaskId,
                     },
                 };
 
 
-                #[no_mangle]
-                pub extern "C" fn diss_value_req_ind_handler(
+                extern "C" fn diss_value_req_ind_handler(
                     _msg_id: KeMsgId,
-                    param: *const cty::c_void,
+                    param: *const core::ffi::c_void,
                     dest_id: KeTaskId,
                     src_id: KeTaskId,
                 ) -> i32 {
                     let param = param as *const DissValueReqInd;
                     let param = unsafe { &*param };
+
+                    #value_req_handler
+
                     match param.value as u32 {
                         #(#handlers)*
                         _ => {
@@ -284,75 +321,29 @@ impl DeviceInformationServiceConfiguration {
                     KE_MSG_CONSUMED as i32
                 }
 
-                static APP_DISS_PROCESS_HANDLERS: [KeMsgHandler; 1] = [KeMsgHandler {
-                    id: DISS_VALUE_REQ_IND as u16,
-                    func: Some(diss_value_req_ind_handler),
-                }];
+                #(#handler_wrappers)*
 
-                #[no_mangle]
-                pub extern "C" fn app_diss_process_handler(
-                    msg_id: KeMsgId,
-                    param: *const cty::c_void,
-                    dest_id: KeTaskId,
-                    src_id: KeTaskId,
-                    msg_ret: *mut KeMsgStatusTag,
-                ) -> ProcessEventResponse {
-                    return unsafe {
-                        bindings::app_std_process_event(
-                            msg_id,
-                            param,
-                            src_id,
-                            dest_id,
-                            msg_ret,
-                            APP_DISS_PROCESS_HANDLERS.as_ptr() as *mut _,
-                            APP_DISS_PROCESS_HANDLERS.len() as i32,
-                        )
-                    };
-                }
+                #process_handler
             }
 
             mod app_diss {
-                use da14531_sdk::{
-                    app_modules::get_user_prf_srv_perm,
-                    ble_stack::{
-                        host::gap::gapm::task::{KeMsgDynGapmProfileTaskAdd, GAPM_PROFILE_TASK_ADD},
-                        profiles::dis::diss::{
-                            DissDbCfg, DIS_FIRM_REV_STR_CHAR_SUP, DIS_HARD_REV_STR_CHAR_CHAR_SUP,
-                            DIS_IEEE_CHAR_CHAR_SUP, DIS_MANUFACTURER_NAME_CHAR_SUP,
-                            DIS_MODEL_NB_STR_CHAR_SUP, DIS_PNP_ID_CHAR_SUP,
-                            DIS_SERIAL_NB_STR_CHAR_SUP, DIS_SW_REV_STR_CHAR_SUP,
-                            DIS_SYSTEM_ID_CHAR_SUP,
-                        },
+                use #sdk::{
+                    ble_stack::profiles::dis::diss::{
+                        DissDbCfg, DIS_FIRM_REV_STR_CHAR_SUP, DIS_HARD_REV_STR_CHAR_CHAR_SUP,
+                        DIS_IEEE_CHAR_CHAR_SUP, DIS_MANUFACTURER_NAME_CHAR_SUP,
+                        DIS_MODEL_NB_STR_CHAR_SUP, DIS_PNP_ID_CHAR_SUP,
+                        DIS_SERIAL_NB_STR_CHAR_SUP, DIS_SW_REV_STR_CHAR_SUP,
+                        DIS_SYSTEM_ID_CHAR_SUP,
                     },
-                    platform::core_modules::rwip::{TASK_APP, TASK_GAPM, TASK_ID_DISS},
+                    platform::core_modules::rwip::TASK_ID_DISS,
                 };
 
                 #[no_mangle]
-                pub extern "C" fn app_dis_init() {
+                extern "C" fn app_dis_init() {
                     // Nothing to do
                 }
 
-                #[no_mangle]
-                pub extern "C" fn app_diss_create_db() {
-                    const SIZE: u16 = core::mem::size_of::<DissDbCfg>() as u16;
-                    let mut msg = KeMsgDynGapmProfileTaskAdd::<SIZE>::new(TASK_APP as u16, TASK_GAPM as u16);
-
-                    msg.fields().operation = GAPM_PROFILE_TASK_ADD as u8;
-                    msg.fields().sec_lvl = get_user_prf_srv_perm(TASK_ID_DISS) as u8;
-                    msg.fields().prf_task_id = TASK_ID_DISS as u16;
-                    msg.fields().app_task = TASK_APP as u16;
-                    msg.fields().start_hdl = 0;
-
-                    let db_cfg_ptr = &mut msg.fields().param as *mut _ as *mut DissDbCfg;
-
-                    let db_cfg = unsafe { db_cfg_ptr.as_mut().unwrap() };
-
-                    db_cfg.features = 0;
-
-                    #(#supported_chars)*
-
-                    msg.send();
-                }
+                #create_db
 
             }
         )
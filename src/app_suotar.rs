@@ -0,0 +1,571 @@
+use proc_macro2::{Ident, Span};
+use quote::quote;
+use syn::{
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    spanned::Spanned,
+    Error, Expr, ExprRange, FieldValue, Lit, Member, Path, RangeLimits, Token, Visibility,
+};
+
+use crate::{
+    diagnostic::{emit, emit_err, once_per_crate, unknown_field, SeenFields},
+    options::{Options, Sdk},
+    profile::{generate_create_db, generate_process_handler, ProcessHandlerEntry},
+};
+
+/// Size of an erasable SPI flash sector.
+const SPI_FLASH_SECTOR_SIZE: u32 = 0x1000;
+
+/// Largest address reachable through the 24 bit base address of `SUOTAR_PATCH_MEM_DEV_IND`.
+const SUOTAR_MAX_ADDRESS: u32 = 0x0100_0000;
+
+const FIELDS: [&str; 6] = [
+    "patch_memory",
+    "patch_region",
+    "max_patch_len",
+    "on_suota_start",
+    "on_suota_status",
+    "on_suota_finished",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PatchMemory {
+    SpiFlash,
+    I2cEeprom,
+}
+
+#[derive(Debug)]
+pub struct SuotaServiceConfiguration {
+    sdk: Sdk,
+    vis: Visibility,
+    patch_memory: PatchMemory,
+    patch_region: (u32, u32),
+    max_patch_len: u16,
+    on_suota_start: Option<Path>,
+    on_suota_status: Option<Path>,
+    on_suota_finished: Option<Path>,
+}
+
+/// Fields as given, along with the spans of the values checked against each other.
+#[derive(Default)]
+struct SuotaServiceFields {
+    patch_memory: Option<PatchMemory>,
+    patch_region: Option<((u32, u32), Span)>,
+    max_patch_len: Option<(u16, Span)>,
+    on_suota_start: Option<Path>,
+    on_suota_status: Option<Path>,
+    on_suota_finished: Option<Path>,
+}
+
+fn parse_u32(expr: &Expr) -> syn::Result<u32> {
+    match expr {
+        Expr::Lit(literal) => match &literal.lit {
+            Lit::Int(lit_int) => lit_int.base10_parse(),
+            _ => Err(Error::new(expr.span(), "expected integer literal")),
+        },
+        _ => Err(Error::new(expr.span(), "expected integer literal")),
+    }
+}
+
+fn parse_patch_region(range: &ExprRange) -> syn::Result<(u32, u32)> {
+    let start = match &range.from {
+        Some(from) => parse_u32(from)?,
+        None => return Err(Error::new(range.span(), "missing start address")),
+    };
+    let end = match &range.to {
+        Some(to) => parse_u32(to)?,
+        None => return Err(Error::new(range.span(), "missing end address")),
+    };
+    let end = match range.limits {
+        RangeLimits::HalfOpen(_) => end,
+        // Saturating is fine, anything that large is rejected below.
+        RangeLimits::Closed(_) => end.saturating_add(1),
+    };
+
+    if start >= end {
+        return Err(Error::new(range.span(), "patch region is empty"));
+    }
+
+    if end > SUOTAR_MAX_ADDRESS {
+        return Err(Error::new(
+            range.span(),
+            format!("patch region must end at or below {SUOTAR_MAX_ADDRESS:#x}"),
+        ));
+    }
+
+    Ok((start, end))
+}
+
+impl Parse for SuotaServiceConfiguration {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let span = input.span();
+        let Options { sdk, vis } = Options::parse(input)?;
+        let fields: Punctuated<FieldValue, Token![,]> =
+            input.parse_terminated(FieldValue::parse)?;
+
+        let mut values = SuotaServiceFields::default();
+        let mut seen = SeenFields::default();
+
+        for field in fields {
+            let key = match field.member {
+                Member::Named(name) => name,
+                Member::Unnamed(unnamed) => {
+                    emit(Error::new(unnamed.span(), "unexpected unnamed field"));
+                    continue;
+                }
+            };
+            if emit_err(seen.insert(&key)).is_some() {
+                emit_err(values.parse_field(&key, field.expr));
+            }
+        }
+
+        // Fields given with an invalid value have been reported already, the placeholders only let
+        // the other problems be reported as well.
+        let missing = |field: &str| {
+            if !seen.contains(field) {
+                emit(Error::new(span, format!("missing `{field}`")));
+            }
+        };
+
+        if values.patch_memory.is_none() {
+            missing("patch_memory");
+        }
+        if values.patch_region.is_none() {
+            missing("patch_region");
+        }
+        if values.max_patch_len.is_none() {
+            missing("max_patch_len");
+        }
+
+        if let (Some(((start, end), _)), Some((max_patch_len, max_patch_len_span))) =
+            (values.patch_region, values.max_patch_len)
+        {
+            if max_patch_len as u32 > end - start {
+                emit(Error::new(
+                    max_patch_len_span,
+                    "`max_patch_len` exceeds the size of `patch_region`",
+                ));
+            }
+        }
+
+        if let (Some(PatchMemory::SpiFlash), Some(((start, end), region_span))) =
+            (values.patch_memory, values.patch_region)
+        {
+            if start % SPI_FLASH_SECTOR_SIZE != 0 || end % SPI_FLASH_SECTOR_SIZE != 0 {
+                emit(Error::new(
+                    region_span,
+                    format!("SPI flash `patch_region` must be aligned to {SPI_FLASH_SECTOR_SIZE:#x} byte sectors"),
+                ));
+            }
+        }
+
+        Ok(Self {
+            sdk,
+            vis,
+            patch_memory: values.patch_memory.unwrap_or(PatchMemory::SpiFlash),
+            patch_region: values.patch_region.map_or((0, 0), |(region, _)| region),
+            max_patch_len: values
+                .max_patch_len
+                .map_or(0, |(max_patch_len, _)| max_patch_len),
+            on_suota_start: values.on_suota_start,
+            on_suota_status: values.on_suota_status,
+            on_suota_finished: values.on_suota_finished,
+        })
+    }
+}
+
+impl SuotaServiceFields {
+    fn parse_field(&mut self, key: &Ident, value: Expr) -> syn::Result<()> {
+        match key.to_string().as_str() {
+            "patch_memory" => match &value {
+                Expr::Path(path) if path.path.is_ident("SpiFlash") => {
+                    self.patch_memory = Some(PatchMemory::SpiFlash);
+                }
+                Expr::Path(path) if path.path.is_ident("I2cEeprom") => {
+                    self.patch_memory = Some(PatchMemory::I2cEeprom);
+                }
+                _ => {
+                    return Err(Error::new(
+                        value.span(),
+                        "expected `SpiFlash` or `I2cEeprom`",
+                    ));
+                }
+            },
+            "patch_region" => match &value {
+                Expr::Range(range) => {
+                    self.patch_region = Some((parse_patch_region(range)?, range.span()));
+                }
+                _ => {
+                    return Err(Error::new(
+                        value.span(),
+                        "expected address range, e.g. `0x20000..0x38000`",
+                    ));
+                }
+            },
+            "max_patch_len" => {
+                let max_patch_len = parse_u32(&value)?;
+                if max_patch_len == 0 || max_patch_len > u16::MAX as u32 {
+                    return Err(Error::new(
+                        value.span(),
+                        format!("`max_patch_len` must be between 1 and {}", u16::MAX),
+                    ));
+                }
+                self.max_patch_len = Some((max_patch_len as u16, value.span()));
+            }
+            "on_suota_start" => match value {
+                Expr::Path(path) => {
+                    self.on_suota_start = Some(path.path);
+                }
+                _ => return Err(Error::new(value.span(), "expected path")),
+            },
+            "on_suota_status" => match value {
+                Expr::Path(path) => {
+                    self.on_suota_status = Some(path.path);
+                }
+                _ => return Err(Error::new(value.span(), "expected path")),
+            },
+            "on_suota_finished" => match value {
+                Expr::Path(path) => {
+                    self.on_suota_finished = Some(path.path);
+                }
+                _ => return Err(Error::new(value.span(), "expected path")),
+            },
+            _ => return Err(unknown_field(key, &FIELDS)),
+        }
+
+        Ok(())
+    }
+}
+
+impl SuotaServiceConfiguration {
+    pub fn generate(&self) -> proc_macro2::TokenStream {
+        let sdk = &self.sdk;
+        let vis = &self.vis;
+        let patch_memory = self.patch_memory;
+        let (region_start, region_end) = self.patch_region;
+        let max_patch_len = self.max_patch_len;
+
+        let in_region = quote!((PATCH_REGION_START..PATCH_REGION_END).contains(&base_address));
+        let (features, img_mem_dev, valid_base_address, write_data) = match patch_memory {
+            PatchMemory::SpiFlash => (
+                quote!(SUOTAR_SPI_SUP),
+                quote!(SUOTAR_IMG_SPI_FLASH),
+                quote!(#in_region && base_address % #SPI_FLASH_SECTOR_SIZE == 0),
+                quote!(
+                    // Erase every sector that starts inside the written range before it is
+                    // first used. The image starts on a sector boundary, so this covers all of
+                    // them.
+                    let mut sector = (address + #SPI_FLASH_SECTOR_SIZE - 1) / #SPI_FLASH_SECTOR_SIZE
+                        * #SPI_FLASH_SECTOR_SIZE;
+                    while sector < address + data.len() as u32 {
+                        unsafe {
+                            bindings::spi_flash_block_erase(sector, bindings::SPI_FLASH_OP_SE);
+                        }
+                        sector += #SPI_FLASH_SECTOR_SIZE;
+                    }
+
+                    let mut written = 0u32;
+                    let result = unsafe {
+                        bindings::spi_flash_write_data(
+                            data.as_ptr() as *mut u8,
+                            address,
+                            data.len() as u32,
+                            &mut written,
+                        )
+                    };
+
+                    result >= 0 && written == data.len() as u32
+                ),
+            ),
+            PatchMemory::I2cEeprom => (
+                quote!(SUOTAR_I2C_SUP),
+                quote!(SUOTAR_IMG_I2C_EEPROM),
+                in_region,
+                quote!(
+                    let mut written = 0u32;
+                    let result = unsafe {
+                        bindings::i2c_eeprom_write_data(
+                            data.as_ptr() as *mut u8,
+                            address,
+                            data.len() as u32,
+                            &mut written,
+                        )
+                    };
+
+                    result == bindings::I2C_NO_ERROR && written == data.len() as u32
+                ),
+            ),
+        };
+
+        let on_suota_start = match &self.on_suota_start {
+            Some(on_suota_start) => quote!(#on_suota_start();),
+            None => quote!(),
+        };
+        let on_suota_status = match &self.on_suota_status {
+            Some(on_suota_status) => quote!(#on_suota_status(status);),
+            None => quote!(),
+        };
+        let on_suota_finished = match &self.on_suota_finished {
+            Some(on_suota_finished) => quote!(#on_suota_finished(success);),
+            None => quote!(let _ = success;),
+        };
+
+        let process_handler = generate_process_handler(
+            sdk,
+            "suotar",
+            &[
+                ProcessHandlerEntry {
+                    msg_id: quote!(SUOTAR_PATCH_MEM_DEV_IND),
+                    func: quote!(suotar_patch_mem_dev_ind_handler),
+                },
+                ProcessHandlerEntry {
+                    msg_id: quote!(SUOTAR_GPIO_MAP_IND),
+                    func: quote!(suotar_gpio_map_ind_handler),
+                },
+                ProcessHandlerEntry {
+                    msg_id: quote!(SUOTAR_PATCH_LEN_IND),
+                    func: quote!(suotar_patch_len_ind_handler),
+                },
+                ProcessHandlerEntry {
+                    msg_id: quote!(SUOTAR_PATCH_DATA_IND),
+                    func: quote!(suotar_patch_data_ind_handler),
+                },
+            ],
+        );
+
+        let create_db = generate_create_db(
+            sdk,
+            "suotar",
+            &quote!(TASK_ID_SUOTAR),
+            &quote!(SuotarDbCfg),
+            0,
+            quote!(
+                db_cfg.features = #features as u8;
+            ),
+        );
+
+        let guard = once_per_crate("app_suotar");
+
+        quote!(
+            #guard
+
+            /// Patch memory region and block size the SUOTA service was configured with.
+            #vis mod suota {
+                /// First address of the patch memory region.
+                pub const PATCH_REGION_START: u32 = #region_start;
+                /// Address following the last byte of the patch memory region.
+                pub const PATCH_REGION_END: u32 = #region_end;
+                /// Largest block the peer may announce through `SUOTAR_PATCH_LEN_IND`.
+                pub const MAX_PATCH_LEN: u16 = #max_patch_len;
+            }
+
+            mod app_suotar_task {
+                use super::suota::{MAX_PATCH_LEN, PATCH_REGION_END, PATCH_REGION_START};
+                use #sdk::{
+                    bindings,
+                    ble_stack::profiles::{
+                        prf_get_task_from_id,
+                        suota::suotar::{
+                            task::{
+                                KeMsgSuotarStatusUpdateReq, SuotarPatchDataInd, SuotarPatchLenInd,
+                                SuotarPatchMemDevInd, SUOTAR_GPIO_MAP_IND, SUOTAR_PATCH_DATA_IND,
+                                SUOTAR_PATCH_LEN_IND, SUOTAR_PATCH_MEM_DEV_IND,
+                            },
+                            SUOTAR_CMP_OK, SUOTAR_CRC_ERR, SUOTAR_EXT_MEM_WRITE_ERR,
+                            SUOTAR_IMG_END, SUOTAR_IMG_STARTED, SUOTAR_INVAL_MEM_TYPE,
+                            SUOTAR_MEM_SERVICE_EXIT, SUOTAR_PATCH_LEN_ERR, SUOTAR_REBOOT,
+                            SUOTAR_SRV_EXIT, #img_mem_dev,
+                        },
+                    },
+                    platform::core_modules::{
+                        ke::{
+                            msg::{KeMsgId, KE_MSG_CONSUMED},
+                            task::KeTaskId,
+                        },
+                        rwip::{TASK_APP, TASK_ID_SUOTAR},
+                    },
+                };
+
+                struct SuotaState {
+                    active: bool,
+                    address: u32,
+                    patch_len: u16,
+                    block_received: u16,
+                    crc: u8,
+                }
+
+                static mut SUOTA_STATE: SuotaState = SuotaState {
+                    active: false,
+                    address: PATCH_REGION_START,
+                    patch_len: 0,
+                    block_received: 0,
+                    crc: 0,
+                };
+
+                /// State of the update. The kernel runs one message handler at a time and each
+                /// handler takes it once, so the reference is never aliased.
+                fn suota_state() -> &'static mut SuotaState {
+                    unsafe { &mut *core::ptr::addr_of_mut!(SUOTA_STATE) }
+                }
+
+                fn send_status(conidx: u8, status: u8) {
+                    let mut msg = KeMsgSuotarStatusUpdateReq::new(
+                        TASK_APP as u16,
+                        prf_get_task_from_id(TASK_ID_SUOTAR as u16),
+                    );
+
+                    msg.fields().conidx = conidx;
+                    msg.fields().status = status;
+
+                    msg.send();
+
+                    #on_suota_status
+                }
+
+                fn finish(state: &mut SuotaState, conidx: u8, success: bool, status: u8) {
+                    state.active = false;
+
+                    unsafe { bindings::app_suotar_stop() };
+
+                    send_status(conidx, status);
+
+                    #on_suota_finished
+                }
+
+                fn write_data(address: u32, data: &[u8]) -> bool {
+                    #write_data
+                }
+
+                extern "C" fn suotar_patch_mem_dev_ind_handler(
+                    _msg_id: KeMsgId,
+                    param: *const core::ffi::c_void,
+                    _dest_id: KeTaskId,
+                    _src_id: KeTaskId,
+                ) -> i32 {
+                    let param = param as *const SuotarPatchMemDevInd;
+                    let param = unsafe { &*param };
+                    let command = (param.mem_dev >> 24) as u8;
+                    let base_address = param.mem_dev & 0x00FF_FFFF;
+                    let state = suota_state();
+
+                    match command as u32 {
+                        #img_mem_dev => {
+                            if !(#valid_base_address) {
+                                send_status(param.conidx, SUOTAR_INVAL_MEM_TYPE as u8);
+                            } else {
+                                #on_suota_start
+
+                                unsafe { bindings::app_suotar_start() };
+
+                                state.active = true;
+                                state.address = base_address;
+                                state.patch_len = 0;
+                                state.block_received = 0;
+                                state.crc = 0;
+
+                                send_status(param.conidx, SUOTAR_IMG_STARTED as u8);
+                            }
+                        }
+                        SUOTAR_IMG_END if state.active => {
+                            if state.crc == 0 {
+                                finish(state, param.conidx, true, SUOTAR_CMP_OK as u8);
+                            } else {
+                                finish(state, param.conidx, false, SUOTAR_CRC_ERR as u8);
+                            }
+                        }
+                        SUOTAR_MEM_SERVICE_EXIT => {
+                            finish(state, param.conidx, false, SUOTAR_SRV_EXIT as u8);
+                        }
+                        SUOTAR_REBOOT => unsafe {
+                            bindings::platform_reset(bindings::RESET_AFTER_SUOTA_UPDATE);
+                        },
+                        _ => {
+                            send_status(param.conidx, SUOTAR_INVAL_MEM_TYPE as u8);
+                        }
+                    }
+
+                    KE_MSG_CONSUMED as i32
+                }
+
+                extern "C" fn suotar_patch_len_ind_handler(
+                    _msg_id: KeMsgId,
+                    param: *const core::ffi::c_void,
+                    _dest_id: KeTaskId,
+                    _src_id: KeTaskId,
+                ) -> i32 {
+                    let param = param as *const SuotarPatchLenInd;
+                    let param = unsafe { &*param };
+                    let state = suota_state();
+
+                    if param.len == 0 || param.len > MAX_PATCH_LEN {
+                        send_status(param.conidx, SUOTAR_PATCH_LEN_ERR as u8);
+                    } else {
+                        state.patch_len = param.len;
+                        state.block_received = 0;
+                    }
+
+                    KE_MSG_CONSUMED as i32
+                }
+
+                extern "C" fn suotar_patch_data_ind_handler(
+                    _msg_id: KeMsgId,
+                    param: *const core::ffi::c_void,
+                    _dest_id: KeTaskId,
+                    _src_id: KeTaskId,
+                ) -> i32 {
+                    let param = param as *const SuotarPatchDataInd;
+                    let param = unsafe { &*param };
+                    let data = unsafe { param.pd.as_slice(param.len as usize) };
+                    let state = suota_state();
+
+                    if !state.active || state.patch_len == 0 {
+                        send_status(param.conidx, SUOTAR_PATCH_LEN_ERR as u8);
+                    } else if state.address + data.len() as u32 > PATCH_REGION_END
+                        || state.block_received as usize + data.len() > state.patch_len as usize
+                    {
+                        finish(state, param.conidx, false, SUOTAR_PATCH_LEN_ERR as u8);
+                    } else if !write_data(state.address, data) {
+                        finish(state, param.conidx, false, SUOTAR_EXT_MEM_WRITE_ERR as u8);
+                    } else {
+                        state.crc = data.iter().fold(state.crc, |crc, byte| crc ^ byte);
+                        state.address += data.len() as u32;
+                        state.block_received += data.len() as u16;
+
+                        if state.block_received == state.patch_len {
+                            state.block_received = 0;
+                            send_status(param.conidx, SUOTAR_CMP_OK as u8);
+                        }
+                    }
+
+                    KE_MSG_CONSUMED as i32
+                }
+
+                extern "C" fn suotar_gpio_map_ind_handler(
+                    _msg_id: KeMsgId,
+                    _param: *const core::ffi::c_void,
+                    _dest_id: KeTaskId,
+                    _src_id: KeTaskId,
+                ) -> i32 {
+                    // The patch memory pins are owned by the application configuration.
+                    KE_MSG_CONSUMED as i32
+                }
+
+                #process_handler
+            }
+
+            mod app_suotar {
+                use #sdk::{
+                    ble_stack::profiles::suota::suotar::{SuotarDbCfg, #features},
+                    platform::core_modules::rwip::TASK_ID_SUOTAR,
+                };
+
+                #[no_mangle]
+                extern "C" fn app_suotar_init() {
+                    // Nothing to do
+                }
+
+                #create_db
+            }
+        )
+    }
+}
@@ -0,0 +1,120 @@
+use quote::quote;
+use syn::{parse::Parse, Visibility};
+
+use crate::{
+    callback::{CallbackParam, CallbackRegistrations, CallbackSignature},
+    diagnostic::once_per_crate,
+    options::{Options, Sdk},
+};
+
+/// Prototypes of the SDK's `app_bond_db_callbacks` struct, in declaration order.
+fn bond_db_callback_signatures(sdk: &Sdk) -> Vec<CallbackSignature> {
+    let app_modules = quote!(#sdk::app_modules);
+    let gap = quote!(#sdk::ble_stack::host::gap);
+
+    vec![
+        CallbackSignature::new("app_bdb_init", vec![])
+            .with_default(quote!(#sdk::bindings::default_app_bdb_init)),
+        CallbackSignature::new("app_bdb_get_size", vec![])
+            .returns(quote!(u8))
+            .with_default(quote!(#sdk::bindings::default_app_bdb_get_size)),
+        CallbackSignature::new(
+            "app_bdb_add_entry",
+            vec![CallbackParam::by_mut(
+                "data",
+                quote!(#app_modules::AppSecBondDataEnvTag),
+            )],
+        )
+        .with_default(quote!(#sdk::bindings::default_app_bdb_add_entry)),
+        CallbackSignature::new(
+            "app_bdb_remove_entry",
+            vec![
+                CallbackParam::value("search_type", quote!(#app_modules::BdbSearchByType)),
+                CallbackParam::value("remove_type", quote!(#app_modules::BdbRemoveType)),
+                CallbackParam::slice("search_param", "search_param_length"),
+                CallbackParam::slice_len("search_param_length", quote!(u8)),
+            ],
+        )
+        .with_default(quote!(#sdk::bindings::default_app_bdb_remove_entry)),
+        CallbackSignature::new(
+            "app_bdb_search_entry",
+            vec![
+                CallbackParam::value("search_type", quote!(#app_modules::BdbSearchByType)),
+                CallbackParam::slice("search_param", "search_param_length"),
+                CallbackParam::slice_len("search_param_length", quote!(u8)),
+            ],
+        )
+        .returns_nullable_ref(quote!(#app_modules::AppSecBondDataEnvTag))
+        .with_default(quote!(#sdk::bindings::default_app_bdb_search_entry)),
+        CallbackSignature::new("app_bdb_get_number_of_stored_irks", vec![])
+            .returns(quote!(u8))
+            .with_default(quote!(
+                #sdk::bindings::default_app_bdb_get_number_of_stored_irks
+            )),
+        CallbackSignature::new(
+            "app_bdb_get_stored_irks",
+            vec![CallbackParam::array_mut(
+                "valid_irk_array",
+                quote!(#gap::GapSecKey),
+                quote!(#app_modules::APP_BOND_DB_MAX_BONDED_PEERS),
+            )],
+        )
+        .returns(quote!(u8))
+        .with_default(quote!(
+            #sdk::bindings::default_app_bdb_get_stored_irks
+        )),
+        CallbackSignature::new(
+            "app_bdb_get_device_info_from_slot",
+            vec![
+                CallbackParam::value("slot", quote!(u8)),
+                CallbackParam::by_mut("dev_info", quote!(#gap::GapRalDevInfo)),
+            ],
+        )
+        .returns(quote!(bool))
+        .with_default(quote!(
+            #sdk::bindings::default_app_bdb_get_device_info_from_slot
+        )),
+    ]
+}
+
+#[derive(Debug)]
+pub struct BondDbCallbacks {
+    sdk: Sdk,
+    vis: Visibility,
+    callbacks: CallbackRegistrations,
+}
+
+impl Parse for BondDbCallbacks {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let Options { sdk, vis } = Options::parse(input)?;
+        let callbacks = CallbackRegistrations::parse(input, &bond_db_callback_signatures(&sdk))?;
+
+        Ok(Self {
+            sdk,
+            vis,
+            callbacks,
+        })
+    }
+}
+
+impl BondDbCallbacks {
+    pub fn generate(&self) -> proc_macro2::TokenStream {
+        let Self { sdk, vis, .. } = self;
+        let (callback_wrappers, struct_fields) =
+            self.callbacks.generate(&bond_db_callback_signatures(sdk));
+
+        let guard = once_per_crate("user_app_bond_db_callbacks");
+
+        quote!(
+            #guard
+
+            #(#callback_wrappers)*
+
+            #[export_name = "user_app_bond_db_callbacks"]
+            #vis static USER_APP_BOND_DB_CALLBACKS: #sdk::app_modules::AppBondDbCallbacks =
+                #sdk::app_modules::AppBondDbCallbacks {
+                #(#struct_fields),*
+            };
+        )
+    }
+}
@@ -0,0 +1,728 @@
+use std::collections::HashMap;
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, quote_spanned};
+use syn::{
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    spanned::Spanned,
+    Attribute, Error, Expr, ExprBlock, ExprClosure, FieldValue, Ident, ItemFn, Member, Path, Token,
+};
+
+use crate::{
+    diagnostic::{did_you_mean, emit, emit_err, unknown_field, SeenFields},
+    options::Sdk,
+};
+
+/// Value registered for a callback: a function path, a non-capturing closure, for callbacks
+/// without parameters an inline block, or a list of those called in order.
+#[derive(Debug)]
+pub enum CallbackHandler {
+    Path(Path),
+    Closure(ExprClosure),
+    Block(ExprBlock),
+    List(Vec<CallbackHandler>),
+}
+
+impl CallbackHandler {
+    pub fn from_expr(expr: Expr) -> syn::Result<Self> {
+        match expr {
+            Expr::Path(path) => Ok(Self::Path(path.path)),
+            Expr::Closure(closure) => Ok(Self::Closure(closure)),
+            Expr::Block(block) => Ok(Self::Block(block)),
+            Expr::Array(array) if array.elems.is_empty() => {
+                Err(Error::new(array.span(), "expected at least one handler"))
+            }
+            Expr::Array(array) => array
+                .elems
+                .into_iter()
+                .map(|elem| match elem {
+                    Expr::Array(_) => Err(Error::new(
+                        elem.span(),
+                        "nested lists of handlers are not supported",
+                    )),
+                    _ => Self::from_expr(elem),
+                })
+                .collect::<syn::Result<_>>()
+                .map(Self::List),
+            _ => Err(Error::new(
+                expr.span(),
+                "expected function path, closure or list of handlers",
+            )),
+        }
+    }
+
+    pub fn contains_block(&self) -> bool {
+        match self {
+            Self::Block(_) => true,
+            Self::List(handlers) => handlers.iter().any(Self::contains_block),
+            _ => false,
+        }
+    }
+}
+
+/// Where the SDK default handler runs relative to the user's handler, selected with a
+/// `#[before_default]` or `#[after_default]` attribute on the field.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ChainDefault {
+    Before,
+    After,
+}
+
+/// How a C callback parameter is handed to the user function.
+#[derive(Debug, Clone)]
+pub enum ParamKind {
+    /// Passed through unchanged.
+    Value,
+    /// `*const T` in C, `&T` for the user function.
+    Ref,
+    /// `*mut T` in C, `&mut T` for the user function.
+    RefMut,
+    /// `void *` in C with its length in the named parameter, `&[u8]` for the user function.
+    Slice(&'static str),
+    /// Length of a [`ParamKind::Slice`], not passed to the user function.
+    SliceLen,
+    /// `*mut T` in C pointing at an array of the given length, `&mut [T]` for the user function.
+    ArrayMut(TokenStream),
+}
+
+#[derive(Debug)]
+pub struct CallbackParam {
+    pub name: &'static str,
+    pub ty: TokenStream,
+    pub kind: ParamKind,
+}
+
+impl CallbackParam {
+    pub fn value(name: &'static str, ty: TokenStream) -> Self {
+        Self {
+            name,
+            ty,
+            kind: ParamKind::Value,
+        }
+    }
+
+    pub fn by_ref(name: &'static str, ty: TokenStream) -> Self {
+        Self {
+            name,
+            ty,
+            kind: ParamKind::Ref,
+        }
+    }
+
+    pub fn by_mut(name: &'static str, ty: TokenStream) -> Self {
+        Self {
+            name,
+            ty,
+            kind: ParamKind::RefMut,
+        }
+    }
+
+    pub fn slice(name: &'static str, len: &'static str) -> Self {
+        Self {
+            name,
+            ty: quote!(u8),
+            kind: ParamKind::Slice(len),
+        }
+    }
+
+    pub fn slice_len(name: &'static str, ty: TokenStream) -> Self {
+        Self {
+            name,
+            ty,
+            kind: ParamKind::SliceLen,
+        }
+    }
+
+    pub fn array_mut(name: &'static str, ty: TokenStream, len: TokenStream) -> Self {
+        Self {
+            name,
+            ty,
+            kind: ParamKind::ArrayMut(len),
+        }
+    }
+
+    /// Whether the parameter is passed on to the user function.
+    pub fn is_user_param(&self) -> bool {
+        !matches!(self.kind, ParamKind::SliceLen)
+    }
+
+    /// Declaration of the parameter in the `extern "C"` wrapper.
+    pub fn c_decl(&self) -> TokenStream {
+        let name = format_ident!("{}", self.name);
+        let ty = &self.ty;
+
+        match self.kind {
+            ParamKind::Value | ParamKind::SliceLen => quote!(#name: #ty),
+            ParamKind::Ref => quote!(#name: *const #ty),
+            ParamKind::RefMut | ParamKind::ArrayMut(_) => quote!(#name: *mut #ty),
+            ParamKind::Slice(_) => quote!(#name: *mut core::ffi::c_void),
+        }
+    }
+
+    /// Type of the parameter as seen by the user function.
+    pub fn user_ty(&self) -> TokenStream {
+        let ty = &self.ty;
+
+        match self.kind {
+            ParamKind::Value | ParamKind::SliceLen => quote!(#ty),
+            ParamKind::Ref => quote!(&#ty),
+            ParamKind::RefMut => quote!(&mut #ty),
+            ParamKind::Slice(_) => quote!(&[#ty]),
+            ParamKind::ArrayMut(_) => quote!(&mut [#ty]),
+        }
+    }
+
+    /// Expression converting the C parameter into the argument of the user function.
+    pub fn arg(&self) -> TokenStream {
+        let name = format_ident!("{}", self.name);
+
+        match &self.kind {
+            ParamKind::Value | ParamKind::SliceLen => quote!(#name),
+            ParamKind::Ref => quote!(unsafe { &*#name }),
+            ParamKind::RefMut => quote!(unsafe { &mut *#name }),
+            ParamKind::Slice(len) => {
+                let len = format_ident!("{}", len);
+                quote!(if #name.is_null() {
+                    &[]
+                } else {
+                    unsafe { core::slice::from_raw_parts(#name as *const u8, #len as usize) }
+                })
+            }
+            ParamKind::ArrayMut(len) => quote!(if #name.is_null() {
+                &mut []
+            } else {
+                unsafe { core::slice::from_raw_parts_mut(#name, #len as usize) }
+            }),
+        }
+    }
+}
+
+/// Prototype of a callback in one of the SDK's callback structs.
+#[derive(Debug)]
+pub struct CallbackSignature {
+    pub name: &'static str,
+    pub params: Vec<CallbackParam>,
+    pub output: Option<TokenStream>,
+    /// SDK handler installed when the user does not register one.
+    pub default: Option<TokenStream>,
+    /// Value returned by an assembled callback that has neither registrations nor a default.
+    pub fallback: Option<TokenStream>,
+    /// Type behind the nullable pointer returned to C, the user function returns an `Option` of a
+    /// static reference to it instead.
+    pub nullable_ref: Option<TokenStream>,
+    /// Result that wins over the others when several handlers are registered.
+    pub prevailing: Option<TokenStream>,
+    /// Parameter threaded through multiple handlers, each one receiving the result of the
+    /// previous one.
+    pub chained: Option<&'static str>,
+}
+
+impl CallbackSignature {
+    pub fn new(name: &'static str, params: Vec<CallbackParam>) -> Self {
+        Self {
+            name,
+            params,
+            output: None,
+            default: None,
+            fallback: None,
+            nullable_ref: None,
+            prevailing: None,
+            chained: None,
+        }
+    }
+
+    pub fn returns(mut self, output: TokenStream) -> Self {
+        self.output = Some(output);
+        self
+    }
+
+    pub fn returns_nullable_ref(mut self, output: TokenStream) -> Self {
+        self.output = Some(quote!(*const #output));
+        self.nullable_ref = Some(output);
+        self
+    }
+
+    pub fn with_default(mut self, default: TokenStream) -> Self {
+        self.default = Some(default);
+        self
+    }
+
+    pub fn with_fallback(mut self, fallback: TokenStream) -> Self {
+        self.fallback = Some(fallback);
+        self
+    }
+
+    pub fn prevailing(mut self, prevailing: TokenStream) -> Self {
+        self.prevailing = Some(prevailing);
+        self
+    }
+
+    pub fn chained(mut self, param: &'static str) -> Self {
+        self.chained = Some(param);
+        self
+    }
+
+    pub fn wrapper_name(&self) -> proc_macro2::Ident {
+        format_ident!("__{}", self.name)
+    }
+
+    /// Reads the `#[before_default]`/`#[after_default]` attribute of the field registering this
+    /// callback.
+    pub fn chain_default(
+        &self,
+        field: &Ident,
+        attrs: &[Attribute],
+    ) -> syn::Result<Option<ChainDefault>> {
+        let mut chain_default = None;
+
+        for attr in attrs {
+            let order = if attr.path.is_ident("before_default") {
+                ChainDefault::Before
+            } else if attr.path.is_ident("after_default") {
+                ChainDefault::After
+            } else {
+                return Err(Error::new_spanned(
+                    attr,
+                    "expected `#[before_default]` or `#[after_default]`",
+                ));
+            };
+
+            if !attr.tokens.is_empty() {
+                return Err(Error::new_spanned(&attr.tokens, "unexpected tokens"));
+            }
+            if chain_default.is_some() {
+                return Err(Error::new_spanned(
+                    attr,
+                    "default handler is already chained",
+                ));
+            }
+            if self.default.is_none() {
+                return Err(Error::new_spanned(
+                    field,
+                    format!("`{}` has no SDK default handler to chain", self.name),
+                ));
+            }
+
+            chain_default = Some(order);
+        }
+
+        Ok(chain_default)
+    }
+
+    fn user_args(&self) -> impl Iterator<Item = TokenStream> + '_ {
+        self.params
+            .iter()
+            .filter(|param| param.is_user_param())
+            .map(CallbackParam::arg)
+    }
+
+    /// Rust function pointer type a user function has to coerce to.
+    pub fn user_fn_type(&self) -> TokenStream {
+        let user_tys = self
+            .params
+            .iter()
+            .filter(|param| param.is_user_param())
+            .map(CallbackParam::user_ty);
+        let output = match (&self.nullable_ref, &self.output) {
+            (Some(referenced), _) => quote!(-> Option<&'static #referenced>),
+            (None, Some(output)) => quote!(-> #output),
+            (None, None) => quote!(),
+        };
+
+        quote!(fn(#(#user_tys),*) #output)
+    }
+
+    /// Body of the `extern "C"` wrapper, invoking the registered handler.
+    ///
+    /// Functions and closures are first bound to a const of the expected function pointer type,
+    /// spanned to the user's expression, so a signature mismatch (or a capturing closure) is
+    /// reported there and names the callback field instead of pointing into the wrapper.
+    fn call(&self, handler: &CallbackHandler) -> TokenStream {
+        let args = self.user_args();
+        let user_fn_type = self.user_fn_type();
+        let check_name = format_ident!("{}", self.name.to_uppercase());
+
+        let check = match handler {
+            CallbackHandler::Path(path) => quote_spanned!(path.span()=>
+                const #check_name: #user_fn_type = #path;
+            ),
+            CallbackHandler::Closure(closure) => quote_spanned!(closure.span()=>
+                const #check_name: #user_fn_type = #closure;
+            ),
+            CallbackHandler::Block(block) => return quote!(#block),
+            CallbackHandler::List(handlers) => return self.call_all(handlers),
+        };
+
+        quote!({
+            #check
+            #check_name(#(#args),*)
+        })
+    }
+
+    /// Calls `handlers` in order, combining their results like
+    /// [`CallbackSignature::generate_dispatcher`] does.
+    fn call_all(&self, handlers: &[CallbackHandler]) -> TokenStream {
+        let calls = handlers.iter().map(|handler| self.call(handler));
+
+        match (&self.output, self.chained, &self.prevailing) {
+            (None, _, _) => quote!(#(#calls;)*),
+            (Some(_), Some(chained), _) => {
+                let chained = format_ident!("{}", chained);
+                quote!(
+                    #(let #chained = #calls;)*
+                    #chained
+                )
+            }
+            (Some(_), None, Some(prevailing)) => quote!(
+                let mut result = None;
+                #(
+                    let next = #calls;
+                    if result != Some(#prevailing) {
+                        result = Some(next);
+                    }
+                )*
+                result.unwrap()
+            ),
+            (Some(_), None, None) => quote!(
+                #(let result = #calls;)*
+                result
+            ),
+        }
+    }
+
+    /// Generates the `extern "C"` wrapper calling `handler`, and the SDK default if chained, and
+    /// the matching struct field.
+    pub fn generate(
+        &self,
+        registration: Option<(&CallbackHandler, Option<ChainDefault>)>,
+    ) -> (Option<TokenStream>, TokenStream) {
+        let field = format_ident!("{}", self.name);
+
+        let (handler, chain_default) = match registration {
+            Some(registration) => registration,
+            None => {
+                let value = match &self.default {
+                    Some(default) => quote!(Some(#default)),
+                    None => quote!(None),
+                };
+                return (None, quote!(#field: #value));
+            }
+        };
+
+        let wrapper_name = self.wrapper_name();
+        let c_params = self.params.iter().map(CallbackParam::c_decl);
+        let output = match &self.output {
+            Some(output) => quote!(-> #output),
+            None => quote!(),
+        };
+        let mut call = self.call(handler);
+
+        if self.nullable_ref.is_some() {
+            call = quote!(
+                match { #call } {
+                    Some(referenced) => referenced as *const _,
+                    None => core::ptr::null(),
+                }
+            );
+        }
+
+        if let (Some(chain_default), Some(default)) = (chain_default, &self.default) {
+            let c_args = self
+                .params
+                .iter()
+                .map(|param| format_ident!("{}", param.name));
+            let default = quote!(unsafe { #default(#(#c_args),*) };);
+
+            call = match chain_default {
+                ChainDefault::Before => quote!(
+                    #default
+                    #call
+                ),
+                ChainDefault::After if self.output.is_some() => quote!(
+                    let result = { #call };
+                    #default
+                    result
+                ),
+                ChainDefault::After => quote!(
+                    { #call };
+                    #default
+                ),
+            };
+        }
+
+        let wrapper = quote!(
+            extern "C" fn #wrapper_name(#(#c_params),*) #output {
+                #call
+            }
+        );
+
+        (Some(wrapper), quote!(#field: Some(#wrapper_name)))
+    }
+
+    /// Link section collecting the functions registered for this callback with an attribute.
+    fn section_name(&self) -> String {
+        format!("da14531_cb_{}", self.name)
+    }
+
+    /// Keeps `item_fn` and adds it to the callback's link section, from where the dispatcher
+    /// generated by [`CallbackSignature::generate_dispatcher`] picks it up.
+    pub fn generate_registration(&self, item_fn: &ItemFn) -> TokenStream {
+        let user_fn_type = self.user_fn_type();
+        let section_name = self.section_name();
+        let fn_name = &item_fn.sig.ident;
+        let entry = quote_spanned!(fn_name.span()=>
+            static ENTRY: Option<#user_fn_type> = Some(#fn_name);
+        );
+
+        quote!(
+            #item_fn
+
+            const _: () = {
+                #[used]
+                #[link_section = #section_name]
+                #entry
+            };
+        )
+    }
+
+    /// Generates the `extern "C"` wrapper calling every function registered for this callback,
+    /// in link order, and the matching struct field.
+    ///
+    /// The wrapper falls back to the SDK default if nothing has been registered. A `None` entry is
+    /// always placed in the section so the `__start_`/`__stop_` symbols exist.
+    pub fn generate_dispatcher(&self) -> (TokenStream, TokenStream) {
+        let field = format_ident!("{}", self.name);
+        let wrapper_name = self.wrapper_name();
+        let user_fn_type = self.user_fn_type();
+        let section_name = self.section_name();
+        let start_name = format!("__start_{}", section_name);
+        let stop_name = format!("__stop_{}", section_name);
+        let c_params = self.params.iter().map(CallbackParam::c_decl);
+        let c_args: Vec<_> = self
+            .params
+            .iter()
+            .map(|param| format_ident!("{}", param.name))
+            .collect();
+        let args: Vec<_> = self.user_args().collect();
+        let output = match &self.output {
+            Some(output) => quote!(-> #output),
+            None => quote!(),
+        };
+
+        let default = self
+            .default
+            .as_ref()
+            .map(|default| quote!(unsafe { #default(#(#c_args),*) }));
+
+        let dispatch = match (&self.output, self.chained) {
+            (Some(_), Some(chained)) => {
+                let chained = format_ident!("{}", chained);
+                quote!(
+                    let mut #chained = #chained;
+                    for callback in entries.iter().flatten() {
+                        #chained = callback(#(#args),*);
+                    }
+                    #chained
+                )
+            }
+            (Some(_), None) => {
+                let otherwise = match (&default, &self.fallback) {
+                    (Some(default), _) => default.clone(),
+                    (None, Some(fallback)) => fallback.clone(),
+                    (None, None) => unreachable!("{} has no default nor fallback", self.name),
+                };
+                let combine = match &self.prevailing {
+                    Some(prevailing) => quote!(
+                        if result != Some(#prevailing) {
+                            result = Some(next);
+                        }
+                    ),
+                    None => quote!(result = Some(next);),
+                };
+                quote!(
+                    let mut result = None;
+                    for callback in entries.iter().flatten() {
+                        let next = callback(#(#args),*);
+                        #combine
+                    }
+                    match result {
+                        Some(result) => result,
+                        None => #otherwise,
+                    }
+                )
+            }
+            (None, _) => {
+                let otherwise = default.unwrap_or_default();
+                quote!(
+                    let mut called = false;
+                    for callback in entries.iter().flatten() {
+                        callback(#(#args),*);
+                        called = true;
+                    }
+                    if !called {
+                        #otherwise
+                    }
+                )
+            }
+        };
+
+        let wrapper = quote!(
+            extern "C" fn #wrapper_name(#(#c_params),*) #output {
+                #[used]
+                #[link_section = #section_name]
+                static SENTINEL: Option<#user_fn_type> = None;
+
+                extern "C" {
+                    #[link_name = #start_name]
+                    static START: Option<#user_fn_type>;
+                    #[link_name = #stop_name]
+                    static STOP: Option<#user_fn_type>;
+                }
+
+                let entries = unsafe {
+                    let start = core::ptr::addr_of!(START);
+                    let stop = core::ptr::addr_of!(STOP);
+                    core::slice::from_raw_parts(start, stop.offset_from(start) as usize)
+                };
+
+                #dispatch
+            }
+        );
+
+        (wrapper, quote!(#field: Some(#wrapper_name)))
+    }
+}
+
+/// Arguments of a registration attribute, e.g. `#[app_callback(on_connection)]`.
+#[derive(Debug)]
+pub struct RegistrationAttr {
+    pub sdk: Sdk,
+    pub name: Ident,
+}
+
+impl Parse for RegistrationAttr {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(Self {
+            sdk: Sdk::parse_option(input)?,
+            name: input.parse()?,
+        })
+    }
+}
+
+/// Looks up the callback named by a registration attribute, e.g. `on_init` or `app_on_init`.
+pub fn find_signature<'a>(
+    signatures: &'a [CallbackSignature],
+    ident: &Ident,
+) -> syn::Result<&'a CallbackSignature> {
+    let name = ident.to_string();
+    let prefixed = format!("app_{}", name);
+
+    signatures
+        .iter()
+        .find(|signature| signature.name == name || signature.name == prefixed)
+        .ok_or_else(|| {
+            let expected: Vec<_> = signatures
+                .iter()
+                .map(|signature| signature.name.trim_start_matches("app_"))
+                .collect();
+            let suggestion = match did_you_mean(name.trim_start_matches("app_"), &expected) {
+                Some(suggestion) => format!(", did you mean `{suggestion}`?"),
+                None => format!(", expected one of: {}", expected.join(", ")),
+            };
+            Error::new(
+                ident.span(),
+                format!("unknown callback `{name}`{suggestion}"),
+            )
+        })
+}
+
+/// Callbacks registered in a `register_*_callbacks!` invocation, keyed by field name.
+#[derive(Debug)]
+pub struct CallbackRegistrations(HashMap<String, (CallbackHandler, Option<ChainDefault>)>);
+
+impl CallbackRegistrations {
+    pub fn parse(input: ParseStream, signatures: &[CallbackSignature]) -> syn::Result<Self> {
+        let fields: Punctuated<FieldValue, Token![,]> =
+            input.parse_terminated(FieldValue::parse)?;
+
+        let mut callbacks = HashMap::new();
+        let mut seen = SeenFields::default();
+
+        for field in fields {
+            let ident = match field.member {
+                Member::Named(name) => name,
+                Member::Unnamed(unnamed) => {
+                    emit(Error::new(unnamed.span(), "unexpected unnamed field"));
+                    continue;
+                }
+            };
+            if emit_err(seen.insert(&ident)).is_none() {
+                continue;
+            }
+
+            if let Some(registration) = emit_err(Self::parse_field(
+                signatures,
+                &ident,
+                field.attrs,
+                field.expr,
+            )) {
+                callbacks.insert(ident.to_string(), registration);
+            }
+        }
+
+        Ok(Self(callbacks))
+    }
+
+    fn parse_field(
+        signatures: &[CallbackSignature],
+        ident: &Ident,
+        attrs: Vec<Attribute>,
+        value: Expr,
+    ) -> syn::Result<(CallbackHandler, Option<ChainDefault>)> {
+        let key = ident.to_string();
+
+        let signature = match signatures.iter().find(|signature| signature.name == key) {
+            Some(signature) => signature,
+            None => {
+                let names: Vec<_> = signatures.iter().map(|signature| signature.name).collect();
+                return Err(unknown_field(ident, &names));
+            }
+        };
+        let chain_default = signature.chain_default(ident, &attrs)?;
+
+        let handler = CallbackHandler::from_expr(value)?;
+        if handler.contains_block() && !signature.params.is_empty() {
+            return Err(Error::new(
+                ident.span(),
+                format!(
+                    "inline blocks are only supported for callbacks without parameters, use a closure for `{key}`"
+                ),
+            ));
+        }
+
+        Ok((handler, chain_default))
+    }
+
+    /// Generates the wrappers of the registered callbacks and the fields of the callback struct.
+    pub fn generate(
+        &self,
+        signatures: &[CallbackSignature],
+    ) -> (Vec<TokenStream>, Vec<TokenStream>) {
+        let mut callback_wrappers = Vec::new();
+        let mut struct_fields = Vec::new();
+
+        for signature in signatures {
+            let registration = self
+                .0
+                .get(signature.name)
+                .map(|(handler, chain_default)| (handler, *chain_default));
+            let (wrapper, field) = signature.generate(registration);
+
+            callback_wrappers.extend(wrapper);
+            struct_fields.push(field);
+        }
+
+        (callback_wrappers, struct_fields)
+    }
+}
@@ -0,0 +1,244 @@
+use proc_macro2::{Ident, Span};
+use quote::quote;
+use syn::{
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    spanned::Spanned,
+    Error, Expr, FieldValue, Member, Token, Visibility,
+};
+
+use crate::{
+    diagnostic::{emit, emit_err, once_per_crate, unknown_field, SeenFields},
+    literal::{expected_one_of, parse_ident, parse_int_in},
+    options::{Options, Sdk},
+    units::parse_duration,
+};
+
+/// Connection intervals are given in 1.25 ms units, 7.5 ms to 4 s.
+const INTERVAL_UNIT_US: u64 = 1_250;
+const INTERVAL_MIN: u64 = 6;
+const INTERVAL_MAX: u64 = 3200;
+
+const LATENCY_MAX: u16 = 499;
+
+/// Supervision timeouts are given in 10 ms units, 100 ms to 32 s.
+const TIMEOUT_UNIT_US: u64 = 10_000;
+const TIMEOUT_MIN: u64 = 10;
+const TIMEOUT_MAX: u64 = 3200;
+
+/// Connection event lengths are given in 0.625 ms slots.
+const CE_LEN_UNIT_US: u64 = 625;
+
+const FIELDS: [&str; 7] = [
+    "interval_min",
+    "interval_max",
+    "latency",
+    "timeout",
+    "ce_len_min",
+    "ce_len_max",
+    "peer_requests",
+];
+const PEER_REQUESTS: [&str; 3] = ["InRange", "Accept", "Reject"];
+
+/// How `connection_params::on_update_params_request` answers connection parameter update
+/// requests of the peer.
+#[derive(Debug)]
+enum PeerRequests {
+    /// Accept requests overlapping the configured interval range and latency.
+    InRange,
+    Accept,
+    Reject,
+}
+
+#[derive(Debug)]
+pub struct ConnectionParamConfiguration {
+    sdk: Sdk,
+    vis: Visibility,
+    interval_min: u16,
+    interval_max: u16,
+    latency: u16,
+    timeout: u16,
+    ce_len_min: u16,
+    ce_len_max: u16,
+    peer_requests: PeerRequests,
+    interval_min_span: Span,
+    timeout_span: Span,
+    ce_len_min_span: Span,
+}
+
+impl Parse for ConnectionParamConfiguration {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let Options { sdk, vis } = Options::parse(input)?;
+        let fields: Punctuated<FieldValue, Token![,]> =
+            input.parse_terminated(FieldValue::parse)?;
+
+        // Defaults of the SDK's user_config.h templates.
+        let mut conn_param_conf = Self {
+            sdk,
+            vis,
+            interval_min: 8,
+            interval_max: 16,
+            latency: 0,
+            timeout: 125,
+            ce_len_min: 0,
+            ce_len_max: 0,
+            peer_requests: PeerRequests::InRange,
+            interval_min_span: Span::call_site(),
+            timeout_span: Span::call_site(),
+            ce_len_min_span: Span::call_site(),
+        };
+
+        let mut seen = SeenFields::default();
+
+        for field in fields {
+            let key = match field.member {
+                Member::Named(name) => name,
+                Member::Unnamed(unnamed) => {
+                    emit(Error::new(unnamed.span(), "unexpected unnamed field"));
+                    continue;
+                }
+            };
+            if emit_err(seen.insert(&key)).is_some() {
+                emit_err(conn_param_conf.parse_field(&key, field.expr));
+            }
+        }
+
+        if conn_param_conf.interval_min > conn_param_conf.interval_max {
+            let message = if seen.contains("interval_max") {
+                "`interval_min` must not exceed `interval_max`".to_string()
+            } else {
+                format!(
+                    "`interval_min` must not exceed `interval_max`, which defaults to {}ms",
+                    (conn_param_conf.interval_max as u64 * INTERVAL_UNIT_US) as f64 / 1_000.0
+                )
+            };
+            emit(Error::new(conn_param_conf.interval_min_span, message));
+        }
+        if conn_param_conf.ce_len_min > conn_param_conf.ce_len_max {
+            emit(Error::new(
+                conn_param_conf.ce_len_min_span,
+                "`ce_len_min` must not exceed `ce_len_max`",
+            ));
+        }
+
+        // The supervision timeout has to cover the peripheral skipping `latency` events and
+        // missing the one after, at the longest interval.
+        let timeout_us = conn_param_conf.timeout as u64 * TIMEOUT_UNIT_US;
+        let min_timeout_us = (1 + conn_param_conf.latency as u64)
+            * conn_param_conf.interval_max as u64
+            * INTERVAL_UNIT_US
+            * 2;
+        if timeout_us <= min_timeout_us {
+            emit(Error::new(
+                conn_param_conf.timeout_span,
+                format!(
+                    "`timeout` must exceed (1 + latency) * interval_max * 2 = {}ms",
+                    min_timeout_us as f64 / 1_000.0
+                ),
+            ));
+        }
+
+        Ok(conn_param_conf)
+    }
+}
+
+impl ConnectionParamConfiguration {
+    fn parse_field(&mut self, key: &Ident, value: Expr) -> syn::Result<()> {
+        match key.to_string().as_str() {
+            "interval_min" => {
+                self.interval_min =
+                    parse_duration(&value, INTERVAL_UNIT_US, INTERVAL_MIN..=INTERVAL_MAX)? as u16;
+                self.interval_min_span = value.span();
+            }
+            "interval_max" => {
+                self.interval_max =
+                    parse_duration(&value, INTERVAL_UNIT_US, INTERVAL_MIN..=INTERVAL_MAX)? as u16;
+            }
+            "latency" => {
+                self.latency = parse_int_in(&value, 0..=LATENCY_MAX)?;
+            }
+            "timeout" => {
+                self.timeout =
+                    parse_duration(&value, TIMEOUT_UNIT_US, TIMEOUT_MIN..=TIMEOUT_MAX)? as u16;
+                self.timeout_span = value.span();
+            }
+            "ce_len_min" => {
+                self.ce_len_min =
+                    parse_duration(&value, CE_LEN_UNIT_US, 0..=u16::MAX as u64)? as u16;
+                self.ce_len_min_span = value.span();
+            }
+            "ce_len_max" => {
+                self.ce_len_max =
+                    parse_duration(&value, CE_LEN_UNIT_US, 0..=u16::MAX as u64)? as u16;
+            }
+            "peer_requests" => {
+                self.peer_requests = match parse_ident(&value)?.as_str() {
+                    "InRange" => PeerRequests::InRange,
+                    "Accept" => PeerRequests::Accept,
+                    "Reject" => PeerRequests::Reject,
+                    _ => return Err(expected_one_of(&value, &PEER_REQUESTS)),
+                };
+            }
+            _ => return Err(unknown_field(key, &FIELDS)),
+        }
+
+        Ok(())
+    }
+
+    pub fn generate(&self) -> proc_macro2::TokenStream {
+        let Self {
+            sdk,
+            vis,
+            interval_min,
+            interval_max,
+            latency,
+            timeout,
+            ce_len_min,
+            ce_len_max,
+            peer_requests,
+            ..
+        } = self;
+
+        let accept = match peer_requests {
+            PeerRequests::InRange => quote!(
+                param.intv_max >= #interval_min
+                    && param.intv_min <= #interval_max
+                    && param.latency <= #latency
+            ),
+            PeerRequests::Accept => quote!(true),
+            PeerRequests::Reject => quote!(false),
+        };
+        let gapc_task = quote!(#sdk::ble_stack::host::gap::gapc::task);
+
+        let guard = once_per_crate("user_connection_param_conf");
+
+        quote!(
+            #guard
+
+            #[export_name = "user_connection_param_conf"]
+            #vis static USER_CONNECTION_PARAM_CONF: #sdk::app_modules::ConnectionParamConfiguration =
+                #sdk::app_modules::ConnectionParamConfiguration {
+                intv_min: #interval_min,
+                intv_max: #interval_max,
+                latency: #latency,
+                time_out: #timeout,
+                ce_len_min: #ce_len_min,
+                ce_len_max: #ce_len_max,
+            };
+
+            /// Handlers following the configured connection parameters.
+            #vis mod connection_params {
+                /// Answers connection parameter update requests of the peer, for use as
+                /// `app_on_update_params_request`.
+                pub fn on_update_params_request(
+                    param: &#gapc_task::GapcParamUpdateReqInd,
+                    cfm: &mut #gapc_task::GapcParamUpdateCfm,
+                ) {
+                    cfm.accept = #accept;
+                    cfm.ce_len_min = #ce_len_min;
+                    cfm.ce_len_max = #ce_len_max;
+                }
+            }
+        )
+    }
+}
@@ -1,63 +1,73 @@
+use proc_macro2::Ident;
 use quote::quote;
-use syn::{parse::Parse, punctuated::Punctuated, Expr, FieldValue, Member, Path, Token};
+use syn::{
+    parse::Parse, punctuated::Punctuated, spanned::Spanned, Error, Expr, FieldValue, Lit, Member,
+    Token, Visibility,
+};
 
+use crate::{
+    diagnostic::{emit, emit_err, once_per_crate, unknown_field, SeenFields},
+    literal::expected_one_of,
+    options::{Options, Sdk},
+    units::parse_duration,
+};
+
+/// The advertising period is given in 10 ms timer units.
+const ADVERTISE_PERIOD_UNIT_US: u64 = 10_000;
+const ADVERTISE_PERIOD_MAX: u64 = i32::MAX as u64;
+
+const FIELDS: [&str; 3] = [
+    "adv_scenario",
+    "advertise_period",
+    "security_request_scenario",
+];
+
+const ADV_SCENARIOS: [(&str, &str); 2] = [
+    ("AdvertiseForever", "DEF_ADV_FOREVER"),
+    ("AdvertiseWithTimeout", "DEF_ADV_WITH_TIMEOUT"),
+];
+const SECURITY_REQUEST_SCENARIOS: [(&str, &str); 2] = [
+    ("NeverSendSecReq", "DEF_SEC_REQ_NEVER"),
+    ("SendSecReqOnConnect", "DEF_SEC_REQ_ON_CONNECT"),
+];
+
+/// Missing fields default to the values of the SDK's user_config.h templates: advertise forever,
+/// an advertising period of 18 s and no security request.
 #[derive(Debug)]
 pub struct DefaultHandlersConfiguration {
-    adv_scenario: Option<Path>,
-    advertise_period: Option<Path>,
-    security_request_scenario: Option<Path>,
+    sdk: Sdk,
+    vis: Visibility,
+    adv_scenario: proc_macro2::TokenStream,
+    advertise_period: proc_macro2::TokenStream,
+    security_request_scenario: proc_macro2::TokenStream,
 }
 
 impl Parse for DefaultHandlersConfiguration {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let Options { sdk, vis } = Options::parse(input)?;
         let fields: Punctuated<FieldValue, Token![,]> =
             input.parse_terminated(FieldValue::parse)?;
 
         let mut callbacks = Self {
-            adv_scenario: None,
-            advertise_period: None,
-            security_request_scenario: None,
+            adv_scenario: quote!(#sdk::app_modules::DEF_ADV_FOREVER),
+            advertise_period: quote!(1800),
+            security_request_scenario: quote!(#sdk::app_modules::DEF_SEC_REQ_NEVER),
+            sdk,
+            vis,
         };
 
+        let mut seen = SeenFields::default();
+
         for field in fields {
             let key = match field.member {
                 Member::Named(name) => name,
                 Member::Unnamed(unnamed) => {
-                    panic!("Unexpected unnamed field: {:?}", unnamed);
+                    emit(Error::new(unnamed.span(), "unexpected unnamed field"));
+                    continue;
                 }
             };
-            let value = field.expr;
-            match key.to_string().as_str() {
-                "adv_scenario" => match value {
-                    Expr::Path(path) => {
-                        callbacks.adv_scenario = Some(path.path);
-                    }
-                    _ => {
-                        panic!("Unextpected expression for adv_scenario: {:?}", value);
-                    }
-                },
-                "advertise_period" => match value {
-                    Expr::Path(path) => {
-                        callbacks.advertise_period = Some(path.path);
-                    }
-                    _ => {
-                        panic!("Unextpected expression for advertise_period: {:?}", value);
-                    }
-                },
-                "security_request_scenario" => match value {
-                    Expr::Path(path) => {
-                        callbacks.security_request_scenario = Some(path.path);
-                    }
-                    _ => {
-                        panic!(
-                            "Unextpected expression for security_request_scenario: {:?}",
-                            value
-                        );
-                    }
-                },
-                _ => {
-                    panic!("Unexpected field: {} = {:?}", key.to_string(), value);
-                }
+            if emit_err(seen.insert(&key)).is_some() {
+                emit_err(callbacks.parse_field(&key, field.expr));
             }
         }
 
@@ -65,28 +75,94 @@ impl Parse for DefaultHandlersConfiguration {
     }
 }
 
-impl DefaultHandlersConfiguration {
-    pub fn generate(&self) -> proc_macro2::TokenStream {
-        if let Some(adv_scenario) = &self.adv_scenario {
-            if let Some(advertise_period) = &self.advertise_period {
-                if let Some(security_request_scenario) = &self.security_request_scenario {
-                    quote!(
-                        #[export_name = "user_default_hnd_conf"]
-                        pub static USER_DEFAULT_HND_CONF: da14531_sdk::app_modules::DefaultHandlersConfiguration =
-                            da14531_sdk::app_modules::DefaultHandlersConfiguration {
-                            adv_scenario: #adv_scenario,
-                            advertise_period: #advertise_period,
-                            security_request_scenario: #security_request_scenario
-                        };
-                    )
-                } else {
-                    panic!("Missing field: security_request_scenario");
+/// Maps an enum-like ident such as `AdvertiseForever` to the SDK constant.
+///
+/// Paths to constants, e.g. `da14531_sdk::app_modules::DEF_ADV_FOREVER`, and blocks are taken as
+/// they are.
+fn parse_scenario(
+    sdk: &Sdk,
+    expr: &Expr,
+    scenarios: &[(&str, &'static str)],
+) -> syn::Result<proc_macro2::TokenStream> {
+    match expr {
+        Expr::Path(path) => {
+            let ident = match path.path.get_ident() {
+                Some(ident) => ident.to_string(),
+                None => return Ok(quote!(#expr)),
+            };
+
+            match scenarios.iter().find(|(name, _)| *name == ident) {
+                Some((_, constant)) => {
+                    let constant = proc_macro2::Ident::new(constant, path.span());
+                    Ok(quote!(#sdk::app_modules::#constant))
                 }
-            } else {
-                panic!("Missing field: advertise_period");
+                // Imported constants are spelled in upper case.
+                None if ident.chars().any(|c| c.is_ascii_lowercase()) => {
+                    let names: Vec<_> = scenarios.iter().map(|(name, _)| *name).collect();
+                    Err(expected_one_of(expr, &names))
+                }
+                None => Ok(quote!(#expr)),
             }
-        } else {
-            panic!("Missing field: adv_scenario");
         }
+        Expr::Block(_) | Expr::Lit(_) => Ok(quote!(#expr)),
+        _ => {
+            let names: Vec<_> = scenarios.iter().map(|(name, _)| *name).collect();
+            Err(expected_one_of(expr, &names))
+        }
+    }
+}
+
+impl DefaultHandlersConfiguration {
+    fn parse_field(&mut self, key: &Ident, value: Expr) -> syn::Result<()> {
+        match key.to_string().as_str() {
+            "adv_scenario" => {
+                self.adv_scenario = parse_scenario(&self.sdk, &value, &ADV_SCENARIOS)?;
+            }
+            "advertise_period" => {
+                self.advertise_period = match &value {
+                    Expr::Lit(literal) if matches!(literal.lit, Lit::Int(_) | Lit::Float(_)) => {
+                        let advertise_period = parse_duration(
+                            &value,
+                            ADVERTISE_PERIOD_UNIT_US,
+                            1..=ADVERTISE_PERIOD_MAX,
+                        )?;
+                        quote!(#advertise_period as _)
+                    }
+                    Expr::Path(_) | Expr::Block(_) => quote!(#value),
+                    _ => return Err(Error::new(value.span(), "expected duration, e.g. `30s`")),
+                };
+            }
+            "security_request_scenario" => {
+                self.security_request_scenario =
+                    parse_scenario(&self.sdk, &value, &SECURITY_REQUEST_SCENARIOS)?;
+            }
+            _ => return Err(unknown_field(key, &FIELDS)),
+        }
+
+        Ok(())
+    }
+
+    pub fn generate(&self) -> proc_macro2::TokenStream {
+        let Self {
+            sdk,
+            vis,
+            adv_scenario,
+            advertise_period,
+            security_request_scenario,
+        } = self;
+
+        let guard = once_per_crate("user_default_hnd_conf");
+
+        quote!(
+            #guard
+
+            #[export_name = "user_default_hnd_conf"]
+            #vis static USER_DEFAULT_HND_CONF: #sdk::app_modules::DefaultHandlersConfiguration =
+                #sdk::app_modules::DefaultHandlersConfiguration {
+                adv_scenario: #adv_scenario,
+                advertise_period: #advertise_period,
+                security_request_scenario: #security_request_scenario
+            };
+        )
     }
 }
@@ -0,0 +1,139 @@
+use std::cell::RefCell;
+
+use proc_macro2::{Ident, Span, TokenStream};
+use proc_macro_error::{Diagnostic, Level};
+use quote::{format_ident, quote};
+use syn::Error;
+
+thread_local! {
+    /// Errors reported with `emit` during the innermost `collect`.
+    static ERRORS: RefCell<Vec<Error>> = const { RefCell::new(Vec::new()) };
+    /// Warnings reported with `warn`, until taken by the entry point.
+    static WARNINGS: RefCell<Vec<Diagnostic>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Runs `f`, which parses and expands a macro input, and returns its result along with the errors
+/// it reported with `emit`, combined into a single error.
+pub fn collect<T>(f: impl FnOnce() -> syn::Result<T>) -> syn::Result<T> {
+    let outer = ERRORS.with(|errors| errors.take());
+    let result = f();
+    let errors = ERRORS.with(|errors| errors.replace(outer));
+
+    let combined = errors
+        .into_iter()
+        .chain(result.as_ref().err().cloned())
+        .reduce(|mut combined, err| {
+            combined.combine(err);
+            combined
+        });
+
+    match combined {
+        Some(err) => Err(err),
+        None => result,
+    }
+}
+
+/// Reports `err` without aborting, so that further problems are reported in the same run.
+///
+/// The error is returned by the enclosing `collect`.
+pub fn emit(err: Error) {
+    ERRORS.with(|errors| errors.borrow_mut().push(err));
+}
+
+/// Reports a warning with a `help` note, which does not prevent the code from being generated.
+///
+/// Only shown with a nightly compiler, see the `nightly` feature.
+pub fn warn(span: Span, message: String, help: &str) {
+    let warning = Diagnostic::spanned(span, Level::Warning, message).help(help.to_owned());
+
+    WARNINGS.with(|warnings| warnings.borrow_mut().push(warning));
+}
+
+/// Takes the warnings reported with `warn` so far.
+pub fn take_warnings() -> Vec<Diagnostic> {
+    WARNINGS.with(|warnings| warnings.take())
+}
+
+/// Reports the error of `result`, if any, and returns its value.
+pub fn emit_err<T>(result: syn::Result<T>) -> Option<T> {
+    match result {
+        Ok(value) => Some(value),
+        Err(err) => {
+            emit(err);
+            None
+        }
+    }
+}
+
+/// Error for a field that is not one of `expected`, suggesting the closest match.
+pub fn unknown_field(key: &Ident, expected: &[&str]) -> Error {
+    let message = match did_you_mean(&key.to_string(), expected) {
+        Some(suggestion) => format!("unexpected field `{key}`, did you mean `{suggestion}`?"),
+        None => format!("unexpected field `{key}`"),
+    };
+
+    Error::new(key.span(), message)
+}
+
+/// Remembers the fields of an invocation to report duplicates.
+#[derive(Debug, Default)]
+pub struct SeenFields(Vec<Ident>);
+
+impl SeenFields {
+    pub fn insert(&mut self, key: &Ident) -> syn::Result<()> {
+        if self.0.contains(key) {
+            return Err(Error::new(key.span(), format!("duplicate field `{key}`")));
+        }
+        self.0.push(key.clone());
+
+        Ok(())
+    }
+
+    /// Whether the field `name` has been given, even if with an invalid value.
+    pub fn contains(&self, name: &str) -> bool {
+        self.0.iter().any(|key| key == name)
+    }
+}
+
+/// Closest of `candidates` to `name`, if it is close enough to be a typo.
+pub fn did_you_mean<'a>(name: &str, candidates: &[&'a str]) -> Option<&'a str> {
+    candidates
+        .iter()
+        .map(|candidate| (edit_distance(name, candidate), *candidate))
+        .filter(|(distance, candidate)| *distance <= std::cmp::max(1, candidate.len() / 3))
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// Levenshtein distance between `a` and `b`.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = diagonal + (ca != *cb) as usize;
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(row[j + 1] + 1);
+        }
+    }
+
+    row[b.len()]
+}
+
+/// Item that fails to build when generated twice in the same crate, for configurations the SDK
+/// links by `symbol`. Its unmangled name is shared by the whole crate, so invocations in different
+/// modules clash even where the generated items have no link name of their own, and unlike an
+/// exported macro it adds nothing to the public API of the crate.
+pub fn once_per_crate(symbol: &str) -> TokenStream {
+    let guard = format_ident!("__DA14531_SDK_{}", symbol.to_uppercase());
+
+    quote!(
+        #[doc(hidden)]
+        #[no_mangle]
+        static #guard: () = ();
+    )
+}
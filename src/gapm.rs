@@ -0,0 +1,269 @@
+use proc_macro2::{Ident, Span};
+use quote::quote;
+use syn::{
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    spanned::Spanned,
+    BinOp, Error, Expr, FieldValue, Member, Token, Visibility,
+};
+
+use crate::{
+    diagnostic::{emit, emit_err, once_per_crate, unknown_field, SeenFields},
+    literal::{expected_one_of, parse_bool, parse_byte_array, parse_ident, parse_int_in},
+    options::{Options, Sdk},
+    units::parse_duration,
+};
+
+/// ATT MTU limits of the SDK.
+const MTU_MIN: u16 = 23;
+const MTU_MAX: u16 = 512;
+
+/// LE data length extension limits.
+const TX_OCTETS_MIN: u16 = 27;
+const TX_OCTETS_MAX: u16 = 251;
+const TX_TIME_MIN_US: u64 = 328;
+const TX_TIME_MAX_US: u64 = 2120;
+
+const FIELDS: [&str; 13] = [
+    "role",
+    "max_mtu",
+    "addr_type",
+    "renew_dur",
+    "addr",
+    "irk",
+    "att_cfg",
+    "gap_start_hdl",
+    "gatt_start_hdl",
+    "max_mps",
+    "max_txoctets",
+    "max_txtime",
+    "privacy",
+];
+const ROLES: [&str; 5] = ["Observer", "Broadcaster", "Central", "Peripheral", "All"];
+const ADDR_TYPES: [&str; 6] = [
+    "Public",
+    "Static",
+    "HostPrivacyRpa",
+    "HostPrivacyNrpa",
+    "ControllerPrivacyPublic",
+    "ControllerPrivacyRandom",
+];
+
+/// Address renewal duration is given in 10 ms units.
+const RENEW_DUR_UNIT_US: u64 = 10_000;
+
+/// Parses the `GAPM_MASK_ATT_*` flags of `att_cfg`, given as paths or integer literals combined
+/// with `|`.
+fn parse_att_cfg(expr: &Expr) -> syn::Result<proc_macro2::TokenStream> {
+    match expr {
+        Expr::Binary(binary) if matches!(binary.op, BinOp::BitOr(_)) => {
+            let left = parse_att_cfg(&binary.left)?;
+            let right = parse_att_cfg(&binary.right)?;
+            Ok(quote!(#left | #right))
+        }
+        Expr::Paren(paren) => parse_att_cfg(&paren.expr),
+        Expr::Path(path) => Ok(quote!(#path)),
+        Expr::Lit(_) => {
+            // Unsuffixed, so that it takes the type of the SDK's flags it is combined with.
+            let att_cfg: u8 = parse_int_in(expr, 0..=u8::MAX)?;
+            let att_cfg = proc_macro2::Literal::u8_unsuffixed(att_cfg);
+            Ok(quote!(#att_cfg))
+        }
+        _ => Err(Error::new(
+            expr.span(),
+            "expected `GAPM_MASK_ATT_*` flags or integer literal, combined with `|`",
+        )),
+    }
+}
+
+#[derive(Debug)]
+pub struct GapmConfiguration {
+    sdk: Sdk,
+    vis: Visibility,
+    role: proc_macro2::TokenStream,
+    max_mtu: u16,
+    addr_type: proc_macro2::TokenStream,
+    renew_dur: u16,
+    addr: [u8; 6],
+    irk: [u8; 16],
+    att_cfg: proc_macro2::TokenStream,
+    gap_start_hdl: u16,
+    gatt_start_hdl: u16,
+    max_mps: u16,
+    max_mps_span: Span,
+    max_txoctets: u16,
+    max_txtime: u16,
+    privacy: bool,
+}
+
+impl Parse for GapmConfiguration {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let Options { sdk, vis } = Options::parse(input)?;
+        let fields: Punctuated<FieldValue, Token![,]> =
+            input.parse_terminated(FieldValue::parse)?;
+
+        let mut gapm_conf = Self {
+            role: quote!(#sdk::ble_stack::host::gap::GAP_ROLE_PERIPHERAL),
+            max_mtu: MTU_MIN,
+            addr_type: quote!(#sdk::app_modules::APP_CFG_ADDR_PUB),
+            renew_dur: 15000,
+            addr: [0; 6],
+            irk: [0; 16],
+            att_cfg: quote!(#sdk::ble_stack::host::gap::gapm::GAPM_MASK_ATT_SVC_CHG_EN),
+            gap_start_hdl: 0,
+            gatt_start_hdl: 0,
+            max_mps: 0,
+            max_mps_span: Span::call_site(),
+            max_txoctets: TX_OCTETS_MAX,
+            max_txtime: TX_TIME_MAX_US as u16,
+            privacy: false,
+            sdk,
+            vis,
+        };
+
+        let mut seen = SeenFields::default();
+
+        for field in fields {
+            let key = match field.member {
+                Member::Named(name) => name,
+                Member::Unnamed(unnamed) => {
+                    emit(Error::new(unnamed.span(), "unexpected unnamed field"));
+                    continue;
+                }
+            };
+            if emit_err(seen.insert(&key)).is_some() {
+                emit_err(gapm_conf.parse_field(&key, field.expr));
+            }
+        }
+
+        if gapm_conf.max_mps != 0 && gapm_conf.max_mps > gapm_conf.max_mtu {
+            emit(Error::new(
+                gapm_conf.max_mps_span,
+                "`max_mps` must not exceed `max_mtu`",
+            ));
+        }
+
+        Ok(gapm_conf)
+    }
+}
+
+impl GapmConfiguration {
+    fn parse_field(&mut self, key: &Ident, value: Expr) -> syn::Result<()> {
+        let sdk = &self.sdk;
+
+        match key.to_string().as_str() {
+            "role" => {
+                let role = match parse_ident(&value)?.as_str() {
+                    "Observer" => quote!(GAP_ROLE_OBSERVER),
+                    "Broadcaster" => quote!(GAP_ROLE_BROADCASTER),
+                    "Central" => quote!(GAP_ROLE_CENTRAL),
+                    "Peripheral" => quote!(GAP_ROLE_PERIPHERAL),
+                    "All" => quote!(GAP_ROLE_ALL),
+                    _ => return Err(expected_one_of(&value, &ROLES)),
+                };
+                self.role = quote!(#sdk::ble_stack::host::gap::#role);
+            }
+            "max_mtu" => {
+                self.max_mtu = parse_int_in(&value, MTU_MIN..=MTU_MAX)?;
+            }
+            "addr_type" => {
+                let addr_type = match parse_ident(&value)?.as_str() {
+                    "Public" => quote!(APP_CFG_ADDR_PUB),
+                    "Static" => quote!(APP_CFG_ADDR_STATIC),
+                    "HostPrivacyRpa" => quote!(APP_CFG_HOST_PRIV_RPA),
+                    "HostPrivacyNrpa" => quote!(APP_CFG_HOST_PRIV_NRPA),
+                    "ControllerPrivacyPublic" => quote!(APP_CFG_CNTL_PRIV_RPA_PUB),
+                    "ControllerPrivacyRandom" => quote!(APP_CFG_CNTL_PRIV_RPA_RAND),
+                    _ => return Err(expected_one_of(&value, &ADDR_TYPES)),
+                };
+                self.addr_type = quote!(#sdk::app_modules::#addr_type);
+            }
+            "renew_dur" => {
+                self.renew_dur =
+                    parse_duration(&value, RENEW_DUR_UNIT_US, 1..=u16::MAX as u64)? as u16;
+            }
+            "addr" => {
+                self.addr = parse_byte_array(&value)?;
+            }
+            "irk" => {
+                self.irk = parse_byte_array(&value)?;
+            }
+            "att_cfg" => {
+                self.att_cfg = parse_att_cfg(&value)?;
+            }
+            "gap_start_hdl" => {
+                self.gap_start_hdl = parse_int_in(&value, 0..=u16::MAX)?;
+            }
+            "gatt_start_hdl" => {
+                self.gatt_start_hdl = parse_int_in(&value, 0..=u16::MAX)?;
+            }
+            "max_mps" => {
+                self.max_mps = parse_int_in(&value, 0..=MTU_MAX)?;
+                self.max_mps_span = value.span();
+            }
+            "max_txoctets" => {
+                self.max_txoctets = parse_int_in(&value, TX_OCTETS_MIN..=TX_OCTETS_MAX)?;
+            }
+            "max_txtime" => {
+                self.max_txtime =
+                    parse_duration(&value, 1, TX_TIME_MIN_US..=TX_TIME_MAX_US)? as u16;
+            }
+            "privacy" => {
+                self.privacy = parse_bool(&value)?;
+            }
+            _ => return Err(unknown_field(key, &FIELDS)),
+        }
+
+        Ok(())
+    }
+
+    pub fn generate(&self) -> proc_macro2::TokenStream {
+        let Self {
+            sdk,
+            vis,
+            role,
+            max_mtu,
+            addr_type,
+            renew_dur,
+            addr,
+            irk,
+            att_cfg,
+            gap_start_hdl,
+            gatt_start_hdl,
+            max_mps,
+            max_txoctets,
+            max_txtime,
+            privacy,
+            ..
+        } = self;
+        let priv1_2 = *privacy as u8;
+
+        let guard = once_per_crate("user_gapm_conf");
+
+        quote!(
+            #guard
+
+            #[export_name = "user_gapm_conf"]
+            #vis static USER_GAPM_CONF: #sdk::app_modules::GapmConfiguration =
+                #sdk::app_modules::GapmConfiguration {
+                role: #role as _,
+                max_mtu: #max_mtu,
+                addr_type: #addr_type as _,
+                renew_dur: #renew_dur,
+                addr: #sdk::platform::core_modules::common::BDAddr {
+                    addr: [#(#addr),*],
+                },
+                irk: #sdk::ble_stack::host::gap::GapSecKey {
+                    key: [#(#irk),*],
+                },
+                att_cfg: (#att_cfg) as _,
+                gap_start_hdl: #gap_start_hdl,
+                gatt_start_hdl: #gatt_start_hdl,
+                max_mps: #max_mps,
+                max_txoctets: #max_txoctets,
+                max_txtime: #max_txtime,
+                priv1_2: #priv1_2 as _,
+            };
+        )
+    }
+}
@@ -0,0 +1,316 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+
+/// Generates a message of the simulated custom profile task, which is captured when it is sent.
+///
+/// `to_message` builds the captured `Message` from `fields`.
+fn generate_message(name: &str, fields: &Ident, to_message: TokenStream) -> TokenStream {
+    let dyn_name = format_ident!("KeMsgDyn{}", name);
+    let doc = format!("Simulated `{dyn_name}`, captured by `take_sent` when it is sent.");
+
+    quote!(
+        #[doc = #doc]
+        #[derive(Debug)]
+        pub struct #dyn_name<const N: u16>(#fields);
+
+        impl<const N: u16> #dyn_name<N> {
+            pub fn new(_src_id: KeTaskId, _dest_id: KeTaskId) -> Self {
+                Self(#fields {
+                    value: crate::host_sim::Value(std::vec![0; N as usize]),
+                    ..Default::default()
+                })
+            }
+
+            pub fn fields(&mut self) -> &mut #fields {
+                &mut self.0
+            }
+
+            pub fn send(self) {
+                let fields = self.0;
+                let mut value = fields.value.0;
+                value.truncate(fields.length as usize);
+                crate::host_sim::send(#to_message);
+            }
+        }
+    )
+}
+
+/// Generates the `host_sim` module that stands in for the SDK when the custom service is built
+/// with the `host-sim` feature.
+pub fn generate() -> TokenStream {
+    let value_req_rsp = generate_message(
+        "Custs1ValueReqRsp",
+        &format_ident!("Custs1ValueReqRsp"),
+        quote!(crate::host_sim::Message::ValueReqRsp {
+            conidx: fields.conidx,
+            att_idx: fields.att_idx,
+            status: fields.status,
+            value,
+        }),
+    );
+    let val_ntf_req = generate_message(
+        "Custs1ValNtfReq",
+        &format_ident!("Custs1ValNtfIndReq"),
+        quote!(crate::host_sim::Message::ValNtfReq {
+            conidx: fields.conidx,
+            handle: fields.handle,
+            value,
+        }),
+    );
+    let val_ind_req = generate_message(
+        "Custs1ValIndReq",
+        &format_ident!("Custs1ValNtfIndReq"),
+        quote!(crate::host_sim::Message::ValIndReq {
+            conidx: fields.conidx,
+            handle: fields.handle,
+            value,
+        }),
+    );
+
+    quote!(
+        /// Host-side stand-in for the parts of the SDK used by the custom service.
+        ///
+        /// The attribute database is a table of `AttDesc`, peer requests are injected with the
+        /// functions of the generated `custs1_sim` module, and messages sent by the application are
+        /// captured by `take_sent`.
+        pub mod host_sim {
+            #![allow(dead_code, non_camel_case_types)]
+
+            /// Attribute of the simulated database.
+            #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+            pub struct AttDesc {
+                pub uuid: u16,
+                pub perm: u32,
+                pub max_length: u16,
+                pub length: u16,
+                pub value: &'static [u8],
+            }
+
+            impl AttDesc {
+                /// Maximum length of the value.
+                pub fn max_len(&self) -> u16 {
+                    self.max_length & 0x7fff
+                }
+
+                /// Whether the value is requested from the application with `CUSTS1_VALUE_REQ_IND`.
+                pub fn value_req(&self) -> bool {
+                    self.max_length & 0x8000 != 0
+                }
+            }
+
+            /// Message sent by the application.
+            #[derive(Debug, Clone, PartialEq, Eq)]
+            pub enum Message {
+                ValueReqRsp {
+                    conidx: u8,
+                    att_idx: u16,
+                    status: u8,
+                    value: std::vec::Vec<u8>,
+                },
+                AttInfoRsp {
+                    conidx: u8,
+                    att_idx: u16,
+                    length: u16,
+                    status: u8,
+                },
+                ValNtfReq {
+                    conidx: u8,
+                    handle: u16,
+                    value: std::vec::Vec<u8>,
+                },
+                ValIndReq {
+                    conidx: u8,
+                    handle: u16,
+                    value: std::vec::Vec<u8>,
+                },
+            }
+
+            std::thread_local! {
+                static SENT: std::cell::RefCell<std::vec::Vec<Message>> =
+                    const { std::cell::RefCell::new(std::vec::Vec::new()) };
+            }
+
+            fn send(message: Message) {
+                SENT.with(|sent| sent.borrow_mut().push(message));
+            }
+
+            /// Takes the messages sent by the current thread since the last call.
+            pub fn take_sent() -> std::vec::Vec<Message> {
+                SENT.with(|sent| sent.take())
+            }
+
+            /// Value of variable length, in place of the trailing array of the SDK's messages.
+            #[derive(Debug, Default, Clone, PartialEq, Eq)]
+            pub struct Value(pub std::vec::Vec<u8>);
+
+            impl Value {
+                /// # Safety
+                ///
+                /// Always safe, `unsafe` only to match the SDK.
+                pub unsafe fn as_slice(&self, len: usize) -> &[u8] {
+                    &self.0[..len]
+                }
+
+                /// # Safety
+                ///
+                /// Always safe, `unsafe` only to match the SDK.
+                pub unsafe fn as_mut_slice(&mut self, len: usize) -> &mut [u8] {
+                    &mut self.0[..len]
+                }
+            }
+
+            pub mod app_modules {
+                pub fn app_env_get_conidx(conidx: u8) -> u8 {
+                    conidx
+                }
+            }
+
+            pub mod platform {
+                pub mod core_modules {
+                    pub mod ke {
+                        pub mod msg {
+                            pub type KeMsgId = u16;
+                        }
+                        pub mod task {
+                            pub type KeTaskId = u16;
+                        }
+                    }
+                    pub mod rwip {
+                        use super::ke::task::KeTaskId;
+
+                        pub const TASK_APP: KeTaskId = 0;
+                        pub const TASK_CUSTS1: KeTaskId = 1;
+                    }
+                }
+            }
+
+            pub mod ble_stack {
+                pub mod host {
+                    pub mod att {
+                        pub const ATT_UUID_16_LEN: u32 = 2;
+                        pub const ATT_DECL_PRIMARY_SERVICE: u16 = 0x2800;
+                        pub const ATT_DECL_CHARACTERISTIC: u16 = 0x2803;
+                        pub const ATT_DESC_CHAR_USER_DESCRIPTION: u16 = 0x2901;
+                        pub const ATT_DESC_CLIENT_CHAR_CFG: u16 = 0x2902;
+                    }
+                }
+
+                pub mod rwble_hl {
+                    pub mod error {
+                        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+                        #[repr(u8)]
+                        pub enum HlErr {
+                            ATT_ERR_NO_ERROR = 0x00,
+                            ATT_ERR_INVALID_HANDLE = 0x01,
+                            ATT_ERR_READ_NOT_PERMITTED = 0x02,
+                            ATT_ERR_WRITE_NOT_PERMITTED = 0x03,
+                            ATT_ERR_INSUFF_AUTHEN = 0x05,
+                            ATT_ERR_INVALID_OFFSET = 0x07,
+                            ATT_ERR_INSUFF_AUTHOR = 0x08,
+                            ATT_ERR_INVALID_ATTRIBUTE_VAL_LEN = 0x0D,
+                            ATT_ERR_INSUFF_ENC = 0x0F,
+                            ATT_ERR_APP_ERROR = 0x80,
+                        }
+                    }
+                }
+
+                pub mod profiles {
+                    pub mod custom {
+                        pub mod custs {
+                            pub mod custs1 {
+                                pub mod task {
+                                    use crate::host_sim::{
+                                        platform::core_modules::ke::task::KeTaskId, Value,
+                                    };
+
+                                    pub const CUSTS1_VAL_WRITE_IND: u32 = 0;
+                                    pub const CUSTS1_VALUE_REQ_IND: u32 = 1;
+                                    pub const CUSTS1_ATT_INFO_REQ: u32 = 2;
+
+                                    #[derive(Debug, Default, Clone)]
+                                    pub struct Custs1ValWriteInd {
+                                        pub conidx: u8,
+                                        pub handle: u16,
+                                        pub length: u16,
+                                        pub value: Value,
+                                    }
+
+                                    #[derive(Debug, Default, Clone)]
+                                    pub struct Custs1ValueReqInd {
+                                        pub conidx: u8,
+                                        pub att_idx: u16,
+                                    }
+
+                                    #[derive(Debug, Default, Clone)]
+                                    pub struct Custs1AttInfoReq {
+                                        pub conidx: u8,
+                                        pub att_idx: u16,
+                                    }
+
+                                    #[derive(Debug, Default, Clone)]
+                                    pub struct Custs1ValueReqRsp {
+                                        pub conidx: u8,
+                                        pub att_idx: u16,
+                                        pub length: u16,
+                                        pub status: u8,
+                                        pub value: Value,
+                                    }
+
+                                    #[derive(Debug, Default, Clone)]
+                                    pub struct Custs1AttInfoRsp {
+                                        pub conidx: u8,
+                                        pub att_idx: u16,
+                                        pub length: u16,
+                                        pub status: u8,
+                                    }
+
+                                    #[derive(Debug, Default, Clone)]
+                                    pub struct Custs1ValNtfIndReq {
+                                        pub conidx: u8,
+                                        pub notification: bool,
+                                        pub handle: u16,
+                                        pub length: u16,
+                                        pub value: Value,
+                                    }
+
+                                    #value_req_rsp
+                                    #val_ntf_req
+                                    #val_ind_req
+
+                                    pub type KeMsgCusts1ValueReqRsp = KeMsgDynCusts1ValueReqRsp<0>;
+
+                                    /// Simulated `KeMsgCusts1AttInfoRsp`, captured by `take_sent`
+                                    /// when it is sent.
+                                    #[derive(Debug)]
+                                    pub struct KeMsgCusts1AttInfoRsp(Custs1AttInfoRsp);
+
+                                    impl KeMsgCusts1AttInfoRsp {
+                                        pub fn new(_src_id: KeTaskId, _dest_id: KeTaskId) -> Self {
+                                            Self(Custs1AttInfoRsp::default())
+                                        }
+
+                                        pub fn fields(&mut self) -> &mut Custs1AttInfoRsp {
+                                            &mut self.0
+                                        }
+
+                                        pub fn send(self) {
+                                            let fields = self.0;
+                                            crate::host_sim::send(
+                                                crate::host_sim::Message::AttInfoRsp {
+                                                    conidx: fields.conidx,
+                                                    att_idx: fields.att_idx,
+                                                    length: fields.length,
+                                                    status: fields.status,
+                                                },
+                                            );
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    )
+}
@@ -1,55 +1,256 @@
-#![feature(iterator_try_collect)]
-
+mod advertise_configuration;
+mod advertising;
+mod app_bass;
 mod app_callbacks;
 mod app_custs;
 mod app_diss;
+mod app_suotar;
+mod bond_db_callbacks;
+mod callback;
+mod connection_param_configuration;
 mod default_handlers_configuration;
+mod diagnostic;
+mod gapm;
+#[cfg(feature = "host-sim")]
+mod host_sim;
+mod literal;
 mod main_loop_callbacks;
+mod options;
+mod profile;
+#[cfg(test)]
+mod tests;
+mod units;
 
+use advertise_configuration::AdvertiseConfiguration;
+use advertising::AdvertisingConfiguration;
+use app_bass::BatteryServiceConfiguration;
 use app_custs::CustomServer1ServiceConfiguration;
 use app_diss::DeviceInformationServiceConfiguration;
+use app_suotar::SuotaServiceConfiguration;
 use proc_macro::TokenStream;
-use syn::parse_macro_input;
+use proc_macro_error::proc_macro_error;
+use syn::{parse::Parser, ItemFn};
 
 use app_callbacks::AppCallbacks;
+use bond_db_callbacks::BondDbCallbacks;
+use callback::RegistrationAttr;
+use connection_param_configuration::ConnectionParamConfiguration;
 use default_handlers_configuration::DefaultHandlersConfiguration;
+use gapm::GapmConfiguration;
 use main_loop_callbacks::ArchMainLoopCallbacks;
+use options::Options;
+use profile::ProfileRegistration;
+
+/// Parses the input and generates the code in `f`, reporting every error found on the way.
+fn expand(f: impl FnOnce() -> syn::Result<proc_macro2::TokenStream>) -> TokenStream {
+    let result = diagnostic::collect(f);
+    for warning in diagnostic::take_warnings() {
+        warning.emit();
+    }
+
+    match result {
+        Ok(code) => code.into(),
+        Err(err) => err.into_compile_error().into(),
+    }
+}
 
 #[proc_macro]
+#[proc_macro_error]
 pub fn register_main_loop_callbacks(input: TokenStream) -> TokenStream {
-    let callbacks = parse_macro_input!(input as ArchMainLoopCallbacks);
+    expand(|| {
+        let callbacks: ArchMainLoopCallbacks = syn::parse(input)?;
 
-    callbacks.generate().into()
+        Ok(callbacks.generate())
+    })
 }
 
 #[proc_macro]
+#[proc_macro_error]
 pub fn register_app_callbacks(input: TokenStream) -> TokenStream {
-    let callbacks = parse_macro_input!(input as AppCallbacks);
-    let x = callbacks.generate();
+    expand(|| {
+        let callbacks: AppCallbacks = syn::parse(input)?;
 
-    x.into()
+        Ok(callbacks.generate())
+    })
 }
 
 #[proc_macro]
+#[proc_macro_error]
+pub fn register_bond_db_callbacks(input: TokenStream) -> TokenStream {
+    expand(|| {
+        let callbacks: BondDbCallbacks = syn::parse(input)?;
+
+        Ok(callbacks.generate())
+    })
+}
+
+#[proc_macro_attribute]
+#[proc_macro_error]
+pub fn main_loop_callback(attr: TokenStream, item: TokenStream) -> TokenStream {
+    expand(|| {
+        let attr: RegistrationAttr = syn::parse(attr)?;
+        let item_fn: ItemFn = syn::parse(item)?;
+
+        main_loop_callbacks::generate_registration(&attr.sdk, &attr.name, &item_fn)
+    })
+}
+
+#[proc_macro_attribute]
+#[proc_macro_error]
+pub fn app_callback(attr: TokenStream, item: TokenStream) -> TokenStream {
+    expand(|| {
+        let attr: RegistrationAttr = syn::parse(attr)?;
+        let item_fn: ItemFn = syn::parse(item)?;
+
+        app_callbacks::generate_registration(&attr.sdk, &attr.name, &item_fn)
+    })
+}
+
+/// Generates the SDK's callback structs from the functions registered with `#[app_callback]` and
+/// `#[main_loop_callback]` anywhere in the firmware.
+///
+/// The registered functions are collected in one link section per callback, named
+/// `da14531_cb_<field>`, and found through the `__start_`/`__stop_` symbols the linker defines for
+/// it. GNU ld and LLD keep such sections under `--gc-sections`, unless `-z start-stop-gc` is
+/// given. Linker scripts that place or discard orphan sections have to keep them explicitly under
+/// their own name, e.g. for `on_init`:
+///
+/// ```text
+/// SECTIONS {
+///     da14531_cb_app_on_init : { KEEP(*(da14531_cb_app_on_init)) } > FLASH
+/// }
+/// INSERT AFTER .rodata;
+/// ```
+#[proc_macro]
+#[proc_macro_error]
+pub fn assemble_callbacks(input: TokenStream) -> TokenStream {
+    expand(|| {
+        let options = Options::parse.parse(input)?;
+
+        let mut code = main_loop_callbacks::generate_assembled(&options);
+        code.extend(app_callbacks::generate_assembled(&options));
+
+        Ok(code)
+    })
+}
+
+#[proc_macro]
+#[proc_macro_error]
 pub fn default_handlers_configuration(input: TokenStream) -> TokenStream {
-    let config = parse_macro_input!(input as DefaultHandlersConfiguration);
+    expand(|| {
+        let config: DefaultHandlersConfiguration = syn::parse(input)?;
+
+        Ok(config.generate())
+    })
+}
+
+#[proc_macro]
+#[proc_macro_error]
+pub fn configure_advertising_params(input: TokenStream) -> TokenStream {
+    expand(|| {
+        let config: AdvertiseConfiguration = syn::parse(input)?;
+
+        Ok(config.generate())
+    })
+}
 
-    config.generate().into()
+#[proc_macro]
+#[proc_macro_error]
+pub fn configure_connection_params(input: TokenStream) -> TokenStream {
+    expand(|| {
+        let config: ConnectionParamConfiguration = syn::parse(input)?;
+
+        Ok(config.generate())
+    })
+}
+
+#[proc_macro]
+#[proc_macro_error]
+pub fn configure_gapm(input: TokenStream) -> TokenStream {
+    expand(|| {
+        let config: GapmConfiguration = syn::parse(input)?;
+
+        Ok(config.generate())
+    })
 }
 
+/// Configures the Device Information Service and generates its task, which answers every
+/// `DISS_VALUE_REQ_IND` with the configured value.
+///
+/// `on_value_req: path::to::handler` is called with each request before it is answered, as
+/// `fn(&DissValueReqInd, KeTaskId, KeTaskId) -> bool`. Returning `false` skips the generated
+/// answer, and the handler then has to send the `DISS_VALUE_CFM` itself. Other task messages go
+/// to `handlers: { ... }`.
 #[proc_macro]
+#[proc_macro_error]
 pub fn configure_device_information_service(input: TokenStream) -> TokenStream {
-    let config = parse_macro_input!(input as DeviceInformationServiceConfiguration);
+    expand(|| {
+        let config: DeviceInformationServiceConfiguration = syn::parse(input)?;
 
-    config.generate().into()
+        Ok(config.generate())
+    })
 }
 
 #[proc_macro]
+#[proc_macro_error]
+pub fn configure_battery_service(input: TokenStream) -> TokenStream {
+    expand(|| {
+        let config: BatteryServiceConfiguration = syn::parse(input)?;
+
+        Ok(config.generate())
+    })
+}
+
+#[proc_macro]
+#[proc_macro_error]
 pub fn configure_custom_server1_service(input: TokenStream) -> TokenStream {
-    let mut config = parse_macro_input!(input as CustomServer1ServiceConfiguration);
+    expand(|| {
+        let mut config: CustomServer1ServiceConfiguration = syn::parse(input)?;
 
-    match config.generate() {
-        Ok(code) => code.into(),
-        Err(err) => err.into_compile_error().into(),
-    }
+        config.generate()
+    })
+}
+
+#[proc_macro]
+#[proc_macro_error]
+pub fn configure_suota_service(input: TokenStream) -> TokenStream {
+    expand(|| {
+        let config: SuotaServiceConfiguration = syn::parse(input)?;
+
+        Ok(config.generate())
+    })
+}
+
+#[proc_macro]
+#[proc_macro_error]
+pub fn configure_advertising(input: TokenStream) -> TokenStream {
+    expand(|| {
+        let config: AdvertisingConfiguration = syn::parse(input)?;
+
+        config.generate()
+    })
+}
+
+/// Generates the `host_sim` module that the custom service uses instead of the SDK with the
+/// `host-sim` feature. Invoke it at the crate root, e.g. of an integration test.
+#[cfg(feature = "host-sim")]
+#[doc(hidden)]
+#[proc_macro]
+#[proc_macro_error]
+pub fn host_sim(input: TokenStream) -> TokenStream {
+    expand(|| {
+        let _: syn::parse::Nothing = syn::parse(input)?;
+
+        Ok(host_sim::generate())
+    })
+}
+
+#[proc_macro]
+#[proc_macro_error]
+pub fn register_profile(input: TokenStream) -> TokenStream {
+    expand(|| {
+        let registration: ProfileRegistration = syn::parse(input)?;
+
+        Ok(registration.generate())
+    })
 }
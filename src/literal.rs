@@ -0,0 +1,111 @@
+use syn::{spanned::Spanned, Error, Expr, Lit, UnOp};
+
+use crate::diagnostic::did_you_mean;
+
+/// Parses an integer literal, optionally negated.
+pub fn parse_int<N>(expr: &Expr) -> syn::Result<N>
+where
+    N: std::str::FromStr,
+    N::Err: std::fmt::Display,
+{
+    match expr {
+        Expr::Lit(literal) => match &literal.lit {
+            Lit::Int(lit_int) => lit_int.base10_parse(),
+            _ => Err(Error::new(expr.span(), "expected integer literal")),
+        },
+        Expr::Unary(unary) if matches!(unary.op, UnOp::Neg(_)) => match &*unary.expr {
+            Expr::Lit(literal) => match &literal.lit {
+                Lit::Int(lit_int) => format!("-{}", lit_int.base10_digits())
+                    .parse()
+                    .map_err(|err| Error::new(expr.span(), err)),
+                _ => Err(Error::new(expr.span(), "expected integer literal")),
+            },
+            _ => Err(Error::new(expr.span(), "expected integer literal")),
+        },
+        _ => Err(Error::new(expr.span(), "expected integer literal")),
+    }
+}
+
+/// Parses an integer literal and checks it against `range`.
+pub fn parse_int_in<N>(expr: &Expr, range: std::ops::RangeInclusive<N>) -> syn::Result<N>
+where
+    N: std::str::FromStr + PartialOrd + std::fmt::Display,
+    N::Err: std::fmt::Display,
+{
+    let value = parse_int(expr)?;
+
+    if !range.contains(&value) {
+        return Err(Error::new(
+            expr.span(),
+            format!(
+                "expected a value between {} and {}",
+                range.start(),
+                range.end()
+            ),
+        ));
+    }
+
+    Ok(value)
+}
+
+pub fn parse_bool(expr: &Expr) -> syn::Result<bool> {
+    match expr {
+        Expr::Lit(literal) => match &literal.lit {
+            Lit::Bool(lit_bool) => Ok(lit_bool.value),
+            _ => Err(Error::new(expr.span(), "expected bool literal")),
+        },
+        _ => Err(Error::new(expr.span(), "expected bool literal")),
+    }
+}
+
+pub fn parse_str(expr: &Expr) -> syn::Result<String> {
+    match expr {
+        Expr::Lit(literal) => match &literal.lit {
+            Lit::Str(lit_str) => Ok(lit_str.value()),
+            _ => Err(Error::new(expr.span(), "expected string literal")),
+        },
+        _ => Err(Error::new(expr.span(), "expected string literal")),
+    }
+}
+
+/// Parses a list of exactly `N` byte literals.
+pub fn parse_byte_array<const N: usize>(expr: &Expr) -> syn::Result<[u8; N]> {
+    let bytes = match expr {
+        Expr::Array(array) => array
+            .elems
+            .iter()
+            .map(|elem| parse_int_in(elem, 0..=u8::MAX))
+            .collect::<syn::Result<Vec<u8>>>()?,
+        _ => return Err(Error::new(expr.span(), format!("expected {N} bytes"))),
+    };
+
+    bytes
+        .try_into()
+        .map_err(|_| Error::new(expr.span(), format!("expected {N} bytes")))
+}
+
+/// Parses a single identifier, such as the name of an option.
+pub fn parse_ident(expr: &Expr) -> syn::Result<String> {
+    match expr {
+        Expr::Path(path) => match path.path.get_ident() {
+            Some(ident) => Ok(ident.to_string()),
+            None => Err(Error::new(expr.span(), "expected identifier")),
+        },
+        _ => Err(Error::new(expr.span(), "expected identifier")),
+    }
+}
+
+/// Error for a value that is not one of `expected`, suggesting the closest match.
+pub fn expected_one_of(expr: &Expr, expected: &[&str]) -> Error {
+    let names: Vec<_> = expected.iter().map(|name| format!("`{name}`")).collect();
+    let (last, rest) = names.split_last().unwrap();
+    let mut message = format!("expected {} or {}", rest.join(", "), last);
+
+    if let Ok(ident) = parse_ident(expr) {
+        if let Some(suggestion) = did_you_mean(&ident, expected) {
+            message = format!("{message}, did you mean `{suggestion}`?");
+        }
+    }
+
+    Error::new(expr.span(), message)
+}
@@ -1,210 +1,112 @@
 use quote::quote;
-use syn::{parse::Parse, punctuated::Punctuated, Expr, FieldValue, Member, Path, Token};
+use syn::{parse::Parse, Ident, ItemFn, Visibility};
+
+use crate::{
+    callback::{find_signature, CallbackParam, CallbackRegistrations, CallbackSignature},
+    diagnostic::once_per_crate,
+    options::{Options, Sdk},
+};
+
+/// Prototypes of the SDK's `arch_main_loop_callbacks` struct, in declaration order.
+fn main_loop_callback_signatures(sdk: &Sdk) -> Vec<CallbackSignature> {
+    let arch = quote!(#sdk::platform::arch);
+
+    vec![
+        CallbackSignature::new("app_on_init", vec![]),
+        CallbackSignature::new("app_on_ble_powered", vec![])
+            .returns(quote!(#arch::ArchMainLoopCallbackRet))
+            .with_fallback(quote!(#arch::GOTO_SLEEP))
+            .prevailing(quote!(#arch::KEEP_POWERED)),
+        CallbackSignature::new("app_on_system_powered", vec![])
+            .returns(quote!(#arch::ArchMainLoopCallbackRet))
+            .with_fallback(quote!(#arch::GOTO_SLEEP))
+            .prevailing(quote!(#arch::KEEP_POWERED)),
+        CallbackSignature::new("app_before_sleep", vec![]),
+        CallbackSignature::new(
+            "app_validate_sleep",
+            vec![CallbackParam::value("mode", quote!(#arch::SleepMode))],
+        )
+        .returns(quote!(#arch::SleepMode))
+        .chained("mode"),
+        CallbackSignature::new(
+            "app_going_to_sleep",
+            vec![CallbackParam::value("mode", quote!(#arch::SleepMode))],
+        ),
+        CallbackSignature::new("app_resume_from_sleep", vec![]),
+    ]
+}
 
 #[derive(Debug)]
 pub struct ArchMainLoopCallbacks {
-    app_on_init: Option<Path>,
-    app_on_ble_powered: Option<Path>,
-    app_on_system_powered: Option<Path>,
-    app_before_sleep: Option<Path>,
-    app_validate_sleep: Option<Path>,
-    app_going_to_sleep: Option<Path>,
-    app_resume_from_sleep: Option<Path>,
+    sdk: Sdk,
+    vis: Visibility,
+    callbacks: CallbackRegistrations,
 }
 
 impl Parse for ArchMainLoopCallbacks {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-        let fields: Punctuated<FieldValue, Token![,]> =
-            input.parse_terminated(FieldValue::parse)?;
-
-        let mut callbacks = Self {
-            app_on_init: None,
-            app_on_ble_powered: None,
-            app_on_system_powered: None,
-            app_before_sleep: None,
-            app_validate_sleep: None,
-            app_going_to_sleep: None,
-            app_resume_from_sleep: None,
-        };
+        let Options { sdk, vis } = Options::parse(input)?;
+        let callbacks = CallbackRegistrations::parse(input, &main_loop_callback_signatures(&sdk))?;
 
-        for field in fields {
-            let key = match field.member {
-                Member::Named(name) => name,
-                Member::Unnamed(unnamed) => {
-                    panic!("Unexpected unnamed field: {:?}", unnamed);
-                }
-            };
-            let value = field.expr;
-            match key.to_string().as_str() {
-                "app_on_init" => match value {
-                    Expr::Path(path) => {
-                        callbacks.app_on_init = Some(path.path);
-                    }
-                    _ => {
-                        panic!("Unextpected expression for app_on_init: {:?}", value);
-                    }
-                },
-                "app_on_ble_powered" => match value {
-                    Expr::Path(path) => {
-                        callbacks.app_on_ble_powered = Some(path.path);
-                    }
-                    _ => {
-                        panic!("Unextpected expression for app_on_ble_powered: {:?}", value);
-                    }
-                },
-                "app_on_system_powered" => match value {
-                    Expr::Path(path) => {
-                        callbacks.app_on_system_powered = Some(path.path);
-                    }
-                    _ => {
-                        panic!(
-                            "Unextpected expression for app_on_system_powered: {:?}",
-                            value
-                        );
-                    }
-                },
-                "app_before_sleep" => match value {
-                    Expr::Path(path) => {
-                        callbacks.app_before_sleep = Some(path.path);
-                    }
-                    _ => {
-                        panic!("Unextpected expression for app_before_sleep: {:?}", value);
-                    }
-                },
-                "app_validate_sleep" => match value {
-                    Expr::Path(path) => {
-                        callbacks.app_validate_sleep = Some(path.path);
-                    }
-                    _ => {
-                        panic!("Unextpected expression for app_validate_sleep: {:?}", value);
-                    }
-                },
-                "app_going_to_sleep" => match value {
-                    Expr::Path(path) => {
-                        callbacks.app_going_to_sleep = Some(path.path);
-                    }
-                    _ => {
-                        panic!("Unextpected expression for app_going_to_sleep: {:?}", value);
-                    }
-                },
-                "app_resume_from_sleep" => match value {
-                    Expr::Path(path) => {
-                        callbacks.app_resume_from_sleep = Some(path.path);
-                    }
-                    _ => {
-                        panic!(
-                            "Unextpected expression for app_resume_from_sleep: {:?}",
-                            value
-                        );
-                    }
-                },
-                _ => {
-                    panic!("Unexpected field: {} = {:?}", key.to_string(), value);
-                }
-            }
-        }
-
-        Ok(callbacks)
+        Ok(Self {
+            sdk,
+            vis,
+            callbacks,
+        })
     }
 }
 
 impl ArchMainLoopCallbacks {
     pub fn generate(&self) -> proc_macro2::TokenStream {
-        let mut callback_wrappers = Vec::new();
-        let mut struct_fields = Vec::new();
-
-        if let Some(app_on_init) = &self.app_on_init {
-            callback_wrappers.push(quote!(
-                #[no_mangle]
-                pub extern "C" fn __app_on_init() {
-                    #app_on_init();
-                }
-            ));
-            struct_fields.push(quote!(app_on_init: Some(__app_on_init)));
-        } else {
-            struct_fields.push(quote!(app_on_init: None));
-        }
-
-        if let Some(app_on_ble_powered) = &self.app_on_ble_powered {
-            callback_wrappers.push(quote!(
-                #[no_mangle]
-                pub extern "C" fn __app_on_ble_powered() -> da14531_sdk::platform::arch::ArchMainLoopCallbackRet {
-                    #app_on_ble_powered()
-                }
-            ));
-            struct_fields.push(quote!(app_on_ble_powered: Some(__app_on_ble_powered)));
-        } else {
-            struct_fields.push(quote!(app_on_ble_powered: None));
-        }
-
-        if let Some(app_on_system_powered) = &self.app_on_system_powered {
-            callback_wrappers.push(quote!(
-                #[no_mangle]
-                pub extern "C" fn __app_on_system_powered() -> da14531_sdk::platform::arch::ArchMainLoopCallbackRet {
-                    #app_on_system_powered()
-                }
-            ));
-            struct_fields.push(quote!(app_on_system_powered: Some(__app_on_system_powered)));
-        } else {
-            struct_fields.push(quote!(app_on_system_powered: None));
-        }
-
-        if let Some(app_before_sleep) = &self.app_before_sleep {
-            callback_wrappers.push(quote!(
-                #[no_mangle]
-                pub extern "C" fn __app_before_sleep() {
-                    #app_before_sleep();
-                }
-            ));
-            struct_fields.push(quote!(app_before_sleep: Some(__app_before_sleep)));
-        } else {
-            struct_fields.push(quote!(app_before_sleep: None));
-        }
-
-        if let Some(app_validate_sleep) = &self.app_validate_sleep {
-            callback_wrappers.push(quote!(
-                #[no_mangle]
-                pub extern "C" fn __app_validate_sleep(mode: da14531_sdk::platform::arch::SleepMode) -> da14531_sdk::platform::arch::SleepMode {
-                    #app_validate_sleep(mode)
-                }
-            ));
-            struct_fields.push(quote!(app_validate_sleep: Some(__app_validate_sleep)));
-        } else {
-            struct_fields.push(quote!(app_validate_sleep: None));
-        }
-
-        if let Some(app_going_to_sleep) = &self.app_going_to_sleep {
-            callback_wrappers.push(quote!(
-                #[no_mangle]
-                pub extern "C" fn __app_going_to_sleep(mode: da14531_sdk::platform::arch::SleepMode) {
-                    #app_going_to_sleep(mode)
-                }
-            ));
-            struct_fields.push(quote!(app_going_to_sleep: Some(__app_going_to_sleep)));
-        } else {
-            struct_fields.push(quote!(app_going_to_sleep: None));
-        }
-
-        if let Some(app_resume_from_sleep) = &self.app_resume_from_sleep {
-            callback_wrappers.push(quote!(
-                #[no_mangle]
-                pub extern "C" fn __app_resume_from_sleep() {
-                    #app_resume_from_sleep();
-                }
-            ));
-            struct_fields.push(quote!(app_resume_from_sleep: Some(__app_resume_from_sleep)));
-        } else {
-            struct_fields.push(quote!(app_resume_from_sleep: None));
-        }
+        let Self { sdk, vis, .. } = self;
+        let (callback_wrappers, struct_fields) =
+            self.callbacks.generate(&main_loop_callback_signatures(sdk));
+
+        let guard = once_per_crate("user_app_main_loop_callbacks");
 
         quote!(
+            #guard
+
             #(#callback_wrappers)*
 
             #[export_name = "user_app_main_loop_callbacks"]
-            pub static USER_APP_MAIN_LOOP_CALLBACKS: da14531_sdk::platform::arch::ArchMainLoopCallbacks =
-                da14531_sdk::platform::arch::ArchMainLoopCallbacks {
+            #vis static USER_APP_MAIN_LOOP_CALLBACKS: #sdk::platform::arch::ArchMainLoopCallbacks =
+                #sdk::platform::arch::ArchMainLoopCallbacks {
                 #(#struct_fields),*
             };
         )
     }
 }
+
+/// Registers `item_fn` for the callback named in its attribute.
+pub fn generate_registration(
+    sdk: &Sdk,
+    name: &Ident,
+    item_fn: &ItemFn,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let signatures = main_loop_callback_signatures(sdk);
+
+    Ok(find_signature(&signatures, name)?.generate_registration(item_fn))
+}
+
+/// Generates `USER_APP_MAIN_LOOP_CALLBACKS` dispatching to the functions registered with attributes.
+pub fn generate_assembled(Options { sdk, vis }: &Options) -> proc_macro2::TokenStream {
+    let (dispatchers, struct_fields): (Vec<_>, Vec<_>) = main_loop_callback_signatures(sdk)
+        .iter()
+        .map(CallbackSignature::generate_dispatcher)
+        .unzip();
+
+    let guard = once_per_crate("user_app_main_loop_callbacks");
+
+    quote!(
+        #guard
+
+        #(#dispatchers)*
+
+        #[export_name = "user_app_main_loop_callbacks"]
+        #vis static USER_APP_MAIN_LOOP_CALLBACKS: #sdk::platform::arch::ArchMainLoopCallbacks =
+            #sdk::platform::arch::ArchMainLoopCallbacks {
+            #(#struct_fields),*
+        };
+    )
+}
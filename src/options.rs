@@ -0,0 +1,148 @@
+use proc_macro2::{Ident, Span, TokenStream};
+use proc_macro_crate::{crate_name, FoundCrate};
+use quote::{quote, ToTokens};
+use syn::{parse::ParseStream, Error, Path, Token, Visibility};
+
+use crate::diagnostic::emit;
+
+/// Package of the SDK, looked up among the dependencies of the crate invoking a macro.
+const SDK_PACKAGE: &str = "da14531-sdk";
+
+/// Options that may lead the input of the macros generating items, in any order:
+///
+/// * `crate = path` of the SDK crate, see [`Sdk`].
+/// * `visibility: pub(crate)` of the generated items meant to be used from Rust, e.g. the
+///   configuration statics. Defaults to `pub(crate)`, so nothing joins the public API of the
+///   invoking crate unless asked for with `visibility: pub`; `pub(self)` keeps them private to
+///   the invoking module. Items only the SDK calls are never visible to Rust, regardless of this
+///   option.
+#[derive(Debug)]
+pub struct Options {
+    pub sdk: Sdk,
+    pub vis: Visibility,
+}
+
+impl Options {
+    pub fn parse(input: ParseStream) -> syn::Result<Self> {
+        Self::parse_options(input, true)
+    }
+
+    /// Parses the options of a macro that only generates items the SDK calls, which rejects
+    /// `visibility` as there is nothing it would apply to.
+    pub fn parse_sdk(input: ParseStream) -> syn::Result<Sdk> {
+        Ok(Self::parse_options(input, false)?.sdk)
+    }
+
+    fn parse_options(input: ParseStream, has_vis: bool) -> syn::Result<Self> {
+        let mut sdk = None;
+        let mut vis = None;
+
+        loop {
+            if input.peek(Token![crate]) && input.peek2(Token![=]) {
+                let span = input.span();
+                if sdk.replace(Sdk::parse_option(input)?).is_some() {
+                    return Err(Error::new(span, "duplicate option `crate`"));
+                }
+                continue;
+            }
+            if !is_visibility_option(input) {
+                break;
+            }
+
+            let option: Ident = input.parse()?;
+            input.parse::<Token![:]>()?;
+            let value: Visibility = input.parse()?;
+            if let Visibility::Inherited = value {
+                return Err(Error::new(
+                    input.span(),
+                    "expected visibility, e.g. `visibility: pub(crate)`",
+                ));
+            }
+            if vis.replace(value).is_some() {
+                return Err(Error::new(option.span(), "duplicate option `visibility`"));
+            }
+            if !has_vis {
+                emit(Error::new(
+                    option.span(),
+                    "`visibility` is not supported here",
+                ));
+            }
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
+        let defaults = Self::default();
+
+        Ok(Self {
+            sdk: sdk.unwrap_or(defaults.sdk),
+            vis: vis.unwrap_or(defaults.vis),
+        })
+    }
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            sdk: Sdk::default(),
+            vis: syn::parse_quote!(pub(crate)),
+        }
+    }
+}
+
+/// Whether `input` continues with `visibility:`, which no configuration has as field.
+fn is_visibility_option(input: ParseStream) -> bool {
+    let fork = input.fork();
+
+    matches!(fork.parse::<Ident>(), Ok(ident) if ident == "visibility") && fork.peek(Token![:])
+}
+
+/// Path of the SDK crate that prefixes the paths of the generated code, e.g.
+/// `#sdk::app_modules`.
+#[derive(Debug)]
+pub struct Sdk(TokenStream);
+
+impl Sdk {
+    /// Parses the optional `crate = path` leading the input of every macro, for firmware that
+    /// re-exports the SDK or uses a fork of it.
+    pub fn parse_option(input: ParseStream) -> syn::Result<Self> {
+        if !(input.peek(Token![crate]) && input.peek2(Token![=])) {
+            return Ok(Self::default());
+        }
+
+        input.parse::<Token![crate]>()?;
+        input.parse::<Token![=]>()?;
+        let path: Path = input.parse().map_err(|err| {
+            Error::new(
+                err.span(),
+                "expected path of the SDK crate, e.g. `crate = firmware::sdk`",
+            )
+        })?;
+        if !input.is_empty() {
+            input.parse::<Token![,]>()?;
+        }
+
+        Ok(Self(path.into_token_stream()))
+    }
+}
+
+impl Default for Sdk {
+    /// The SDK as named in the manifest of the invoking crate, even if renamed there.
+    fn default() -> Self {
+        let path = match crate_name(SDK_PACKAGE) {
+            Ok(FoundCrate::Itself) => quote!(crate),
+            Ok(FoundCrate::Name(name)) => Ident::new(&name, Span::call_site()).into_token_stream(),
+            // Not a direct dependency, e.g. when only reachable through a re-export, which then
+            // has to be given with `crate = path`.
+            Err(_) => quote!(da14531_sdk),
+        };
+
+        Self(path)
+    }
+}
+
+impl ToTokens for Sdk {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.0.to_tokens(tokens);
+    }
+}
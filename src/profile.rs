@@ -0,0 +1,396 @@
+use proc_macro2::{Ident, TokenStream, TokenTree};
+use quote::{format_ident, quote};
+use syn::{
+    braced,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    spanned::Spanned,
+    Error, Expr, Lit, Path, Token,
+};
+
+use crate::{
+    diagnostic::{emit, emit_err, once_per_crate, unknown_field, SeenFields},
+    options::{Options, Sdk},
+};
+
+const FIELDS: [&str; 6] = [
+    "name",
+    "task_id",
+    "db_cfg",
+    "db_cfg_init",
+    "start_handle",
+    "handlers",
+];
+
+/// A `MSG_ID => handler` entry of a profile's message handler table.
+#[derive(Debug)]
+pub struct MessageHandler {
+    pub msg_id: Path,
+    pub handler: Path,
+}
+
+impl Parse for MessageHandler {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let msg_id: Path = input.parse()?;
+        let _arrow_token: Token![=>] = input.parse()?;
+        let handler: Path = input.parse()?;
+
+        Ok(Self { msg_id, handler })
+    }
+}
+
+/// Braced list of message handlers, e.g. `{ FINDT_ALERT_IND => crate::ble::on_alert }`.
+#[derive(Debug, Default)]
+pub struct MessageHandlers(pub Vec<MessageHandler>);
+
+impl Parse for MessageHandlers {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        braced!(content in input);
+        let handlers: Punctuated<MessageHandler, Token![,]> =
+            content.parse_terminated(MessageHandler::parse)?;
+
+        let handlers: Vec<MessageHandler> = handlers.into_iter().collect();
+        for (idx, handler) in handlers.iter().enumerate() {
+            if handlers[..idx]
+                .iter()
+                .any(|other| other.msg_id == handler.msg_id)
+            {
+                emit(Error::new_spanned(
+                    &handler.msg_id,
+                    "duplicate handler for this message",
+                ));
+            }
+        }
+
+        Ok(Self(handlers))
+    }
+}
+
+impl MessageHandlers {
+    /// Parses the value of a `handlers` field, reporting a malformed list without aborting the
+    /// parsing of the fields that follow it.
+    pub fn parse_field(input: ParseStream) -> syn::Result<Option<Self>> {
+        let mut tokens = TokenStream::new();
+        while !input.is_empty() && !input.peek(Token![,]) {
+            tokens.extend(Some(input.parse::<TokenTree>()?));
+        }
+
+        Ok(emit_err(syn::parse2(tokens)))
+    }
+}
+
+/// An entry of the `KeMsgHandler` table generated by [`generate_process_handler`].
+pub struct ProcessHandlerEntry {
+    pub msg_id: TokenStream,
+    pub func: TokenStream,
+}
+
+impl MessageHandlers {
+    /// Generates a `KeMsgHandler` compatible wrapper for every user handler.
+    ///
+    /// User handlers take a reference to the message parameters, whose type is inferred from the
+    /// handler's signature, followed by the destination and source task IDs:
+    /// `fn(param: &FindtAlertInd, dest_id: KeTaskId, src_id: KeTaskId)`.
+    pub fn generate_wrappers(
+        &self,
+        sdk: &Sdk,
+        profile: &str,
+    ) -> (Vec<TokenStream>, Vec<ProcessHandlerEntry>) {
+        let mut wrappers = Vec::new();
+        let mut entries = Vec::new();
+
+        for (idx, MessageHandler { msg_id, handler }) in self.0.iter().enumerate() {
+            let wrapper_name = format_ident!("__app_{}_msg_handler_{}", profile, idx);
+
+            wrappers.push(quote!(
+                extern "C" fn #wrapper_name(
+                    _msg_id: #sdk::platform::core_modules::ke::msg::KeMsgId,
+                    param: *const core::ffi::c_void,
+                    dest_id: #sdk::platform::core_modules::ke::task::KeTaskId,
+                    src_id: #sdk::platform::core_modules::ke::task::KeTaskId,
+                ) -> i32 {
+                    #handler(unsafe { &*(param as *const _) }, dest_id, src_id);
+
+                    #sdk::platform::core_modules::ke::msg::KE_MSG_CONSUMED as i32
+                }
+            ));
+
+            entries.push(ProcessHandlerEntry {
+                msg_id: quote!(#msg_id),
+                func: quote!(#wrapper_name),
+            });
+        }
+
+        (wrappers, entries)
+    }
+}
+
+/// Generates the `KeMsgHandler` table and the `app_<profile>_process_handler` that dispatches
+/// through `app_std_process_event`.
+pub fn generate_process_handler(
+    sdk: &Sdk,
+    profile: &str,
+    entries: &[ProcessHandlerEntry],
+) -> TokenStream {
+    let table_name = format_ident!("APP_{}_PROCESS_HANDLERS", profile.to_uppercase());
+    let process_handler_name = format_ident!("app_{}_process_handler", profile);
+    let entries_len = entries.len();
+    let entries = entries.iter().map(|ProcessHandlerEntry { msg_id, func }| {
+        quote!(
+            #sdk::platform::core_modules::ke::msg::KeMsgHandler {
+                id: #msg_id as u16,
+                func: Some(#func),
+            }
+        )
+    });
+
+    quote!(
+        static #table_name: [#sdk::platform::core_modules::ke::msg::KeMsgHandler; #entries_len] = [
+            #(#entries),*
+        ];
+
+        #[no_mangle]
+        extern "C" fn #process_handler_name(
+            msg_id: #sdk::platform::core_modules::ke::msg::KeMsgId,
+            param: *const core::ffi::c_void,
+            dest_id: #sdk::platform::core_modules::ke::task::KeTaskId,
+            src_id: #sdk::platform::core_modules::ke::task::KeTaskId,
+            msg_ret: *mut #sdk::platform::core_modules::ke::msg::KeMsgStatusTag,
+        ) -> #sdk::app_modules::ProcessEventResponse {
+            return unsafe {
+                #sdk::bindings::app_std_process_event(
+                    msg_id,
+                    param,
+                    src_id,
+                    dest_id,
+                    msg_ret,
+                    #table_name.as_ptr() as *mut _,
+                    #table_name.len() as i32,
+                )
+            };
+        }
+    )
+}
+
+/// Generates `app_<profile>_create_db`, which the SDK calls to add the profile task to the stack.
+///
+/// See [`generate_create_db_fn`] for `db_cfg_init`.
+pub fn generate_create_db(
+    sdk: &Sdk,
+    profile: &str,
+    task_id: &TokenStream,
+    db_cfg_type: &TokenStream,
+    start_handle: u16,
+    db_cfg_init: TokenStream,
+) -> TokenStream {
+    let create_db = generate_create_db_fn(
+        sdk,
+        &format_ident!("app_{}_create_db", profile),
+        task_id,
+        db_cfg_type,
+        start_handle,
+        db_cfg_init,
+    );
+
+    quote!(
+        #[no_mangle]
+        #create_db
+    )
+}
+
+/// Generates the function `name`, which adds the profile task to the stack.
+///
+/// The database configuration is zeroed, then `db_cfg_init` is spliced in and can refer to it
+/// as `db_cfg`.
+pub fn generate_create_db_fn(
+    sdk: &Sdk,
+    name: &Ident,
+    task_id: &TokenStream,
+    db_cfg_type: &TokenStream,
+    start_handle: u16,
+    db_cfg_init: TokenStream,
+) -> TokenStream {
+    quote!(
+        extern "C" fn #name() {
+            const SIZE: u16 = core::mem::size_of::<#db_cfg_type>() as u16;
+            let mut msg = #sdk::ble_stack::host::gap::gapm::task::KeMsgDynGapmProfileTaskAdd::<SIZE>::new(
+                #sdk::platform::core_modules::rwip::TASK_APP as u16,
+                #sdk::platform::core_modules::rwip::TASK_GAPM as u16,
+            );
+
+            msg.fields().operation = #sdk::ble_stack::host::gap::gapm::task::GAPM_PROFILE_TASK_ADD as u8;
+            msg.fields().sec_lvl = #sdk::app_modules::get_user_prf_srv_perm(#task_id) as u8;
+            msg.fields().prf_task_id = #task_id as u16;
+            msg.fields().app_task = #sdk::platform::core_modules::rwip::TASK_APP as u16;
+            msg.fields().start_hdl = #start_handle;
+
+            let db_cfg_ptr = &mut msg.fields().param as *mut _ as *mut #db_cfg_type;
+
+            unsafe { core::ptr::write_bytes(db_cfg_ptr, 0, 1) };
+
+            let db_cfg = unsafe { db_cfg_ptr.as_mut().unwrap() };
+
+            #db_cfg_init
+
+            msg.send();
+        }
+    )
+}
+
+#[derive(Debug)]
+pub struct ProfileRegistration {
+    sdk: Sdk,
+    name: Ident,
+    task_id: Path,
+    db_cfg: Path,
+    db_cfg_init: Option<Path>,
+    start_handle: u16,
+    handlers: MessageHandlers,
+}
+
+impl Parse for ProfileRegistration {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let span = input.span();
+        let sdk = Options::parse_sdk(input)?;
+        let mut name = None;
+        let mut task_id = None;
+        let mut db_cfg = None;
+        let mut db_cfg_init = None;
+        let mut start_handle = 0;
+        let mut handlers = MessageHandlers::default();
+
+        let mut seen = SeenFields::default();
+
+        while !input.is_empty() {
+            let key: Ident = input.parse()?;
+            let _colon_token: Token![:] = input.parse()?;
+            let is_new = emit_err(seen.insert(&key)).is_some();
+
+            match key.to_string().as_str() {
+                "handlers" => {
+                    if let Some(parsed) = MessageHandlers::parse_field(input)? {
+                        handlers = parsed;
+                    }
+                }
+                _ => {
+                    let value: Expr = input.parse()?;
+                    let result = match (key.to_string().as_str(), value) {
+                        _ if !is_new => Ok(()),
+                        ("name", Expr::Path(path)) if path.path.get_ident().is_some() => {
+                            name = path.path.get_ident().cloned();
+                            Ok(())
+                        }
+                        ("task_id", Expr::Path(path)) => {
+                            task_id = Some(path.path);
+                            Ok(())
+                        }
+                        ("db_cfg", Expr::Path(path)) => {
+                            db_cfg = Some(path.path);
+                            Ok(())
+                        }
+                        ("db_cfg_init", Expr::Path(path)) => {
+                            db_cfg_init = Some(path.path);
+                            Ok(())
+                        }
+                        ("start_handle", Expr::Lit(literal)) => match &literal.lit {
+                            Lit::Int(lit_int) => {
+                                lit_int.base10_parse().map(|value| start_handle = value)
+                            }
+                            _ => Err(Error::new(literal.span(), "expected integer literal")),
+                        },
+                        ("name" | "task_id" | "db_cfg" | "db_cfg_init" | "start_handle", value) => {
+                            Err(Error::new(
+                                value.span(),
+                                format!("unexpected expression for {key}"),
+                            ))
+                        }
+                        _ => Err(unknown_field(&key, &FIELDS)),
+                    };
+                    emit_err(result);
+                }
+            }
+
+            if input.is_empty() {
+                break;
+            }
+            let _comma_token: Token![,] = input.parse()?;
+        }
+
+        // Fields given with an invalid value have been reported already, the placeholders only let
+        // the other problems be reported as well.
+        let missing = |field: &str| {
+            if !seen.contains(field) {
+                emit(Error::new(span, format!("missing `{field}`")));
+            }
+            Ident::new(field, span)
+        };
+
+        Ok(Self {
+            sdk,
+            name: name.unwrap_or_else(|| missing("name")),
+            task_id: task_id.unwrap_or_else(|| missing("task_id").into()),
+            db_cfg: db_cfg.unwrap_or_else(|| missing("db_cfg").into()),
+            db_cfg_init,
+            start_handle,
+            handlers,
+        })
+    }
+}
+
+impl ProfileRegistration {
+    pub fn generate(&self) -> TokenStream {
+        let profile = self.name.to_string();
+        let task_id = &self.task_id;
+        let db_cfg = &self.db_cfg;
+        let init_name = format_ident!("app_{}_init", profile);
+        let task_mod_name = format_ident!("app_{}_task", profile);
+        let mod_name = format_ident!("app_{}", profile);
+
+        let db_cfg_init = match &self.db_cfg_init {
+            Some(db_cfg_init) => quote!(#db_cfg_init(db_cfg);),
+            None => quote!(
+                let _ = db_cfg;
+            ),
+        };
+
+        let sdk = &self.sdk;
+        let (wrappers, entries) = self.handlers.generate_wrappers(sdk, &profile);
+        let process_handler = generate_process_handler(sdk, &profile, &entries);
+        let create_db = generate_create_db(
+            sdk,
+            &profile,
+            &quote!(#task_id),
+            &quote!(#db_cfg),
+            self.start_handle,
+            db_cfg_init,
+        );
+
+        let guard = once_per_crate(&format!("app_{}", profile));
+
+        quote!(
+            #guard
+
+            mod #task_mod_name {
+                #[allow(unused_imports)]
+                use super::*;
+
+                #(#wrappers)*
+
+                #process_handler
+            }
+
+            mod #mod_name {
+                #[allow(unused_imports)]
+                use super::*;
+
+                #[no_mangle]
+                extern "C" fn #init_name() {
+                    // Nothing to do
+                }
+
+                #create_db
+            }
+        )
+    }
+}
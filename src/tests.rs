@@ -0,0 +1,439 @@
+//! Golden-output tests of the generators.
+//!
+//! Each test expands a fixture and compares the pretty-printed code with `tests/snapshots`. Run
+//! `UPDATE_SNAPSHOTS=1 cargo test` to accept changed output, and review the diff before committing.
+
+use std::{env, fs, path::Path};
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{parse::Parse, parse_quote};
+
+use crate::{
+    advertise_configuration::AdvertiseConfiguration,
+    advertising::AdvertisingConfiguration,
+    app_bass::BatteryServiceConfiguration,
+    app_callbacks,
+    app_callbacks::AppCallbacks,
+    app_custs::CustomServer1ServiceConfiguration,
+    app_diss::DeviceInformationServiceConfiguration,
+    app_suotar::SuotaServiceConfiguration,
+    bond_db_callbacks::BondDbCallbacks,
+    connection_param_configuration::ConnectionParamConfiguration,
+    default_handlers_configuration::DefaultHandlersConfiguration,
+    diagnostic,
+    gapm::GapmConfiguration,
+    main_loop_callbacks,
+    main_loop_callbacks::ArchMainLoopCallbacks,
+    options::{Options, Sdk},
+    profile::ProfileRegistration,
+};
+
+fn parse<T: Parse>(input: TokenStream) -> T {
+    diagnostic::collect(|| syn::parse2(input))
+        .unwrap_or_else(|err| panic!("fixture does not parse: {err}"))
+}
+
+/// Messages of every error reported for `input`.
+fn parse_errors<T: Parse + std::fmt::Debug>(input: TokenStream) -> Vec<String> {
+    let err = diagnostic::collect(|| syn::parse2::<T>(input)).expect_err("fixture parses");
+
+    err.into_iter().map(|err| err.to_string()).collect()
+}
+
+fn assert_snapshot(name: &str, code: TokenStream) {
+    let file: syn::File = syn::parse2(code).expect("generated code is not a valid file");
+    let actual = prettyplease::unparse(&file);
+
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/snapshots")
+        .join(format!("{name}.rs"));
+
+    if env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        fs::write(&path, actual).unwrap();
+        return;
+    }
+
+    let expected = fs::read_to_string(&path).unwrap_or_else(|_| {
+        panic!(
+            "missing snapshot {}, run with UPDATE_SNAPSHOTS=1",
+            path.display()
+        )
+    });
+    assert_eq!(
+        actual,
+        expected,
+        "output differs from {}, run with UPDATE_SNAPSHOTS=1 to accept it",
+        path.display()
+    );
+}
+
+/// Snapshot name suffix of the callbacks that depend on the security features of the SDK.
+fn sec_features() -> &'static str {
+    if cfg!(feature = "ble-app-ral") {
+        ".ble-app-ral"
+    } else if cfg!(feature = "ble-app-sec") {
+        ".ble-app-sec"
+    } else {
+        ""
+    }
+}
+
+#[test]
+fn main_loop_callbacks() {
+    let callbacks: ArchMainLoopCallbacks = parse(quote!(
+        app_on_init: { crate::ble::init(); },
+        app_validate_sleep: |mode| mode,
+        app_going_to_sleep: crate::ble::sleep,
+    ));
+
+    assert_snapshot("main_loop_callbacks", callbacks.generate());
+}
+
+#[test]
+fn main_loop_callback_registration() {
+    let code = main_loop_callbacks::generate_registration(
+        &Sdk::default(),
+        &format_ident!("on_init"),
+        &parse_quote!(
+            fn init() {}
+        ),
+    )
+    .unwrap();
+
+    assert_snapshot("main_loop_callback_registration", code);
+}
+
+#[test]
+fn app_callbacks() {
+    let callbacks: AppCallbacks = parse(quote!(
+        app_on_connection: crate::ble::on_connection,
+        app_on_update_params_request: crate::ble::on_update,
+        app_on_adv_undirect_complete: crate::ble::on_adv_done,
+    ));
+
+    assert_snapshot(
+        &format!("app_callbacks{}", sec_features()),
+        callbacks.generate(),
+    );
+}
+
+#[test]
+fn app_callback_registration() {
+    let code = app_callbacks::generate_registration(
+        &Sdk::default(),
+        &format_ident!("on_get_dev_appearance"),
+        &parse_quote!(
+            fn appearance(appearance: &mut u16) {
+                *appearance = 0x0200;
+            }
+        ),
+    )
+    .unwrap();
+
+    assert_snapshot("app_callback_registration", code);
+}
+
+#[test]
+fn assembled_callbacks() {
+    let options = Options::default();
+    let mut code = main_loop_callbacks::generate_assembled(&options);
+    code.extend(app_callbacks::generate_assembled(&options));
+
+    assert_snapshot(&format!("assembled_callbacks{}", sec_features()), code);
+}
+
+#[test]
+fn bond_db_callbacks() {
+    let callbacks: BondDbCallbacks = parse(quote!(
+        app_bdb_get_size: || 8,
+        app_bdb_search_entry: crate::bond_db::search,
+        app_bdb_remove_entry: |_, _, param| crate::bond_db::remove(param),
+        app_bdb_get_stored_irks: crate::bond_db::get_irks,
+    ));
+
+    assert_snapshot("bond_db_callbacks", callbacks.generate());
+}
+
+#[test]
+fn sdk_option() {
+    let callbacks: BondDbCallbacks = parse(quote!(
+        crate = firmware::sdk,
+        app_bdb_search_entry: crate::bond_db::search,
+    ));
+
+    assert_snapshot("sdk_option", callbacks.generate());
+}
+
+#[test]
+fn visibility_option() {
+    let config: ConnectionParamConfiguration = parse(quote!(
+        visibility: pub(crate),
+        crate = firmware::sdk,
+        peer_requests: Reject,
+    ));
+
+    assert_snapshot("visibility_option", config.generate());
+}
+
+#[test]
+fn default_handlers_configuration() {
+    let config: DefaultHandlersConfiguration = parse(quote!(
+        adv_scenario: AdvertiseWithTimeout,
+        advertise_period: 30s,
+        security_request_scenario: DEF_SEC_REQ_ON_CONNECT,
+    ));
+
+    assert_snapshot("default_handlers_configuration", config.generate());
+}
+
+#[test]
+fn advertising_params() {
+    let config: AdvertiseConfiguration = parse(quote!(
+        mode: LimitedDiscoverable,
+        interval_min: 100ms,
+        interval_max: 250ms,
+        channel_map: [37, 39],
+        filter_policy: AllowScanWlstConAny,
+        peer_addr: [0x01, 0x02, 0x03, 0x04, 0x05, 0x06],
+        peer_addr_type: Random,
+    ));
+
+    assert_snapshot("advertising_params", config.generate());
+}
+
+#[test]
+fn connection_params() {
+    let config: ConnectionParamConfiguration = parse(quote!(
+        interval_min: 15ms,
+        interval_max: 30ms,
+        latency: 4,
+        timeout: 2s,
+        ce_len_max: 4,
+        peer_requests: InRange,
+    ));
+
+    assert_snapshot("connection_params", config.generate());
+}
+
+#[test]
+fn connection_params_errors() {
+    let errors = parse_errors::<ConnectionParamConfiguration>(quote!(
+        interval_min: 5ms,
+        latency: 500,
+        peer_request: Reject,
+    ));
+
+    assert_eq!(
+        errors,
+        [
+            "expected a duration between 7.5ms and 4s",
+            "expected a value between 0 and 499",
+            "unexpected field `peer_request`, did you mean `peer_requests`?",
+        ]
+    );
+}
+
+#[cfg(feature = "nightly")]
+#[test]
+fn unsuffixed_duration_warning() {
+    diagnostic::take_warnings();
+    let _: ConnectionParamConfiguration = parse(quote!(ce_len_max: 4));
+
+    let warnings: Vec<_> = diagnostic::take_warnings()
+        .iter()
+        .map(|warning| warning.message().to_owned())
+        .collect();
+    assert_eq!(
+        warnings,
+        ["duration without unit is taken as 4 units of 625us"]
+    );
+}
+
+#[test]
+fn gapm() {
+    let config: GapmConfiguration = parse(quote!(
+        role: Peripheral,
+        max_mtu: 247,
+        addr_type: Static,
+        renew_dur: 150s,
+        att_cfg: GAPM_MASK_ATT_SVC_CHG_EN | 0x04,
+        max_mps: 247,
+        max_txoctets: 251,
+        max_txtime: 2120us,
+    ));
+
+    assert_snapshot("gapm", config.generate());
+}
+
+#[test]
+fn advertising() {
+    let config: AdvertisingConfiguration = parse(quote!(
+        advertise_data: {
+            complete_name: "Tag",
+            uuid128_list: ["0000fe59-0000-1000-8000-00805f9b34fb"],
+        },
+        scan_response_data: {
+            uuid16_list: [0x180F, 0x180A],
+            manufacturer_data: (0x0A7A, b"\x01\x02"),
+            appearance: 0x0200,
+        },
+    ));
+
+    assert_snapshot("advertising", config.generate().unwrap());
+}
+
+#[test]
+fn device_information_service() {
+    let config: DeviceInformationServiceConfiguration = parse(quote!(
+        manufacturer_name: "ACME",
+        firm_rev_str: "1.0",
+        on_value_req: crate::dis::on_value_req,
+        handlers: {
+            DISS_SET_VALUE_RSP => crate::dis::on_set_value_rsp,
+        },
+    ));
+
+    assert_snapshot("device_information_service", config.generate());
+}
+
+#[test]
+fn battery_service() {
+    let config: BatteryServiceConfiguration = parse(quote!(
+        battery_level: crate::bat::main,
+        notification: true,
+        on_notification_config: crate::bat::on_ntf_cfg,
+    ));
+
+    assert_snapshot("battery_service", config.generate());
+}
+
+#[test]
+fn suota_service() {
+    let config: SuotaServiceConfiguration = parse(quote!(
+        patch_memory: SpiFlash,
+        patch_region: 0x20000..0x38000,
+        max_patch_len: 240,
+        on_suota_status: crate::ota::on_status,
+    ));
+
+    assert_snapshot("suota_service", config.generate());
+}
+
+#[test]
+fn suota_service_errors() {
+    let errors = parse_errors::<SuotaServiceConfiguration>(quote!(
+        patch_memory: SpiFlash,
+        patch_region: 0x20100..0x20200,
+        max_patch_len: 512,
+    ));
+
+    assert_eq!(
+        errors,
+        [
+            "`max_patch_len` exceeds the size of `patch_region`",
+            "SPI flash `patch_region` must be aligned to 0x1000 byte sectors",
+        ]
+    );
+}
+
+/// Custom service with two services, one of them advertised, and every kind of handler.
+fn custom_server1_service_config() -> CustomServer1ServiceConfiguration {
+    parse(quote!(
+        CONTROL: {
+            uuid: 0xFE00,
+            advertise: true,
+            characteristics: {
+                MODE: {
+                    uuid: 0xFE01,
+                    permissions: { WRITE_ENABLED | WRITE_REQUEST_ACCEPTED },
+                    length: 1,
+                    user_description: "Mode",
+                    write_handler: crate::ble::on_mode,
+                },
+                STATUS: {
+                    uuid: 0xFE02,
+                    permissions: { READ_AUTH | NOTIFICATION_ENABLED },
+                    length: crate::ble::STATUS_LEN,
+                    read_handler: crate::ble::on_status,
+                },
+                COMMAND: {
+                    uuid: 0xFE03,
+                    permissions: { WRITE_SECURE | WRITE_COMMAND_ACCEPTED | INDICATION_UNAUTH },
+                    length: 20,
+                    write_handler: crate::ble::on_command,
+                },
+            },
+        },
+        LOG: {
+            uuid: 0xFE10,
+            characteristics: {
+                ENTRIES: {
+                    uuid: 0xFE11,
+                    permissions: { READ_UNAUTH },
+                    length: 64,
+                    read_handler: crate::log::on_read,
+                },
+            },
+        },
+    ))
+}
+
+#[test]
+fn custom_server1_service() {
+    let mut config = custom_server1_service_config();
+    config.host_sim = false;
+
+    assert_snapshot("custom_server1_service", config.generate().unwrap());
+}
+
+#[test]
+fn custom_server1_service_host_sim() {
+    let mut config = custom_server1_service_config();
+    config.host_sim = true;
+
+    assert_snapshot(
+        "custom_server1_service.host-sim",
+        config.generate().unwrap(),
+    );
+}
+
+#[cfg(feature = "host-sim")]
+#[test]
+fn host_sim() {
+    assert_snapshot("host_sim", crate::host_sim::generate());
+}
+
+#[test]
+fn profile() {
+    let registration: ProfileRegistration = parse(quote!(
+        name: findt,
+        task_id: TASK_ID_FINDT,
+        db_cfg: FindtDbCfg,
+        handlers: {
+            FINDT_ALERT_IND => crate::ble::on_alert,
+        },
+    ));
+
+    assert_snapshot("profile", registration.generate());
+}
+
+#[test]
+fn profile_errors() {
+    let errors = parse_errors::<ProfileRegistration>(quote!(
+        name: crate::findt,
+        handlers: { FINDT_ALERT_IND },
+        start_handle: "1",
+    ));
+
+    assert_eq!(
+        errors,
+        [
+            "unexpected expression for name",
+            "expected `=>`",
+            "expected integer literal",
+            "missing `task_id`",
+            "missing `db_cfg`",
+        ]
+    );
+}
@@ -0,0 +1,93 @@
+use syn::{spanned::Spanned, Error, Expr, Lit};
+
+use crate::diagnostic;
+
+/// Parses a duration such as `100ms`, `687.5ms`, `2s` or `328us` into microseconds.
+///
+/// An unsuffixed integer is taken as a number of `unit_us` microsecond units, the raw value the
+/// SDK expects.
+pub fn parse_duration_us(expr: &Expr, unit_us: u64) -> syn::Result<u64> {
+    let (digits, suffix, span) = match expr {
+        Expr::Lit(literal) => match &literal.lit {
+            Lit::Int(lit_int) => (lit_int.base10_digits(), lit_int.suffix(), lit_int.span()),
+            Lit::Float(lit_float) if !lit_float.suffix().is_empty() => (
+                lit_float.base10_digits(),
+                lit_float.suffix(),
+                lit_float.span(),
+            ),
+            _ => return Err(Error::new(expr.span(), "expected duration, e.g. `100ms`")),
+        },
+        _ => return Err(Error::new(expr.span(), "expected duration, e.g. `100ms`")),
+    };
+
+    let scale = match suffix {
+        "" => unit_us,
+        "us" => 1,
+        "ms" => 1_000,
+        "s" => 1_000_000,
+        suffix => {
+            return Err(Error::new(
+                span,
+                format!("unknown unit `{suffix}`, expected `us`, `ms` or `s`"),
+            ))
+        }
+    };
+
+    if cfg!(feature = "nightly") && suffix.is_empty() {
+        diagnostic::warn(
+            span,
+            format!("duration without unit is taken as {digits} units of {unit_us}us"),
+            "add a unit suffix such as `ms`",
+        );
+    }
+
+    let value: f64 = digits
+        .parse()
+        .map_err(|_| Error::new(span, "duration out of range"))?;
+    let us = value * scale as f64;
+    if us.fract() != 0.0 {
+        return Err(Error::new(
+            span,
+            "duration must be a whole number of microseconds",
+        ));
+    }
+    if us > u64::MAX as f64 {
+        return Err(Error::new(span, "duration out of range"));
+    }
+
+    Ok(us as u64)
+}
+
+/// Parses a duration and converts it to the nearest number of `unit_us` microsecond units,
+/// checking it against `range`, which is given in units.
+pub fn parse_duration(
+    expr: &Expr,
+    unit_us: u64,
+    range: std::ops::RangeInclusive<u64>,
+) -> syn::Result<u64> {
+    let units = (parse_duration_us(expr, unit_us)? + unit_us / 2) / unit_us;
+
+    if !range.contains(&units) {
+        return Err(Error::new(
+            expr.span(),
+            format!(
+                "expected a duration between {} and {}",
+                format_us(range.start() * unit_us),
+                format_us(range.end() * unit_us)
+            ),
+        ));
+    }
+
+    Ok(units)
+}
+
+/// Formats a number of microseconds with the largest unit that keeps it readable.
+fn format_us(us: u64) -> String {
+    if us >= 1_000_000 && us % 10_000 == 0 {
+        format!("{}s", us as f64 / 1_000_000.0)
+    } else if us >= 1_000 && us % 10 == 0 {
+        format!("{}ms", us as f64 / 1_000.0)
+    } else {
+        format!("{us}us")
+    }
+}
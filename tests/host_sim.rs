@@ -0,0 +1,141 @@
+//! Drives the handlers of a custom service on the host, see `host_sim!`.
+#![cfg(feature = "host-sim")]
+
+use std::cell::RefCell;
+
+use da14531_sdk_macros::{configure_custom_server1_service, host_sim};
+
+host_sim!();
+
+use host_sim::{
+    ble_stack::{
+        host::att::{
+            ATT_DECL_CHARACTERISTIC, ATT_DECL_PRIMARY_SERVICE, ATT_DESC_CHAR_USER_DESCRIPTION,
+        },
+        profiles::custom::custs::custs1::task::{
+            Custs1ValWriteInd, Custs1ValueReqInd, KeMsgDynCusts1ValueReqRsp,
+        },
+        rwble_hl::error::HlErr,
+    },
+    Message,
+};
+
+configure_custom_server1_service!(
+    CONTROL: {
+        uuid: 0xFE00,
+        characteristics: {
+            MODE: {
+                uuid: 0xFE01,
+                permissions: { WRITE_ENABLED | WRITE_REQUEST_ACCEPTED },
+                length: 1,
+                user_description: "Mode",
+                write_handler: crate::handlers::on_mode,
+            },
+            STATUS: {
+                uuid: 0xFE02,
+                permissions: { READ_ENABLED },
+                length: crate::handlers::STATUS_LEN,
+                read_handler: crate::handlers::on_status,
+            },
+        },
+    },
+);
+
+mod handlers {
+    use super::*;
+
+    pub const STATUS_LEN: u16 = 2;
+
+    thread_local! {
+        pub static MODE: RefCell<Vec<u8>> = const { RefCell::new(Vec::new()) };
+    }
+
+    pub fn on_mode(param: &Custs1ValWriteInd) {
+        let value = unsafe { param.value.as_slice(param.length as usize) };
+        MODE.with(|mode| *mode.borrow_mut() = value.to_vec());
+    }
+
+    pub fn on_status(param: &Custs1ValueReqInd) {
+        let mut response = KeMsgDynCusts1ValueReqRsp::<2>::new(0, 0);
+        response.fields().conidx = param.conidx;
+        response.fields().att_idx = param.att_idx;
+        response.fields().length = 2;
+        response.fields().status = HlErr::ATT_ERR_NO_ERROR as u8;
+        unsafe { response.fields().value.as_mut_slice(2) }.copy_from_slice(&[0x12, 0x34]);
+        response.send();
+    }
+}
+
+use char_idx_map::{CHAR_MODE_HANDLE, CHAR_STATUS_HANDLE};
+
+#[test]
+fn att_db_layout() {
+    assert_eq!(CUSTS1_ATT_DB.len(), 6);
+    assert_eq!(CUSTS1_ATT_DB[0].uuid, ATT_DECL_PRIMARY_SERVICE);
+    assert_eq!(CUSTS1_ATT_DB[0].value, &[0x00, 0xFE]);
+    assert_eq!(CUSTS1_ATT_DB[1].uuid, ATT_DECL_CHARACTERISTIC);
+
+    let mode = CUSTS1_ATT_DB[CHAR_MODE_HANDLE as usize];
+    assert_eq!(mode.uuid, 0xFE01);
+    assert_eq!(mode.max_len(), 1);
+    assert!(mode.value_req());
+
+    let description = CUSTS1_ATT_DB[CHAR_MODE_HANDLE as usize + 1];
+    assert_eq!(description.uuid, ATT_DESC_CHAR_USER_DESCRIPTION);
+    assert_eq!(description.value, b"Mode");
+
+    assert_eq!(CUSTS1_ATT_DB[CHAR_STATUS_HANDLE as usize].uuid, 0xFE02);
+}
+
+#[test]
+fn write_reaches_handler() {
+    let sent = custs1_sim::write(0, CHAR_MODE_HANDLE, &[3]);
+
+    assert!(sent.is_empty());
+    handlers::MODE.with(|mode| assert_eq!(*mode.borrow(), [3]));
+}
+
+#[test]
+fn read_is_answered_by_handler() {
+    let sent = custs1_sim::read(1, CHAR_STATUS_HANDLE);
+
+    assert_eq!(
+        sent,
+        [Message::ValueReqRsp {
+            conidx: 1,
+            att_idx: CHAR_STATUS_HANDLE,
+            status: HlErr::ATT_ERR_NO_ERROR as u8,
+            value: vec![0x12, 0x34],
+        }]
+    );
+}
+
+#[test]
+fn unhandled_read_is_rejected() {
+    let sent = custs1_sim::read(0, CHAR_MODE_HANDLE);
+
+    assert_eq!(
+        sent,
+        [Message::ValueReqRsp {
+            conidx: 0,
+            att_idx: CHAR_MODE_HANDLE,
+            status: HlErr::ATT_ERR_APP_ERROR as u8,
+            value: vec![],
+        }]
+    );
+}
+
+#[test]
+fn att_info_is_rejected() {
+    let sent = custs1_sim::att_info(0, CHAR_MODE_HANDLE);
+
+    assert_eq!(
+        sent,
+        [Message::AttInfoRsp {
+            conidx: 0,
+            att_idx: CHAR_MODE_HANDLE,
+            length: 0,
+            status: HlErr::ATT_ERR_WRITE_NOT_PERMITTED as u8,
+        }]
+    );
+}
@@ -0,0 +1,21 @@
+extern crate self as da14531_sdk;
+
+#[path = "../support/sdk.rs"]
+mod sdk;
+
+use da14531_sdk_macros::configure_advertising;
+use sdk::*;
+
+configure_advertising! {
+    advertise_data: {
+        complete_name: "Tag",
+        uuid128_list: ["0000fe59-0000-1000-8000-00805f9b34fb"],
+    },
+    scan_response_data: {
+        uuid16_list: [0x180F, 0x180A],
+        manufacturer_data: (0x0A7A, b"\x01\x02"),
+        appearance: 0x0200,
+    },
+}
+
+fn main() {}
@@ -0,0 +1,19 @@
+extern crate self as da14531_sdk;
+
+#[path = "../support/sdk.rs"]
+mod sdk;
+
+use da14531_sdk_macros::configure_advertising_params;
+use sdk::*;
+
+configure_advertising_params! {
+    mode: LimitedDiscoverable,
+    interval_min: 100ms,
+    interval_max: 250ms,
+    channel_map: [37, 39],
+    filter_policy: AllowScanWlstConAny,
+    peer_addr: [0x01, 0x02, 0x03, 0x04, 0x05, 0x06],
+    peer_addr_type: Random,
+}
+
+fn main() {}
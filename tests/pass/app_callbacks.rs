@@ -0,0 +1,29 @@
+extern crate self as da14531_sdk;
+
+#[path = "../support/sdk.rs"]
+mod sdk;
+
+use da14531_sdk_macros::register_app_callbacks;
+use sdk::*;
+
+register_app_callbacks! {
+    app_on_connection: crate::ble::on_connection,
+    app_on_update_params_request: crate::ble::on_update,
+    app_on_adv_undirect_complete: crate::ble::on_adv_done,
+}
+
+mod ble {
+    use crate::ble_stack::host::gap::gapc::task::{
+        GapcConnectionReqInd, GapcParamUpdateCfm, GapcParamUpdateReqInd,
+    };
+
+    pub fn on_connection(_conidx: u8, _param: &GapcConnectionReqInd) {}
+
+    pub fn on_update(_param: &GapcParamUpdateReqInd, cfm: &mut GapcParamUpdateCfm) {
+        cfm.accept = true;
+    }
+
+    pub fn on_adv_done(_status: u8) {}
+}
+
+fn main() {}
@@ -0,0 +1,27 @@
+extern crate self as da14531_sdk;
+
+#[path = "../support/sdk.rs"]
+mod sdk;
+
+use da14531_sdk_macros::configure_battery_service;
+use sdk::*;
+
+configure_battery_service! {
+    battery_level: [crate::bat::main, crate::bat::backup],
+    notification: true,
+    on_notification_config: crate::bat::on_ntf_cfg,
+}
+
+mod bat {
+    pub fn main() -> u8 {
+        100
+    }
+
+    pub fn backup() -> u8 {
+        50
+    }
+
+    pub fn on_ntf_cfg(_conidx: u8, _enabled: bool) {}
+}
+
+fn main() {}
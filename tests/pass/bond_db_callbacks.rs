@@ -0,0 +1,36 @@
+extern crate self as da14531_sdk;
+
+#[path = "../support/sdk.rs"]
+mod sdk;
+
+use da14531_sdk_macros::register_bond_db_callbacks;
+use sdk::*;
+
+register_bond_db_callbacks! {
+    app_bdb_get_size: || 8,
+    app_bdb_search_entry: crate::bond_db::search,
+    app_bdb_remove_entry: |_, _, param| crate::bond_db::remove(param),
+    app_bdb_get_stored_irks: crate::bond_db::get_irks,
+}
+
+mod bond_db {
+    use crate::{
+        app_modules::{AppSecBondDataEnvTag, BdbSearchByType},
+        ble_stack::host::gap::GapSecKey,
+    };
+
+    pub fn search(
+        _search_type: BdbSearchByType,
+        _param: &[u8],
+    ) -> Option<&'static AppSecBondDataEnvTag> {
+        None
+    }
+
+    pub fn remove(_param: &[u8]) {}
+
+    pub fn get_irks(_irks: &mut [GapSecKey]) -> u8 {
+        0
+    }
+}
+
+fn main() {}
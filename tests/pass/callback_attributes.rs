@@ -0,0 +1,34 @@
+extern crate self as da14531_sdk;
+
+#[path = "../support/sdk.rs"]
+mod sdk;
+
+use core::sync::atomic::{AtomicBool, Ordering};
+
+use da14531_sdk_macros::{app_callback, assemble_callbacks, main_loop_callback};
+use sdk::*;
+
+static INITIALIZED: AtomicBool = AtomicBool::new(false);
+
+#[main_loop_callback(on_init)]
+fn init() {
+    INITIALIZED.store(true, Ordering::Relaxed);
+}
+
+#[app_callback(on_get_dev_appearance)]
+fn appearance(appearance: &mut u16) {
+    *appearance = 0x0200;
+}
+
+assemble_callbacks!();
+
+// rustc links executables with `--gc-sections`, so this also checks that the registered
+// functions survive it. The defaults of the stand-in panic if they are called instead.
+fn main() {
+    __app_on_init();
+    assert!(INITIALIZED.load(Ordering::Relaxed));
+
+    let mut appearance = 0;
+    __app_on_get_dev_appearance(&mut appearance);
+    assert_eq!(appearance, 0x0200);
+}
@@ -0,0 +1,20 @@
+extern crate self as da14531_sdk;
+
+#[path = "../support/sdk.rs"]
+mod sdk;
+
+use da14531_sdk_macros::configure_connection_params;
+use sdk::*;
+
+configure_connection_params! {
+    interval_min: 15ms,
+    interval_max: 30ms,
+    latency: 4,
+    timeout: 2s,
+    ce_len_max: 4,
+    peer_requests: InRange,
+}
+
+fn main() {
+    let _: fn(_, _) = connection_params::on_update_params_request;
+}
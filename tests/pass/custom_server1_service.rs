@@ -0,0 +1,73 @@
+extern crate self as da14531_sdk;
+
+#[path = "../support/sdk.rs"]
+mod sdk;
+
+use da14531_sdk_macros::configure_custom_server1_service;
+use sdk::*;
+
+// With `host-sim` the service is generated for the `host_sim!` stand-in instead.
+#[cfg(feature = "host-sim")]
+da14531_sdk_macros::host_sim!();
+
+configure_custom_server1_service! {
+    start_handle: 0x30,
+    CONTROL: {
+        uuid: 0xFE00,
+        advertise: true,
+        characteristics: {
+            MODE: {
+                uuid: 0xFE01,
+                permissions: { WRITE_ENABLED | WRITE_REQUEST_ACCEPTED },
+                length: 1,
+                user_description: "Mode",
+                write_handler: crate::ble::on_mode,
+            },
+            STATUS: {
+                uuid: 0xFE02,
+                permissions: { READ_AUTH | NOTIFICATION_ENABLED },
+                length: crate::ble::STATUS_LEN,
+                read_handler: crate::ble::on_status,
+            },
+            COMMAND: {
+                uuid: 0xFE03,
+                permissions: { WRITE_SECURE | WRITE_COMMAND_ACCEPTED | INDICATION_UNAUTH },
+                length: 20,
+                write_handler: crate::ble::on_command,
+            },
+        },
+    },
+}
+
+mod ble {
+    #[cfg(not(feature = "host-sim"))]
+    use crate::ble_stack::profiles::custom::custs::custs1::task::{
+        Custs1ValWriteInd, Custs1ValueReqInd,
+    };
+    #[cfg(feature = "host-sim")]
+    use crate::host_sim::ble_stack::profiles::custom::custs::custs1::task::{
+        Custs1ValWriteInd, Custs1ValueReqInd,
+    };
+
+    pub const STATUS_LEN: u16 = 2;
+
+    pub fn on_mode(_param: &Custs1ValWriteInd) {}
+
+    pub fn on_status(_param: &Custs1ValueReqInd) {}
+
+    pub fn on_command(_param: &Custs1ValWriteInd) {}
+}
+
+fn main() {
+    // The SDK finds the service through `rom_cust_prf_cfg`, whose table of callbacks creates the
+    // database with `app_custs1_create_db`.
+    #[cfg(not(feature = "host-sim"))]
+    {
+        use crate::platform::core_modules::rwip::TASK_ID_CUSTS1;
+
+        let get_func_callbacks = ROM_CUST_PRF_CFG.custs_get_func_callbacks.unwrap();
+        let funcs = unsafe { &*get_func_callbacks(TASK_ID_CUSTS1) };
+        assert!(core::ptr::eq(funcs, &CUST_PRF_FUNCS[0]));
+        assert!(funcs.db_create_func.is_some());
+    }
+}
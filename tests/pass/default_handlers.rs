@@ -0,0 +1,17 @@
+extern crate self as da14531_sdk;
+
+#[path = "../support/sdk.rs"]
+mod sdk;
+
+use da14531_sdk_macros::default_handlers_configuration;
+use sdk::*;
+
+use crate::app_modules::DEF_SEC_REQ_ON_CONNECT;
+
+default_handlers_configuration! {
+    adv_scenario: AdvertiseWithTimeout,
+    advertise_period: 30s,
+    security_request_scenario: DEF_SEC_REQ_ON_CONNECT,
+}
+
+fn main() {}
@@ -0,0 +1,33 @@
+extern crate self as da14531_sdk;
+
+#[path = "../support/sdk.rs"]
+mod sdk;
+
+use da14531_sdk_macros::configure_device_information_service;
+use sdk::*;
+
+use crate::ble_stack::profiles::dis::diss::task::DISS_SET_VALUE_RSP;
+
+configure_device_information_service! {
+    manufacturer_name: "ACME",
+    firm_rev_str: "1.0",
+    on_value_req: crate::dis::on_value_req,
+    handlers: {
+        DISS_SET_VALUE_RSP => crate::dis::on_set_value_rsp,
+    },
+}
+
+mod dis {
+    use crate::{
+        ble_stack::profiles::dis::diss::task::{DissSetValueRsp, DissValueReqInd},
+        platform::core_modules::ke::task::KeTaskId,
+    };
+
+    pub fn on_value_req(_param: &DissValueReqInd, _dest_id: KeTaskId, _src_id: KeTaskId) -> bool {
+        true
+    }
+
+    pub fn on_set_value_rsp(_param: &DissSetValueRsp, _dest_id: KeTaskId, _src_id: KeTaskId) {}
+}
+
+fn main() {}
@@ -0,0 +1,22 @@
+extern crate self as da14531_sdk;
+
+#[path = "../support/sdk.rs"]
+mod sdk;
+
+use da14531_sdk_macros::configure_gapm;
+use sdk::*;
+
+use crate::ble_stack::host::gap::gapm::GAPM_MASK_ATT_SVC_CHG_EN;
+
+configure_gapm! {
+    role: Peripheral,
+    max_mtu: 247,
+    addr_type: Static,
+    renew_dur: 150s,
+    att_cfg: GAPM_MASK_ATT_SVC_CHG_EN | 0x04,
+    max_mps: 247,
+    max_txoctets: 251,
+    max_txtime: 2120us,
+}
+
+fn main() {}
@@ -0,0 +1,23 @@
+extern crate self as da14531_sdk;
+
+#[path = "../support/sdk.rs"]
+mod sdk;
+
+use da14531_sdk_macros::register_main_loop_callbacks;
+use sdk::*;
+
+register_main_loop_callbacks! {
+    app_on_init: { crate::ble::init(); },
+    app_validate_sleep: |mode| mode,
+    app_going_to_sleep: crate::ble::sleep,
+}
+
+mod ble {
+    use crate::platform::arch::SleepMode;
+
+    pub fn init() {}
+
+    pub fn sleep(_mode: SleepMode) {}
+}
+
+fn main() {}
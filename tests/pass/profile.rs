@@ -0,0 +1,39 @@
+extern crate self as da14531_sdk;
+
+#[path = "../support/sdk.rs"]
+mod sdk;
+
+use da14531_sdk_macros::register_profile;
+use sdk::*;
+
+use crate::platform::core_modules::{ke::task::KeTaskId, rwip::KeApiId};
+
+const TASK_ID_FINDT: KeApiId = 30;
+const FINDT_ALERT_IND: u32 = 0x1E00;
+
+#[repr(C)]
+struct FindtDbCfg {
+    dummy: u8,
+}
+
+#[repr(C)]
+struct FindtAlertInd {
+    alert_lvl: u8,
+}
+
+register_profile! {
+    name: findt,
+    task_id: TASK_ID_FINDT,
+    db_cfg: FindtDbCfg,
+    handlers: {
+        FINDT_ALERT_IND => crate::ble::on_alert,
+    },
+}
+
+mod ble {
+    use super::*;
+
+    pub fn on_alert(_param: &FindtAlertInd, _dest_id: KeTaskId, _src_id: KeTaskId) {}
+}
+
+fn main() {}
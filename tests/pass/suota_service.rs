@@ -0,0 +1,26 @@
+extern crate self as da14531_sdk;
+
+#[path = "../support/sdk.rs"]
+mod sdk;
+
+use da14531_sdk_macros::configure_suota_service;
+use sdk::*;
+
+configure_suota_service! {
+    patch_memory: SpiFlash,
+    patch_region: 0x20000..0x38000,
+    max_patch_len: 240,
+    on_suota_start: crate::ota::on_start,
+    on_suota_status: crate::ota::on_status,
+    on_suota_finished: crate::ota::on_finished,
+}
+
+mod ota {
+    pub fn on_start() {}
+
+    pub fn on_status(_status: u8) {}
+
+    pub fn on_finished(_success: bool) {}
+}
+
+fn main() {}
@@ -0,0 +1,52 @@
+#[doc(hidden)]
+#[no_mangle]
+static __DA14531_SDK_USER_ADVERTISE_DATA: () = ();
+#[no_mangle]
+pub(crate) static USER_ADVERTISE_DATA: [u8; 23usize] = [
+    4u8,
+    9u8,
+    84u8,
+    97u8,
+    103u8,
+    17u8,
+    7u8,
+    251u8,
+    52u8,
+    155u8,
+    95u8,
+    128u8,
+    0u8,
+    0u8,
+    128u8,
+    0u8,
+    16u8,
+    0u8,
+    0u8,
+    89u8,
+    254u8,
+    0u8,
+    0u8,
+];
+#[no_mangle]
+pub(crate) static USER_ADVERTISE_DATA_LEN: u8 = 23u8;
+#[no_mangle]
+pub(crate) static USER_ADVERTISE_SCAN_RESPONSE_DATA: [u8; 16usize] = [
+    5u8,
+    3u8,
+    15u8,
+    24u8,
+    10u8,
+    24u8,
+    5u8,
+    255u8,
+    122u8,
+    10u8,
+    1u8,
+    2u8,
+    3u8,
+    25u8,
+    0u8,
+    2u8,
+];
+#[no_mangle]
+pub(crate) static USER_ADVERTISE_SCAN_RESPONSE_DATA_LEN: u8 = 16u8;
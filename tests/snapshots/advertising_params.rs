@@ -0,0 +1,14 @@
+#[doc(hidden)]
+#[no_mangle]
+static __DA14531_SDK_USER_ADV_CONF: () = ();
+#[export_name = "user_adv_conf"]
+pub(crate) static USER_ADV_CONF: da14531_sdk::app_modules::AdvertiseConfiguration = da14531_sdk::app_modules::AdvertiseConfiguration {
+    intv_min: 160u16,
+    intv_max: 400u16,
+    channel_map: 5u8,
+    mode: da14531_sdk::ble_stack::host::gap::gapm::task::GAP_LIM_DISCOVERABLE as _,
+    adv_filt_policy: da14531_sdk::platform::core_modules::common::ADV_ALLOW_SCAN_WLST_CON_ANY
+        as _,
+    peer_addr: [1u8, 2u8, 3u8, 4u8, 5u8, 6u8],
+    peer_addr_type: da14531_sdk::platform::core_modules::common::ADDR_RAND as _,
+};
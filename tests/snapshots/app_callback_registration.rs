@@ -0,0 +1,8 @@
+fn appearance(appearance: &mut u16) {
+    *appearance = 0x0200;
+}
+const _: () = {
+    #[used]
+    #[link_section = "da14531_cb_app_on_get_dev_appearance"]
+    static ENTRY: Option<fn(&mut u16)> = Some(appearance);
+};
@@ -0,0 +1,67 @@
+#[doc(hidden)]
+#[no_mangle]
+static __DA14531_SDK_USER_APP_CALLBACKS: () = ();
+extern "C" fn __app_on_connection(
+    conidx: u8,
+    param: *const da14531_sdk::ble_stack::host::gap::gapc::task::GapcConnectionReqInd,
+) {
+    {
+        const APP_ON_CONNECTION: fn(
+            u8,
+            &da14531_sdk::ble_stack::host::gap::gapc::task::GapcConnectionReqInd,
+        ) = crate::ble::on_connection;
+        APP_ON_CONNECTION(conidx, unsafe { &*param })
+    }
+}
+extern "C" fn __app_on_adv_undirect_complete(status: u8) {
+    {
+        const APP_ON_ADV_UNDIRECT_COMPLETE: fn(u8) = crate::ble::on_adv_done;
+        APP_ON_ADV_UNDIRECT_COMPLETE(status)
+    }
+}
+extern "C" fn __app_on_update_params_request(
+    param: *const da14531_sdk::ble_stack::host::gap::gapc::task::GapcParamUpdateReqInd,
+    cfm: *mut da14531_sdk::ble_stack::host::gap::gapc::task::GapcParamUpdateCfm,
+) {
+    {
+        const APP_ON_UPDATE_PARAMS_REQUEST: fn(
+            &da14531_sdk::ble_stack::host::gap::gapc::task::GapcParamUpdateReqInd,
+            &mut da14531_sdk::ble_stack::host::gap::gapc::task::GapcParamUpdateCfm,
+        ) = crate::ble::on_update;
+        APP_ON_UPDATE_PARAMS_REQUEST(unsafe { &*param }, unsafe { &mut *cfm })
+    }
+}
+#[export_name = "user_app_callbacks"]
+pub(crate) static USER_APP_CALLBACKS: da14531_sdk::app_modules::AppCallbacks = da14531_sdk::app_modules::AppCallbacks {
+    app_on_connection: Some(__app_on_connection),
+    app_on_disconnect: None,
+    app_on_update_params_rejected: None,
+    app_on_update_params_complete: None,
+    app_on_set_dev_config_complete: Some(
+        da14531_sdk::bindings::default_app_on_set_dev_config_complete,
+    ),
+    app_on_adv_nonconn_complete: None,
+    app_on_adv_undirect_complete: Some(__app_on_adv_undirect_complete),
+    app_on_adv_direct_complete: None,
+    app_on_db_init_complete: Some(
+        da14531_sdk::bindings::default_app_on_db_init_complete,
+    ),
+    app_on_scanning_completed: None,
+    app_on_adv_report_ind: None,
+    app_on_connect_failed: None,
+    app_on_get_dev_name: Some(da14531_sdk::bindings::default_app_on_get_dev_name),
+    app_on_get_dev_appearance: Some(
+        da14531_sdk::bindings::default_app_on_get_dev_appearance,
+    ),
+    app_on_get_dev_slv_pref_params: Some(
+        da14531_sdk::bindings::default_app_on_get_dev_slv_pref_params,
+    ),
+    app_on_set_dev_info: Some(da14531_sdk::bindings::default_app_on_set_dev_info),
+    app_on_data_length_change: None,
+    app_on_update_params_request: Some(__app_on_update_params_request),
+    app_on_generate_static_random_addr: Some(
+        da14531_sdk::bindings::default_app_generate_static_random_addr,
+    ),
+    app_on_svc_changed_cfg_ind: None,
+    app_on_get_peer_features: None,
+};
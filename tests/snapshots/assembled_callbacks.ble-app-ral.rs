@@ -0,0 +1,1212 @@
+#[doc(hidden)]
+#[no_mangle]
+static __DA14531_SDK_USER_APP_MAIN_LOOP_CALLBACKS: () = ();
+extern "C" fn __app_on_init() {
+    #[used]
+    #[link_section = "da14531_cb_app_on_init"]
+    static SENTINEL: Option<fn()> = None;
+    extern "C" {
+        #[link_name = "__start_da14531_cb_app_on_init"]
+        static START: Option<fn()>;
+        #[link_name = "__stop_da14531_cb_app_on_init"]
+        static STOP: Option<fn()>;
+    }
+    let entries = unsafe {
+        let start = core::ptr::addr_of!(START);
+        let stop = core::ptr::addr_of!(STOP);
+        core::slice::from_raw_parts(start, stop.offset_from(start) as usize)
+    };
+    let mut called = false;
+    for callback in entries.iter().flatten() {
+        callback();
+        called = true;
+    }
+    if !called {}
+}
+extern "C" fn __app_on_ble_powered() -> da14531_sdk::platform::arch::ArchMainLoopCallbackRet {
+    #[used]
+    #[link_section = "da14531_cb_app_on_ble_powered"]
+    static SENTINEL: Option<
+        fn() -> da14531_sdk::platform::arch::ArchMainLoopCallbackRet,
+    > = None;
+    extern "C" {
+        #[link_name = "__start_da14531_cb_app_on_ble_powered"]
+        static START: Option<
+            fn() -> da14531_sdk::platform::arch::ArchMainLoopCallbackRet,
+        >;
+        #[link_name = "__stop_da14531_cb_app_on_ble_powered"]
+        static STOP: Option<
+            fn() -> da14531_sdk::platform::arch::ArchMainLoopCallbackRet,
+        >;
+    }
+    let entries = unsafe {
+        let start = core::ptr::addr_of!(START);
+        let stop = core::ptr::addr_of!(STOP);
+        core::slice::from_raw_parts(start, stop.offset_from(start) as usize)
+    };
+    let mut result = None;
+    for callback in entries.iter().flatten() {
+        let next = callback();
+        if result != Some(da14531_sdk::platform::arch::KEEP_POWERED) {
+            result = Some(next);
+        }
+    }
+    match result {
+        Some(result) => result,
+        None => da14531_sdk::platform::arch::GOTO_SLEEP,
+    }
+}
+extern "C" fn __app_on_system_powered() -> da14531_sdk::platform::arch::ArchMainLoopCallbackRet {
+    #[used]
+    #[link_section = "da14531_cb_app_on_system_powered"]
+    static SENTINEL: Option<
+        fn() -> da14531_sdk::platform::arch::ArchMainLoopCallbackRet,
+    > = None;
+    extern "C" {
+        #[link_name = "__start_da14531_cb_app_on_system_powered"]
+        static START: Option<
+            fn() -> da14531_sdk::platform::arch::ArchMainLoopCallbackRet,
+        >;
+        #[link_name = "__stop_da14531_cb_app_on_system_powered"]
+        static STOP: Option<
+            fn() -> da14531_sdk::platform::arch::ArchMainLoopCallbackRet,
+        >;
+    }
+    let entries = unsafe {
+        let start = core::ptr::addr_of!(START);
+        let stop = core::ptr::addr_of!(STOP);
+        core::slice::from_raw_parts(start, stop.offset_from(start) as usize)
+    };
+    let mut result = None;
+    for callback in entries.iter().flatten() {
+        let next = callback();
+        if result != Some(da14531_sdk::platform::arch::KEEP_POWERED) {
+            result = Some(next);
+        }
+    }
+    match result {
+        Some(result) => result,
+        None => da14531_sdk::platform::arch::GOTO_SLEEP,
+    }
+}
+extern "C" fn __app_before_sleep() {
+    #[used]
+    #[link_section = "da14531_cb_app_before_sleep"]
+    static SENTINEL: Option<fn()> = None;
+    extern "C" {
+        #[link_name = "__start_da14531_cb_app_before_sleep"]
+        static START: Option<fn()>;
+        #[link_name = "__stop_da14531_cb_app_before_sleep"]
+        static STOP: Option<fn()>;
+    }
+    let entries = unsafe {
+        let start = core::ptr::addr_of!(START);
+        let stop = core::ptr::addr_of!(STOP);
+        core::slice::from_raw_parts(start, stop.offset_from(start) as usize)
+    };
+    let mut called = false;
+    for callback in entries.iter().flatten() {
+        callback();
+        called = true;
+    }
+    if !called {}
+}
+extern "C" fn __app_validate_sleep(
+    mode: da14531_sdk::platform::arch::SleepMode,
+) -> da14531_sdk::platform::arch::SleepMode {
+    #[used]
+    #[link_section = "da14531_cb_app_validate_sleep"]
+    static SENTINEL: Option<
+        fn(
+            da14531_sdk::platform::arch::SleepMode,
+        ) -> da14531_sdk::platform::arch::SleepMode,
+    > = None;
+    extern "C" {
+        #[link_name = "__start_da14531_cb_app_validate_sleep"]
+        static START: Option<
+            fn(
+                da14531_sdk::platform::arch::SleepMode,
+            ) -> da14531_sdk::platform::arch::SleepMode,
+        >;
+        #[link_name = "__stop_da14531_cb_app_validate_sleep"]
+        static STOP: Option<
+            fn(
+                da14531_sdk::platform::arch::SleepMode,
+            ) -> da14531_sdk::platform::arch::SleepMode,
+        >;
+    }
+    let entries = unsafe {
+        let start = core::ptr::addr_of!(START);
+        let stop = core::ptr::addr_of!(STOP);
+        core::slice::from_raw_parts(start, stop.offset_from(start) as usize)
+    };
+    let mut mode = mode;
+    for callback in entries.iter().flatten() {
+        mode = callback(mode);
+    }
+    mode
+}
+extern "C" fn __app_going_to_sleep(mode: da14531_sdk::platform::arch::SleepMode) {
+    #[used]
+    #[link_section = "da14531_cb_app_going_to_sleep"]
+    static SENTINEL: Option<fn(da14531_sdk::platform::arch::SleepMode)> = None;
+    extern "C" {
+        #[link_name = "__start_da14531_cb_app_going_to_sleep"]
+        static START: Option<fn(da14531_sdk::platform::arch::SleepMode)>;
+        #[link_name = "__stop_da14531_cb_app_going_to_sleep"]
+        static STOP: Option<fn(da14531_sdk::platform::arch::SleepMode)>;
+    }
+    let entries = unsafe {
+        let start = core::ptr::addr_of!(START);
+        let stop = core::ptr::addr_of!(STOP);
+        core::slice::from_raw_parts(start, stop.offset_from(start) as usize)
+    };
+    let mut called = false;
+    for callback in entries.iter().flatten() {
+        callback(mode);
+        called = true;
+    }
+    if !called {}
+}
+extern "C" fn __app_resume_from_sleep() {
+    #[used]
+    #[link_section = "da14531_cb_app_resume_from_sleep"]
+    static SENTINEL: Option<fn()> = None;
+    extern "C" {
+        #[link_name = "__start_da14531_cb_app_resume_from_sleep"]
+        static START: Option<fn()>;
+        #[link_name = "__stop_da14531_cb_app_resume_from_sleep"]
+        static STOP: Option<fn()>;
+    }
+    let entries = unsafe {
+        let start = core::ptr::addr_of!(START);
+        let stop = core::ptr::addr_of!(STOP);
+        core::slice::from_raw_parts(start, stop.offset_from(start) as usize)
+    };
+    let mut called = false;
+    for callback in entries.iter().flatten() {
+        callback();
+        called = true;
+    }
+    if !called {}
+}
+#[export_name = "user_app_main_loop_callbacks"]
+pub(crate) static USER_APP_MAIN_LOOP_CALLBACKS: da14531_sdk::platform::arch::ArchMainLoopCallbacks = da14531_sdk::platform::arch::ArchMainLoopCallbacks {
+    app_on_init: Some(__app_on_init),
+    app_on_ble_powered: Some(__app_on_ble_powered),
+    app_on_system_powered: Some(__app_on_system_powered),
+    app_before_sleep: Some(__app_before_sleep),
+    app_validate_sleep: Some(__app_validate_sleep),
+    app_going_to_sleep: Some(__app_going_to_sleep),
+    app_resume_from_sleep: Some(__app_resume_from_sleep),
+};
+#[doc(hidden)]
+#[no_mangle]
+static __DA14531_SDK_USER_APP_CALLBACKS: () = ();
+extern "C" fn __app_on_connection(
+    conidx: u8,
+    param: *const da14531_sdk::ble_stack::host::gap::gapc::task::GapcConnectionReqInd,
+) {
+    #[used]
+    #[link_section = "da14531_cb_app_on_connection"]
+    static SENTINEL: Option<
+        fn(u8, &da14531_sdk::ble_stack::host::gap::gapc::task::GapcConnectionReqInd),
+    > = None;
+    extern "C" {
+        #[link_name = "__start_da14531_cb_app_on_connection"]
+        static START: Option<
+            fn(u8, &da14531_sdk::ble_stack::host::gap::gapc::task::GapcConnectionReqInd),
+        >;
+        #[link_name = "__stop_da14531_cb_app_on_connection"]
+        static STOP: Option<
+            fn(u8, &da14531_sdk::ble_stack::host::gap::gapc::task::GapcConnectionReqInd),
+        >;
+    }
+    let entries = unsafe {
+        let start = core::ptr::addr_of!(START);
+        let stop = core::ptr::addr_of!(STOP);
+        core::slice::from_raw_parts(start, stop.offset_from(start) as usize)
+    };
+    let mut called = false;
+    for callback in entries.iter().flatten() {
+        callback(conidx, unsafe { &*param });
+        called = true;
+    }
+    if !called {
+        unsafe { da14531_sdk::bindings::default_app_on_connection(conidx, param) }
+    }
+}
+extern "C" fn __app_on_disconnect(
+    param: *const da14531_sdk::ble_stack::host::gap::gapc::task::GapcDisconnectInd,
+) {
+    #[used]
+    #[link_section = "da14531_cb_app_on_disconnect"]
+    static SENTINEL: Option<
+        fn(&da14531_sdk::ble_stack::host::gap::gapc::task::GapcDisconnectInd),
+    > = None;
+    extern "C" {
+        #[link_name = "__start_da14531_cb_app_on_disconnect"]
+        static START: Option<
+            fn(&da14531_sdk::ble_stack::host::gap::gapc::task::GapcDisconnectInd),
+        >;
+        #[link_name = "__stop_da14531_cb_app_on_disconnect"]
+        static STOP: Option<
+            fn(&da14531_sdk::ble_stack::host::gap::gapc::task::GapcDisconnectInd),
+        >;
+    }
+    let entries = unsafe {
+        let start = core::ptr::addr_of!(START);
+        let stop = core::ptr::addr_of!(STOP);
+        core::slice::from_raw_parts(start, stop.offset_from(start) as usize)
+    };
+    let mut called = false;
+    for callback in entries.iter().flatten() {
+        callback(unsafe { &*param });
+        called = true;
+    }
+    if !called {}
+}
+extern "C" fn __app_on_update_params_rejected(status: u8) {
+    #[used]
+    #[link_section = "da14531_cb_app_on_update_params_rejected"]
+    static SENTINEL: Option<fn(u8)> = None;
+    extern "C" {
+        #[link_name = "__start_da14531_cb_app_on_update_params_rejected"]
+        static START: Option<fn(u8)>;
+        #[link_name = "__stop_da14531_cb_app_on_update_params_rejected"]
+        static STOP: Option<fn(u8)>;
+    }
+    let entries = unsafe {
+        let start = core::ptr::addr_of!(START);
+        let stop = core::ptr::addr_of!(STOP);
+        core::slice::from_raw_parts(start, stop.offset_from(start) as usize)
+    };
+    let mut called = false;
+    for callback in entries.iter().flatten() {
+        callback(status);
+        called = true;
+    }
+    if !called {}
+}
+extern "C" fn __app_on_update_params_complete() {
+    #[used]
+    #[link_section = "da14531_cb_app_on_update_params_complete"]
+    static SENTINEL: Option<fn()> = None;
+    extern "C" {
+        #[link_name = "__start_da14531_cb_app_on_update_params_complete"]
+        static START: Option<fn()>;
+        #[link_name = "__stop_da14531_cb_app_on_update_params_complete"]
+        static STOP: Option<fn()>;
+    }
+    let entries = unsafe {
+        let start = core::ptr::addr_of!(START);
+        let stop = core::ptr::addr_of!(STOP);
+        core::slice::from_raw_parts(start, stop.offset_from(start) as usize)
+    };
+    let mut called = false;
+    for callback in entries.iter().flatten() {
+        callback();
+        called = true;
+    }
+    if !called {}
+}
+extern "C" fn __app_on_set_dev_config_complete() {
+    #[used]
+    #[link_section = "da14531_cb_app_on_set_dev_config_complete"]
+    static SENTINEL: Option<fn()> = None;
+    extern "C" {
+        #[link_name = "__start_da14531_cb_app_on_set_dev_config_complete"]
+        static START: Option<fn()>;
+        #[link_name = "__stop_da14531_cb_app_on_set_dev_config_complete"]
+        static STOP: Option<fn()>;
+    }
+    let entries = unsafe {
+        let start = core::ptr::addr_of!(START);
+        let stop = core::ptr::addr_of!(STOP);
+        core::slice::from_raw_parts(start, stop.offset_from(start) as usize)
+    };
+    let mut called = false;
+    for callback in entries.iter().flatten() {
+        callback();
+        called = true;
+    }
+    if !called {
+        unsafe { da14531_sdk::bindings::default_app_on_set_dev_config_complete() }
+    }
+}
+extern "C" fn __app_on_adv_nonconn_complete(status: u8) {
+    #[used]
+    #[link_section = "da14531_cb_app_on_adv_nonconn_complete"]
+    static SENTINEL: Option<fn(u8)> = None;
+    extern "C" {
+        #[link_name = "__start_da14531_cb_app_on_adv_nonconn_complete"]
+        static START: Option<fn(u8)>;
+        #[link_name = "__stop_da14531_cb_app_on_adv_nonconn_complete"]
+        static STOP: Option<fn(u8)>;
+    }
+    let entries = unsafe {
+        let start = core::ptr::addr_of!(START);
+        let stop = core::ptr::addr_of!(STOP);
+        core::slice::from_raw_parts(start, stop.offset_from(start) as usize)
+    };
+    let mut called = false;
+    for callback in entries.iter().flatten() {
+        callback(status);
+        called = true;
+    }
+    if !called {}
+}
+extern "C" fn __app_on_adv_undirect_complete(status: u8) {
+    #[used]
+    #[link_section = "da14531_cb_app_on_adv_undirect_complete"]
+    static SENTINEL: Option<fn(u8)> = None;
+    extern "C" {
+        #[link_name = "__start_da14531_cb_app_on_adv_undirect_complete"]
+        static START: Option<fn(u8)>;
+        #[link_name = "__stop_da14531_cb_app_on_adv_undirect_complete"]
+        static STOP: Option<fn(u8)>;
+    }
+    let entries = unsafe {
+        let start = core::ptr::addr_of!(START);
+        let stop = core::ptr::addr_of!(STOP);
+        core::slice::from_raw_parts(start, stop.offset_from(start) as usize)
+    };
+    let mut called = false;
+    for callback in entries.iter().flatten() {
+        callback(status);
+        called = true;
+    }
+    if !called {}
+}
+extern "C" fn __app_on_adv_direct_complete(status: u8) {
+    #[used]
+    #[link_section = "da14531_cb_app_on_adv_direct_complete"]
+    static SENTINEL: Option<fn(u8)> = None;
+    extern "C" {
+        #[link_name = "__start_da14531_cb_app_on_adv_direct_complete"]
+        static START: Option<fn(u8)>;
+        #[link_name = "__stop_da14531_cb_app_on_adv_direct_complete"]
+        static STOP: Option<fn(u8)>;
+    }
+    let entries = unsafe {
+        let start = core::ptr::addr_of!(START);
+        let stop = core::ptr::addr_of!(STOP);
+        core::slice::from_raw_parts(start, stop.offset_from(start) as usize)
+    };
+    let mut called = false;
+    for callback in entries.iter().flatten() {
+        callback(status);
+        called = true;
+    }
+    if !called {}
+}
+extern "C" fn __app_on_db_init_complete() {
+    #[used]
+    #[link_section = "da14531_cb_app_on_db_init_complete"]
+    static SENTINEL: Option<fn()> = None;
+    extern "C" {
+        #[link_name = "__start_da14531_cb_app_on_db_init_complete"]
+        static START: Option<fn()>;
+        #[link_name = "__stop_da14531_cb_app_on_db_init_complete"]
+        static STOP: Option<fn()>;
+    }
+    let entries = unsafe {
+        let start = core::ptr::addr_of!(START);
+        let stop = core::ptr::addr_of!(STOP);
+        core::slice::from_raw_parts(start, stop.offset_from(start) as usize)
+    };
+    let mut called = false;
+    for callback in entries.iter().flatten() {
+        callback();
+        called = true;
+    }
+    if !called {
+        unsafe { da14531_sdk::bindings::default_app_on_db_init_complete() }
+    }
+}
+extern "C" fn __app_on_scanning_completed(status: u8) {
+    #[used]
+    #[link_section = "da14531_cb_app_on_scanning_completed"]
+    static SENTINEL: Option<fn(u8)> = None;
+    extern "C" {
+        #[link_name = "__start_da14531_cb_app_on_scanning_completed"]
+        static START: Option<fn(u8)>;
+        #[link_name = "__stop_da14531_cb_app_on_scanning_completed"]
+        static STOP: Option<fn(u8)>;
+    }
+    let entries = unsafe {
+        let start = core::ptr::addr_of!(START);
+        let stop = core::ptr::addr_of!(STOP);
+        core::slice::from_raw_parts(start, stop.offset_from(start) as usize)
+    };
+    let mut called = false;
+    for callback in entries.iter().flatten() {
+        callback(status);
+        called = true;
+    }
+    if !called {}
+}
+extern "C" fn __app_on_adv_report_ind(
+    param: *const da14531_sdk::ble_stack::host::gap::gapm::task::GapmAdvReportInd,
+) {
+    #[used]
+    #[link_section = "da14531_cb_app_on_adv_report_ind"]
+    static SENTINEL: Option<
+        fn(&da14531_sdk::ble_stack::host::gap::gapm::task::GapmAdvReportInd),
+    > = None;
+    extern "C" {
+        #[link_name = "__start_da14531_cb_app_on_adv_report_ind"]
+        static START: Option<
+            fn(&da14531_sdk::ble_stack::host::gap::gapm::task::GapmAdvReportInd),
+        >;
+        #[link_name = "__stop_da14531_cb_app_on_adv_report_ind"]
+        static STOP: Option<
+            fn(&da14531_sdk::ble_stack::host::gap::gapm::task::GapmAdvReportInd),
+        >;
+    }
+    let entries = unsafe {
+        let start = core::ptr::addr_of!(START);
+        let stop = core::ptr::addr_of!(STOP);
+        core::slice::from_raw_parts(start, stop.offset_from(start) as usize)
+    };
+    let mut called = false;
+    for callback in entries.iter().flatten() {
+        callback(unsafe { &*param });
+        called = true;
+    }
+    if !called {}
+}
+extern "C" fn __app_on_connect_failed() {
+    #[used]
+    #[link_section = "da14531_cb_app_on_connect_failed"]
+    static SENTINEL: Option<fn()> = None;
+    extern "C" {
+        #[link_name = "__start_da14531_cb_app_on_connect_failed"]
+        static START: Option<fn()>;
+        #[link_name = "__stop_da14531_cb_app_on_connect_failed"]
+        static STOP: Option<fn()>;
+    }
+    let entries = unsafe {
+        let start = core::ptr::addr_of!(START);
+        let stop = core::ptr::addr_of!(STOP);
+        core::slice::from_raw_parts(start, stop.offset_from(start) as usize)
+    };
+    let mut called = false;
+    for callback in entries.iter().flatten() {
+        callback();
+        called = true;
+    }
+    if !called {}
+}
+extern "C" fn __app_on_get_dev_name(
+    dev_name: *mut da14531_sdk::app_modules::AppDeviceName,
+) {
+    #[used]
+    #[link_section = "da14531_cb_app_on_get_dev_name"]
+    static SENTINEL: Option<fn(&mut da14531_sdk::app_modules::AppDeviceName)> = None;
+    extern "C" {
+        #[link_name = "__start_da14531_cb_app_on_get_dev_name"]
+        static START: Option<fn(&mut da14531_sdk::app_modules::AppDeviceName)>;
+        #[link_name = "__stop_da14531_cb_app_on_get_dev_name"]
+        static STOP: Option<fn(&mut da14531_sdk::app_modules::AppDeviceName)>;
+    }
+    let entries = unsafe {
+        let start = core::ptr::addr_of!(START);
+        let stop = core::ptr::addr_of!(STOP);
+        core::slice::from_raw_parts(start, stop.offset_from(start) as usize)
+    };
+    let mut called = false;
+    for callback in entries.iter().flatten() {
+        callback(unsafe { &mut *dev_name });
+        called = true;
+    }
+    if !called {
+        unsafe { da14531_sdk::bindings::default_app_on_get_dev_name(dev_name) }
+    }
+}
+extern "C" fn __app_on_get_dev_appearance(appearance: *mut u16) {
+    #[used]
+    #[link_section = "da14531_cb_app_on_get_dev_appearance"]
+    static SENTINEL: Option<fn(&mut u16)> = None;
+    extern "C" {
+        #[link_name = "__start_da14531_cb_app_on_get_dev_appearance"]
+        static START: Option<fn(&mut u16)>;
+        #[link_name = "__stop_da14531_cb_app_on_get_dev_appearance"]
+        static STOP: Option<fn(&mut u16)>;
+    }
+    let entries = unsafe {
+        let start = core::ptr::addr_of!(START);
+        let stop = core::ptr::addr_of!(STOP);
+        core::slice::from_raw_parts(start, stop.offset_from(start) as usize)
+    };
+    let mut called = false;
+    for callback in entries.iter().flatten() {
+        callback(unsafe { &mut *appearance });
+        called = true;
+    }
+    if !called {
+        unsafe { da14531_sdk::bindings::default_app_on_get_dev_appearance(appearance) }
+    }
+}
+extern "C" fn __app_on_get_dev_slv_pref_params(
+    slv_params: *mut da14531_sdk::ble_stack::host::gap::GapSlvPref,
+) {
+    #[used]
+    #[link_section = "da14531_cb_app_on_get_dev_slv_pref_params"]
+    static SENTINEL: Option<fn(&mut da14531_sdk::ble_stack::host::gap::GapSlvPref)> = None;
+    extern "C" {
+        #[link_name = "__start_da14531_cb_app_on_get_dev_slv_pref_params"]
+        static START: Option<fn(&mut da14531_sdk::ble_stack::host::gap::GapSlvPref)>;
+        #[link_name = "__stop_da14531_cb_app_on_get_dev_slv_pref_params"]
+        static STOP: Option<fn(&mut da14531_sdk::ble_stack::host::gap::GapSlvPref)>;
+    }
+    let entries = unsafe {
+        let start = core::ptr::addr_of!(START);
+        let stop = core::ptr::addr_of!(STOP);
+        core::slice::from_raw_parts(start, stop.offset_from(start) as usize)
+    };
+    let mut called = false;
+    for callback in entries.iter().flatten() {
+        callback(unsafe { &mut *slv_params });
+        called = true;
+    }
+    if !called {
+        unsafe {
+            da14531_sdk::bindings::default_app_on_get_dev_slv_pref_params(slv_params)
+        }
+    }
+}
+extern "C" fn __app_on_set_dev_info(
+    param: *const da14531_sdk::ble_stack::host::gap::gapc::task::GapcSetDevInfoReqInd,
+    status: *mut u8,
+) {
+    #[used]
+    #[link_section = "da14531_cb_app_on_set_dev_info"]
+    static SENTINEL: Option<
+        fn(&da14531_sdk::ble_stack::host::gap::gapc::task::GapcSetDevInfoReqInd, &mut u8),
+    > = None;
+    extern "C" {
+        #[link_name = "__start_da14531_cb_app_on_set_dev_info"]
+        static START: Option<
+            fn(
+                &da14531_sdk::ble_stack::host::gap::gapc::task::GapcSetDevInfoReqInd,
+                &mut u8,
+            ),
+        >;
+        #[link_name = "__stop_da14531_cb_app_on_set_dev_info"]
+        static STOP: Option<
+            fn(
+                &da14531_sdk::ble_stack::host::gap::gapc::task::GapcSetDevInfoReqInd,
+                &mut u8,
+            ),
+        >;
+    }
+    let entries = unsafe {
+        let start = core::ptr::addr_of!(START);
+        let stop = core::ptr::addr_of!(STOP);
+        core::slice::from_raw_parts(start, stop.offset_from(start) as usize)
+    };
+    let mut called = false;
+    for callback in entries.iter().flatten() {
+        callback(unsafe { &*param }, unsafe { &mut *status });
+        called = true;
+    }
+    if !called {
+        unsafe { da14531_sdk::bindings::default_app_on_set_dev_info(param, status) }
+    }
+}
+extern "C" fn __app_on_data_length_change(
+    conidx: u8,
+    param: *const da14531_sdk::ble_stack::host::gap::gapc::task::GapcLePktSizeInd,
+) {
+    #[used]
+    #[link_section = "da14531_cb_app_on_data_length_change"]
+    static SENTINEL: Option<
+        fn(u8, &da14531_sdk::ble_stack::host::gap::gapc::task::GapcLePktSizeInd),
+    > = None;
+    extern "C" {
+        #[link_name = "__start_da14531_cb_app_on_data_length_change"]
+        static START: Option<
+            fn(u8, &da14531_sdk::ble_stack::host::gap::gapc::task::GapcLePktSizeInd),
+        >;
+        #[link_name = "__stop_da14531_cb_app_on_data_length_change"]
+        static STOP: Option<
+            fn(u8, &da14531_sdk::ble_stack::host::gap::gapc::task::GapcLePktSizeInd),
+        >;
+    }
+    let entries = unsafe {
+        let start = core::ptr::addr_of!(START);
+        let stop = core::ptr::addr_of!(STOP);
+        core::slice::from_raw_parts(start, stop.offset_from(start) as usize)
+    };
+    let mut called = false;
+    for callback in entries.iter().flatten() {
+        callback(conidx, unsafe { &*param });
+        called = true;
+    }
+    if !called {}
+}
+extern "C" fn __app_on_update_params_request(
+    param: *const da14531_sdk::ble_stack::host::gap::gapc::task::GapcParamUpdateReqInd,
+    cfm: *mut da14531_sdk::ble_stack::host::gap::gapc::task::GapcParamUpdateCfm,
+) {
+    #[used]
+    #[link_section = "da14531_cb_app_on_update_params_request"]
+    static SENTINEL: Option<
+        fn(
+            &da14531_sdk::ble_stack::host::gap::gapc::task::GapcParamUpdateReqInd,
+            &mut da14531_sdk::ble_stack::host::gap::gapc::task::GapcParamUpdateCfm,
+        ),
+    > = None;
+    extern "C" {
+        #[link_name = "__start_da14531_cb_app_on_update_params_request"]
+        static START: Option<
+            fn(
+                &da14531_sdk::ble_stack::host::gap::gapc::task::GapcParamUpdateReqInd,
+                &mut da14531_sdk::ble_stack::host::gap::gapc::task::GapcParamUpdateCfm,
+            ),
+        >;
+        #[link_name = "__stop_da14531_cb_app_on_update_params_request"]
+        static STOP: Option<
+            fn(
+                &da14531_sdk::ble_stack::host::gap::gapc::task::GapcParamUpdateReqInd,
+                &mut da14531_sdk::ble_stack::host::gap::gapc::task::GapcParamUpdateCfm,
+            ),
+        >;
+    }
+    let entries = unsafe {
+        let start = core::ptr::addr_of!(START);
+        let stop = core::ptr::addr_of!(STOP);
+        core::slice::from_raw_parts(start, stop.offset_from(start) as usize)
+    };
+    let mut called = false;
+    for callback in entries.iter().flatten() {
+        callback(unsafe { &*param }, unsafe { &mut *cfm });
+        called = true;
+    }
+    if !called {
+        unsafe { da14531_sdk::bindings::default_app_update_params_request(param, cfm) }
+    }
+}
+extern "C" fn __app_on_generate_static_random_addr(
+    addr: *mut da14531_sdk::platform::core_modules::common::BDAddr,
+) {
+    #[used]
+    #[link_section = "da14531_cb_app_on_generate_static_random_addr"]
+    static SENTINEL: Option<
+        fn(&mut da14531_sdk::platform::core_modules::common::BDAddr),
+    > = None;
+    extern "C" {
+        #[link_name = "__start_da14531_cb_app_on_generate_static_random_addr"]
+        static START: Option<
+            fn(&mut da14531_sdk::platform::core_modules::common::BDAddr),
+        >;
+        #[link_name = "__stop_da14531_cb_app_on_generate_static_random_addr"]
+        static STOP: Option<
+            fn(&mut da14531_sdk::platform::core_modules::common::BDAddr),
+        >;
+    }
+    let entries = unsafe {
+        let start = core::ptr::addr_of!(START);
+        let stop = core::ptr::addr_of!(STOP);
+        core::slice::from_raw_parts(start, stop.offset_from(start) as usize)
+    };
+    let mut called = false;
+    for callback in entries.iter().flatten() {
+        callback(unsafe { &mut *addr });
+        called = true;
+    }
+    if !called {
+        unsafe { da14531_sdk::bindings::default_app_generate_static_random_addr(addr) }
+    }
+}
+extern "C" fn __app_on_svc_changed_cfg_ind(conidx: u8, value: u16) {
+    #[used]
+    #[link_section = "da14531_cb_app_on_svc_changed_cfg_ind"]
+    static SENTINEL: Option<fn(u8, u16)> = None;
+    extern "C" {
+        #[link_name = "__start_da14531_cb_app_on_svc_changed_cfg_ind"]
+        static START: Option<fn(u8, u16)>;
+        #[link_name = "__stop_da14531_cb_app_on_svc_changed_cfg_ind"]
+        static STOP: Option<fn(u8, u16)>;
+    }
+    let entries = unsafe {
+        let start = core::ptr::addr_of!(START);
+        let stop = core::ptr::addr_of!(STOP);
+        core::slice::from_raw_parts(start, stop.offset_from(start) as usize)
+    };
+    let mut called = false;
+    for callback in entries.iter().flatten() {
+        callback(conidx, value);
+        called = true;
+    }
+    if !called {}
+}
+extern "C" fn __app_on_get_peer_features(
+    conidx: u8,
+    param: *const da14531_sdk::ble_stack::host::gap::gapc::task::GapcPeerFeaturesInd,
+) {
+    #[used]
+    #[link_section = "da14531_cb_app_on_get_peer_features"]
+    static SENTINEL: Option<
+        fn(u8, &da14531_sdk::ble_stack::host::gap::gapc::task::GapcPeerFeaturesInd),
+    > = None;
+    extern "C" {
+        #[link_name = "__start_da14531_cb_app_on_get_peer_features"]
+        static START: Option<
+            fn(u8, &da14531_sdk::ble_stack::host::gap::gapc::task::GapcPeerFeaturesInd),
+        >;
+        #[link_name = "__stop_da14531_cb_app_on_get_peer_features"]
+        static STOP: Option<
+            fn(u8, &da14531_sdk::ble_stack::host::gap::gapc::task::GapcPeerFeaturesInd),
+        >;
+    }
+    let entries = unsafe {
+        let start = core::ptr::addr_of!(START);
+        let stop = core::ptr::addr_of!(STOP);
+        core::slice::from_raw_parts(start, stop.offset_from(start) as usize)
+    };
+    let mut called = false;
+    for callback in entries.iter().flatten() {
+        callback(conidx, unsafe { &*param });
+        called = true;
+    }
+    if !called {}
+}
+extern "C" fn __app_on_pairing_request(
+    conidx: u8,
+    param: *const da14531_sdk::ble_stack::host::gap::gapc::task::GapcBondReqInd,
+) {
+    #[used]
+    #[link_section = "da14531_cb_app_on_pairing_request"]
+    static SENTINEL: Option<
+        fn(u8, &da14531_sdk::ble_stack::host::gap::gapc::task::GapcBondReqInd),
+    > = None;
+    extern "C" {
+        #[link_name = "__start_da14531_cb_app_on_pairing_request"]
+        static START: Option<
+            fn(u8, &da14531_sdk::ble_stack::host::gap::gapc::task::GapcBondReqInd),
+        >;
+        #[link_name = "__stop_da14531_cb_app_on_pairing_request"]
+        static STOP: Option<
+            fn(u8, &da14531_sdk::ble_stack::host::gap::gapc::task::GapcBondReqInd),
+        >;
+    }
+    let entries = unsafe {
+        let start = core::ptr::addr_of!(START);
+        let stop = core::ptr::addr_of!(STOP);
+        core::slice::from_raw_parts(start, stop.offset_from(start) as usize)
+    };
+    let mut called = false;
+    for callback in entries.iter().flatten() {
+        callback(conidx, unsafe { &*param });
+        called = true;
+    }
+    if !called {
+        unsafe { da14531_sdk::bindings::default_app_on_pairing_request(conidx, param) }
+    }
+}
+extern "C" fn __app_on_tk_exch(
+    conidx: u8,
+    param: *const da14531_sdk::ble_stack::host::gap::gapc::task::GapcBondReqInd,
+) {
+    #[used]
+    #[link_section = "da14531_cb_app_on_tk_exch"]
+    static SENTINEL: Option<
+        fn(u8, &da14531_sdk::ble_stack::host::gap::gapc::task::GapcBondReqInd),
+    > = None;
+    extern "C" {
+        #[link_name = "__start_da14531_cb_app_on_tk_exch"]
+        static START: Option<
+            fn(u8, &da14531_sdk::ble_stack::host::gap::gapc::task::GapcBondReqInd),
+        >;
+        #[link_name = "__stop_da14531_cb_app_on_tk_exch"]
+        static STOP: Option<
+            fn(u8, &da14531_sdk::ble_stack::host::gap::gapc::task::GapcBondReqInd),
+        >;
+    }
+    let entries = unsafe {
+        let start = core::ptr::addr_of!(START);
+        let stop = core::ptr::addr_of!(STOP);
+        core::slice::from_raw_parts(start, stop.offset_from(start) as usize)
+    };
+    let mut called = false;
+    for callback in entries.iter().flatten() {
+        callback(conidx, unsafe { &*param });
+        called = true;
+    }
+    if !called {
+        unsafe { da14531_sdk::bindings::default_app_on_tk_exch(conidx, param) }
+    }
+}
+extern "C" fn __app_on_irk_exch(
+    param: *const da14531_sdk::ble_stack::host::gap::gapc::task::GapcBondReqInd,
+) {
+    #[used]
+    #[link_section = "da14531_cb_app_on_irk_exch"]
+    static SENTINEL: Option<
+        fn(&da14531_sdk::ble_stack::host::gap::gapc::task::GapcBondReqInd),
+    > = None;
+    extern "C" {
+        #[link_name = "__start_da14531_cb_app_on_irk_exch"]
+        static START: Option<
+            fn(&da14531_sdk::ble_stack::host::gap::gapc::task::GapcBondReqInd),
+        >;
+        #[link_name = "__stop_da14531_cb_app_on_irk_exch"]
+        static STOP: Option<
+            fn(&da14531_sdk::ble_stack::host::gap::gapc::task::GapcBondReqInd),
+        >;
+    }
+    let entries = unsafe {
+        let start = core::ptr::addr_of!(START);
+        let stop = core::ptr::addr_of!(STOP);
+        core::slice::from_raw_parts(start, stop.offset_from(start) as usize)
+    };
+    let mut called = false;
+    for callback in entries.iter().flatten() {
+        callback(unsafe { &*param });
+        called = true;
+    }
+    if !called {}
+}
+extern "C" fn __app_on_csrk_exch(
+    conidx: u8,
+    param: *const da14531_sdk::ble_stack::host::gap::gapc::task::GapcBondReqInd,
+) {
+    #[used]
+    #[link_section = "da14531_cb_app_on_csrk_exch"]
+    static SENTINEL: Option<
+        fn(u8, &da14531_sdk::ble_stack::host::gap::gapc::task::GapcBondReqInd),
+    > = None;
+    extern "C" {
+        #[link_name = "__start_da14531_cb_app_on_csrk_exch"]
+        static START: Option<
+            fn(u8, &da14531_sdk::ble_stack::host::gap::gapc::task::GapcBondReqInd),
+        >;
+        #[link_name = "__stop_da14531_cb_app_on_csrk_exch"]
+        static STOP: Option<
+            fn(u8, &da14531_sdk::ble_stack::host::gap::gapc::task::GapcBondReqInd),
+        >;
+    }
+    let entries = unsafe {
+        let start = core::ptr::addr_of!(START);
+        let stop = core::ptr::addr_of!(STOP);
+        core::slice::from_raw_parts(start, stop.offset_from(start) as usize)
+    };
+    let mut called = false;
+    for callback in entries.iter().flatten() {
+        callback(conidx, unsafe { &*param });
+        called = true;
+    }
+    if !called {
+        unsafe { da14531_sdk::bindings::default_app_on_csrk_exch(conidx, param) }
+    }
+}
+extern "C" fn __app_on_ltk_exch(
+    conidx: u8,
+    param: *const da14531_sdk::ble_stack::host::gap::gapc::task::GapcBondReqInd,
+) {
+    #[used]
+    #[link_section = "da14531_cb_app_on_ltk_exch"]
+    static SENTINEL: Option<
+        fn(u8, &da14531_sdk::ble_stack::host::gap::gapc::task::GapcBondReqInd),
+    > = None;
+    extern "C" {
+        #[link_name = "__start_da14531_cb_app_on_ltk_exch"]
+        static START: Option<
+            fn(u8, &da14531_sdk::ble_stack::host::gap::gapc::task::GapcBondReqInd),
+        >;
+        #[link_name = "__stop_da14531_cb_app_on_ltk_exch"]
+        static STOP: Option<
+            fn(u8, &da14531_sdk::ble_stack::host::gap::gapc::task::GapcBondReqInd),
+        >;
+    }
+    let entries = unsafe {
+        let start = core::ptr::addr_of!(START);
+        let stop = core::ptr::addr_of!(STOP);
+        core::slice::from_raw_parts(start, stop.offset_from(start) as usize)
+    };
+    let mut called = false;
+    for callback in entries.iter().flatten() {
+        callback(conidx, unsafe { &*param });
+        called = true;
+    }
+    if !called {
+        unsafe { da14531_sdk::bindings::default_app_on_ltk_exch(conidx, param) }
+    }
+}
+extern "C" fn __app_on_pairing_succeeded(conidx: u8) {
+    #[used]
+    #[link_section = "da14531_cb_app_on_pairing_succeeded"]
+    static SENTINEL: Option<fn(u8)> = None;
+    extern "C" {
+        #[link_name = "__start_da14531_cb_app_on_pairing_succeeded"]
+        static START: Option<fn(u8)>;
+        #[link_name = "__stop_da14531_cb_app_on_pairing_succeeded"]
+        static STOP: Option<fn(u8)>;
+    }
+    let entries = unsafe {
+        let start = core::ptr::addr_of!(START);
+        let stop = core::ptr::addr_of!(STOP);
+        core::slice::from_raw_parts(start, stop.offset_from(start) as usize)
+    };
+    let mut called = false;
+    for callback in entries.iter().flatten() {
+        callback(conidx);
+        called = true;
+    }
+    if !called {}
+}
+extern "C" fn __app_on_encrypt_ind(conidx: u8, auth: u8) {
+    #[used]
+    #[link_section = "da14531_cb_app_on_encrypt_ind"]
+    static SENTINEL: Option<fn(u8, u8)> = None;
+    extern "C" {
+        #[link_name = "__start_da14531_cb_app_on_encrypt_ind"]
+        static START: Option<fn(u8, u8)>;
+        #[link_name = "__stop_da14531_cb_app_on_encrypt_ind"]
+        static STOP: Option<fn(u8, u8)>;
+    }
+    let entries = unsafe {
+        let start = core::ptr::addr_of!(START);
+        let stop = core::ptr::addr_of!(STOP);
+        core::slice::from_raw_parts(start, stop.offset_from(start) as usize)
+    };
+    let mut called = false;
+    for callback in entries.iter().flatten() {
+        callback(conidx, auth);
+        called = true;
+    }
+    if !called {}
+}
+extern "C" fn __app_on_encrypt_req_ind(
+    conidx: u8,
+    param: *const da14531_sdk::ble_stack::host::gap::gapc::task::GapcEncryptReqInd,
+) {
+    #[used]
+    #[link_section = "da14531_cb_app_on_encrypt_req_ind"]
+    static SENTINEL: Option<
+        fn(u8, &da14531_sdk::ble_stack::host::gap::gapc::task::GapcEncryptReqInd),
+    > = None;
+    extern "C" {
+        #[link_name = "__start_da14531_cb_app_on_encrypt_req_ind"]
+        static START: Option<
+            fn(u8, &da14531_sdk::ble_stack::host::gap::gapc::task::GapcEncryptReqInd),
+        >;
+        #[link_name = "__stop_da14531_cb_app_on_encrypt_req_ind"]
+        static STOP: Option<
+            fn(u8, &da14531_sdk::ble_stack::host::gap::gapc::task::GapcEncryptReqInd),
+        >;
+    }
+    let entries = unsafe {
+        let start = core::ptr::addr_of!(START);
+        let stop = core::ptr::addr_of!(STOP);
+        core::slice::from_raw_parts(start, stop.offset_from(start) as usize)
+    };
+    let mut called = false;
+    for callback in entries.iter().flatten() {
+        callback(conidx, unsafe { &*param });
+        called = true;
+    }
+    if !called {
+        unsafe { da14531_sdk::bindings::default_app_on_encrypt_req_ind(conidx, param) }
+    }
+}
+extern "C" fn __app_on_security_req_ind(conidx: u8) {
+    #[used]
+    #[link_section = "da14531_cb_app_on_security_req_ind"]
+    static SENTINEL: Option<fn(u8)> = None;
+    extern "C" {
+        #[link_name = "__start_da14531_cb_app_on_security_req_ind"]
+        static START: Option<fn(u8)>;
+        #[link_name = "__stop_da14531_cb_app_on_security_req_ind"]
+        static STOP: Option<fn(u8)>;
+    }
+    let entries = unsafe {
+        let start = core::ptr::addr_of!(START);
+        let stop = core::ptr::addr_of!(STOP);
+        core::slice::from_raw_parts(start, stop.offset_from(start) as usize)
+    };
+    let mut called = false;
+    for callback in entries.iter().flatten() {
+        callback(conidx);
+        called = true;
+    }
+    if !called {}
+}
+extern "C" fn __app_on_addr_solved_ind(
+    conidx: u8,
+    param: *const da14531_sdk::ble_stack::host::gap::gapm::task::GapmAddrSolvedInd,
+) {
+    #[used]
+    #[link_section = "da14531_cb_app_on_addr_solved_ind"]
+    static SENTINEL: Option<
+        fn(u8, &da14531_sdk::ble_stack::host::gap::gapm::task::GapmAddrSolvedInd),
+    > = None;
+    extern "C" {
+        #[link_name = "__start_da14531_cb_app_on_addr_solved_ind"]
+        static START: Option<
+            fn(u8, &da14531_sdk::ble_stack::host::gap::gapm::task::GapmAddrSolvedInd),
+        >;
+        #[link_name = "__stop_da14531_cb_app_on_addr_solved_ind"]
+        static STOP: Option<
+            fn(u8, &da14531_sdk::ble_stack::host::gap::gapm::task::GapmAddrSolvedInd),
+        >;
+    }
+    let entries = unsafe {
+        let start = core::ptr::addr_of!(START);
+        let stop = core::ptr::addr_of!(STOP);
+        core::slice::from_raw_parts(start, stop.offset_from(start) as usize)
+    };
+    let mut called = false;
+    for callback in entries.iter().flatten() {
+        callback(conidx, unsafe { &*param });
+        called = true;
+    }
+    if !called {}
+}
+extern "C" fn __app_on_addr_resolve_failed(conidx: u8) {
+    #[used]
+    #[link_section = "da14531_cb_app_on_addr_resolve_failed"]
+    static SENTINEL: Option<fn(u8)> = None;
+    extern "C" {
+        #[link_name = "__start_da14531_cb_app_on_addr_resolve_failed"]
+        static START: Option<fn(u8)>;
+        #[link_name = "__stop_da14531_cb_app_on_addr_resolve_failed"]
+        static STOP: Option<fn(u8)>;
+    }
+    let entries = unsafe {
+        let start = core::ptr::addr_of!(START);
+        let stop = core::ptr::addr_of!(STOP);
+        core::slice::from_raw_parts(start, stop.offset_from(start) as usize)
+    };
+    let mut called = false;
+    for callback in entries.iter().flatten() {
+        callback(conidx);
+        called = true;
+    }
+    if !called {}
+}
+extern "C" fn __app_on_ral_cmp_evt(
+    param: *const da14531_sdk::ble_stack::host::gap::gapm::task::GapmCmpEvt,
+) {
+    #[used]
+    #[link_section = "da14531_cb_app_on_ral_cmp_evt"]
+    static SENTINEL: Option<
+        fn(&da14531_sdk::ble_stack::host::gap::gapm::task::GapmCmpEvt),
+    > = None;
+    extern "C" {
+        #[link_name = "__start_da14531_cb_app_on_ral_cmp_evt"]
+        static START: Option<
+            fn(&da14531_sdk::ble_stack::host::gap::gapm::task::GapmCmpEvt),
+        >;
+        #[link_name = "__stop_da14531_cb_app_on_ral_cmp_evt"]
+        static STOP: Option<
+            fn(&da14531_sdk::ble_stack::host::gap::gapm::task::GapmCmpEvt),
+        >;
+    }
+    let entries = unsafe {
+        let start = core::ptr::addr_of!(START);
+        let stop = core::ptr::addr_of!(STOP);
+        core::slice::from_raw_parts(start, stop.offset_from(start) as usize)
+    };
+    let mut called = false;
+    for callback in entries.iter().flatten() {
+        callback(unsafe { &*param });
+        called = true;
+    }
+    if !called {}
+}
+extern "C" fn __app_on_ral_size_ind(ral_size: u8) {
+    #[used]
+    #[link_section = "da14531_cb_app_on_ral_size_ind"]
+    static SENTINEL: Option<fn(u8)> = None;
+    extern "C" {
+        #[link_name = "__start_da14531_cb_app_on_ral_size_ind"]
+        static START: Option<fn(u8)>;
+        #[link_name = "__stop_da14531_cb_app_on_ral_size_ind"]
+        static STOP: Option<fn(u8)>;
+    }
+    let entries = unsafe {
+        let start = core::ptr::addr_of!(START);
+        let stop = core::ptr::addr_of!(STOP);
+        core::slice::from_raw_parts(start, stop.offset_from(start) as usize)
+    };
+    let mut called = false;
+    for callback in entries.iter().flatten() {
+        callback(ral_size);
+        called = true;
+    }
+    if !called {}
+}
+extern "C" fn __app_on_ral_addr_ind(list_size: u8, ral_list: *mut core::ffi::c_void) {
+    #[used]
+    #[link_section = "da14531_cb_app_on_ral_addr_ind"]
+    static SENTINEL: Option<fn(&[u8])> = None;
+    extern "C" {
+        #[link_name = "__start_da14531_cb_app_on_ral_addr_ind"]
+        static START: Option<fn(&[u8])>;
+        #[link_name = "__stop_da14531_cb_app_on_ral_addr_ind"]
+        static STOP: Option<fn(&[u8])>;
+    }
+    let entries = unsafe {
+        let start = core::ptr::addr_of!(START);
+        let stop = core::ptr::addr_of!(STOP);
+        core::slice::from_raw_parts(start, stop.offset_from(start) as usize)
+    };
+    let mut called = false;
+    for callback in entries.iter().flatten() {
+        callback(
+            if ral_list.is_null() {
+                &[]
+            } else {
+                unsafe {
+                    core::slice::from_raw_parts(
+                        ral_list as *const u8,
+                        list_size as usize,
+                    )
+                }
+            },
+        );
+        called = true;
+    }
+    if !called {}
+}
+#[export_name = "user_app_callbacks"]
+pub(crate) static USER_APP_CALLBACKS: da14531_sdk::app_modules::AppCallbacks = da14531_sdk::app_modules::AppCallbacks {
+    app_on_connection: Some(__app_on_connection),
+    app_on_disconnect: Some(__app_on_disconnect),
+    app_on_update_params_rejected: Some(__app_on_update_params_rejected),
+    app_on_update_params_complete: Some(__app_on_update_params_complete),
+    app_on_set_dev_config_complete: Some(__app_on_set_dev_config_complete),
+    app_on_adv_nonconn_complete: Some(__app_on_adv_nonconn_complete),
+    app_on_adv_undirect_complete: Some(__app_on_adv_undirect_complete),
+    app_on_adv_direct_complete: Some(__app_on_adv_direct_complete),
+    app_on_db_init_complete: Some(__app_on_db_init_complete),
+    app_on_scanning_completed: Some(__app_on_scanning_completed),
+    app_on_adv_report_ind: Some(__app_on_adv_report_ind),
+    app_on_connect_failed: Some(__app_on_connect_failed),
+    app_on_get_dev_name: Some(__app_on_get_dev_name),
+    app_on_get_dev_appearance: Some(__app_on_get_dev_appearance),
+    app_on_get_dev_slv_pref_params: Some(__app_on_get_dev_slv_pref_params),
+    app_on_set_dev_info: Some(__app_on_set_dev_info),
+    app_on_data_length_change: Some(__app_on_data_length_change),
+    app_on_update_params_request: Some(__app_on_update_params_request),
+    app_on_generate_static_random_addr: Some(__app_on_generate_static_random_addr),
+    app_on_svc_changed_cfg_ind: Some(__app_on_svc_changed_cfg_ind),
+    app_on_get_peer_features: Some(__app_on_get_peer_features),
+    app_on_pairing_request: Some(__app_on_pairing_request),
+    app_on_tk_exch: Some(__app_on_tk_exch),
+    app_on_irk_exch: Some(__app_on_irk_exch),
+    app_on_csrk_exch: Some(__app_on_csrk_exch),
+    app_on_ltk_exch: Some(__app_on_ltk_exch),
+    app_on_pairing_succeeded: Some(__app_on_pairing_succeeded),
+    app_on_encrypt_ind: Some(__app_on_encrypt_ind),
+    app_on_encrypt_req_ind: Some(__app_on_encrypt_req_ind),
+    app_on_security_req_ind: Some(__app_on_security_req_ind),
+    app_on_addr_solved_ind: Some(__app_on_addr_solved_ind),
+    app_on_addr_resolve_failed: Some(__app_on_addr_resolve_failed),
+    app_on_ral_cmp_evt: Some(__app_on_ral_cmp_evt),
+    app_on_ral_size_ind: Some(__app_on_ral_size_ind),
+    app_on_ral_addr_ind: Some(__app_on_ral_addr_ind),
+};
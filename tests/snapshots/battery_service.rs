@@ -0,0 +1,150 @@
+#[doc(hidden)]
+#[no_mangle]
+static __DA14531_SDK_APP_BASS: () = ();
+mod app_bass_task {
+    use da14531_sdk::{
+        ble_stack::profiles::bas::bass::task::{
+            BASS_BATT_LEVEL_NTF_CFG_IND, BASS_BATT_LEVEL_UPD_RSP, BASS_ENABLE_RSP,
+        },
+        platform::core_modules::ke::{
+            msg::{KeMsgId, KE_MSG_CONSUMED},
+            task::KeTaskId,
+        },
+    };
+    extern "C" fn bass_batt_level_ntf_cfg_ind_handler(
+        _msg_id: KeMsgId,
+        param: *const core::ffi::c_void,
+        _dest_id: KeTaskId,
+        _src_id: KeTaskId,
+    ) -> i32 {
+        let param = param
+            as *const da14531_sdk::ble_stack::profiles::bas::bass::task::BassBattLevelNtfCfgInd;
+        let param = unsafe { &*param };
+        crate::bat::on_ntf_cfg(
+            param.conidx,
+            param.ntf_cfg != da14531_sdk::ble_stack::profiles::PRF_CLI_STOP_NTFIND as u8,
+        );
+        KE_MSG_CONSUMED as i32
+    }
+    extern "C" fn bass_consume_handler(
+        _msg_id: KeMsgId,
+        _param: *const core::ffi::c_void,
+        _dest_id: KeTaskId,
+        _src_id: KeTaskId,
+    ) -> i32 {
+        KE_MSG_CONSUMED as i32
+    }
+    static APP_BASS_PROCESS_HANDLERS: [da14531_sdk::platform::core_modules::ke::msg::KeMsgHandler; 3usize] = [
+        da14531_sdk::platform::core_modules::ke::msg::KeMsgHandler {
+            id: BASS_BATT_LEVEL_NTF_CFG_IND as u16,
+            func: Some(bass_batt_level_ntf_cfg_ind_handler),
+        },
+        da14531_sdk::platform::core_modules::ke::msg::KeMsgHandler {
+            id: BASS_BATT_LEVEL_UPD_RSP as u16,
+            func: Some(bass_consume_handler),
+        },
+        da14531_sdk::platform::core_modules::ke::msg::KeMsgHandler {
+            id: BASS_ENABLE_RSP as u16,
+            func: Some(bass_consume_handler),
+        },
+    ];
+    #[no_mangle]
+    extern "C" fn app_bass_process_handler(
+        msg_id: da14531_sdk::platform::core_modules::ke::msg::KeMsgId,
+        param: *const core::ffi::c_void,
+        dest_id: da14531_sdk::platform::core_modules::ke::task::KeTaskId,
+        src_id: da14531_sdk::platform::core_modules::ke::task::KeTaskId,
+        msg_ret: *mut da14531_sdk::platform::core_modules::ke::msg::KeMsgStatusTag,
+    ) -> da14531_sdk::app_modules::ProcessEventResponse {
+        return unsafe {
+            da14531_sdk::bindings::app_std_process_event(
+                msg_id,
+                param,
+                src_id,
+                dest_id,
+                msg_ret,
+                APP_BASS_PROCESS_HANDLERS.as_ptr() as *mut _,
+                APP_BASS_PROCESS_HANDLERS.len() as i32,
+            )
+        };
+    }
+}
+pub(crate) mod app_bass {
+    use da14531_sdk::{
+        ble_stack::profiles::{
+            bas::bass::{
+                task::{KeMsgBassBattLevelUpdReq, KeMsgBassEnableReq},
+                BassDbCfg,
+            },
+            prf_get_task_from_id, PRF_CLI_STOP_NTFIND,
+        },
+        platform::core_modules::rwip::{TASK_APP, TASK_ID_BASS},
+    };
+    #[no_mangle]
+    extern "C" fn app_bass_init() {}
+    #[no_mangle]
+    extern "C" fn app_bass_create_db() {
+        const SIZE: u16 = core::mem::size_of::<BassDbCfg>() as u16;
+        let mut msg = da14531_sdk::ble_stack::host::gap::gapm::task::KeMsgDynGapmProfileTaskAdd::<
+            SIZE,
+        >::new(
+            da14531_sdk::platform::core_modules::rwip::TASK_APP as u16,
+            da14531_sdk::platform::core_modules::rwip::TASK_GAPM as u16,
+        );
+        msg
+            .fields()
+            .operation = da14531_sdk::ble_stack::host::gap::gapm::task::GAPM_PROFILE_TASK_ADD
+            as u8;
+        msg
+            .fields()
+            .sec_lvl = da14531_sdk::app_modules::get_user_prf_srv_perm(TASK_ID_BASS)
+            as u8;
+        msg.fields().prf_task_id = TASK_ID_BASS as u16;
+        msg
+            .fields()
+            .app_task = da14531_sdk::platform::core_modules::rwip::TASK_APP as u16;
+        msg.fields().start_hdl = 0u16;
+        let db_cfg_ptr = &mut msg.fields().param as *mut _ as *mut BassDbCfg;
+        unsafe { core::ptr::write_bytes(db_cfg_ptr, 0, 1) };
+        let db_cfg = unsafe { db_cfg_ptr.as_mut().unwrap() };
+        db_cfg.bas_nb = 1u8;
+        db_cfg
+            .features[0u8
+            as usize] = da14531_sdk::ble_stack::profiles::bas::bass::BAS_BATT_LVL_NTF_SUP
+            as u8;
+        msg.send();
+    }
+    /// Enables the battery service through `app_prf_enable`, with notifications stopped as
+    /// for a peer that did not subscribe yet.
+    #[export_name = "app_bass_enable"]
+    extern "C" fn app_bass_enable_unsubscribed(conidx: u8) {
+        app_bass_enable(conidx, PRF_CLI_STOP_NTFIND as u8);
+    }
+    /// Enables the battery service for a new connection, reporting the current levels.
+    ///
+    /// `ntf_cfg` is the client characteristic configuration stored for a bonded peer,
+    /// call this instead of `app_prf_enable` to restore its subscription.
+    pub fn app_bass_enable(conidx: u8, ntf_cfg: u8) {
+        let mut msg = KeMsgBassEnableReq::new(
+            TASK_APP as u16,
+            prf_get_task_from_id(TASK_ID_BASS as u16),
+        );
+        msg.fields().conidx = conidx;
+        msg.fields().ntf_cfg = ntf_cfg;
+        msg.fields().old_batt_lvl[0u8 as usize] = crate::bat::main();
+        msg.send();
+    }
+    /// Reads every battery level source and pushes the values to the `bass` task, which
+    /// serves them to read requests and notifies subscribed peers.
+    pub fn app_bass_batt_level_update() {
+        {
+            let mut msg = KeMsgBassBattLevelUpdReq::new(
+                TASK_APP as u16,
+                prf_get_task_from_id(TASK_ID_BASS as u16),
+            );
+            msg.fields().bas_instance = 0u8;
+            msg.fields().batt_level = crate::bat::main();
+            msg.send();
+        }
+    }
+}
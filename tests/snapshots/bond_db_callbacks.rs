@@ -0,0 +1,103 @@
+#[doc(hidden)]
+#[no_mangle]
+static __DA14531_SDK_USER_APP_BOND_DB_CALLBACKS: () = ();
+extern "C" fn __app_bdb_get_size() -> u8 {
+    {
+        const APP_BDB_GET_SIZE: fn() -> u8 = || 8;
+        APP_BDB_GET_SIZE()
+    }
+}
+extern "C" fn __app_bdb_remove_entry(
+    search_type: da14531_sdk::app_modules::BdbSearchByType,
+    remove_type: da14531_sdk::app_modules::BdbRemoveType,
+    search_param: *mut core::ffi::c_void,
+    search_param_length: u8,
+) {
+    {
+        const APP_BDB_REMOVE_ENTRY: fn(
+            da14531_sdk::app_modules::BdbSearchByType,
+            da14531_sdk::app_modules::BdbRemoveType,
+            &[u8],
+        ) = |_, _, param| crate::bond_db::remove(param);
+        APP_BDB_REMOVE_ENTRY(
+            search_type,
+            remove_type,
+            if search_param.is_null() {
+                &[]
+            } else {
+                unsafe {
+                    core::slice::from_raw_parts(
+                        search_param as *const u8,
+                        search_param_length as usize,
+                    )
+                }
+            },
+        )
+    }
+}
+extern "C" fn __app_bdb_search_entry(
+    search_type: da14531_sdk::app_modules::BdbSearchByType,
+    search_param: *mut core::ffi::c_void,
+    search_param_length: u8,
+) -> *const da14531_sdk::app_modules::AppSecBondDataEnvTag {
+    match {
+        {
+            const APP_BDB_SEARCH_ENTRY: fn(
+                da14531_sdk::app_modules::BdbSearchByType,
+                &[u8],
+            ) -> Option<&'static da14531_sdk::app_modules::AppSecBondDataEnvTag> = crate::bond_db::search;
+            APP_BDB_SEARCH_ENTRY(
+                search_type,
+                if search_param.is_null() {
+                    &[]
+                } else {
+                    unsafe {
+                        core::slice::from_raw_parts(
+                            search_param as *const u8,
+                            search_param_length as usize,
+                        )
+                    }
+                },
+            )
+        }
+    } {
+        Some(referenced) => referenced as *const _,
+        None => core::ptr::null(),
+    }
+}
+extern "C" fn __app_bdb_get_stored_irks(
+    valid_irk_array: *mut da14531_sdk::ble_stack::host::gap::GapSecKey,
+) -> u8 {
+    {
+        const APP_BDB_GET_STORED_IRKS: fn(
+            &mut [da14531_sdk::ble_stack::host::gap::GapSecKey],
+        ) -> u8 = crate::bond_db::get_irks;
+        APP_BDB_GET_STORED_IRKS(
+            if valid_irk_array.is_null() {
+                &mut []
+            } else {
+                unsafe {
+                    core::slice::from_raw_parts_mut(
+                        valid_irk_array,
+                        da14531_sdk::app_modules::APP_BOND_DB_MAX_BONDED_PEERS as usize,
+                    )
+                }
+            },
+        )
+    }
+}
+#[export_name = "user_app_bond_db_callbacks"]
+pub(crate) static USER_APP_BOND_DB_CALLBACKS: da14531_sdk::app_modules::AppBondDbCallbacks = da14531_sdk::app_modules::AppBondDbCallbacks {
+    app_bdb_init: Some(da14531_sdk::bindings::default_app_bdb_init),
+    app_bdb_get_size: Some(__app_bdb_get_size),
+    app_bdb_add_entry: Some(da14531_sdk::bindings::default_app_bdb_add_entry),
+    app_bdb_remove_entry: Some(__app_bdb_remove_entry),
+    app_bdb_search_entry: Some(__app_bdb_search_entry),
+    app_bdb_get_number_of_stored_irks: Some(
+        da14531_sdk::bindings::default_app_bdb_get_number_of_stored_irks,
+    ),
+    app_bdb_get_stored_irks: Some(__app_bdb_get_stored_irks),
+    app_bdb_get_device_info_from_slot: Some(
+        da14531_sdk::bindings::default_app_bdb_get_device_info_from_slot,
+    ),
+};
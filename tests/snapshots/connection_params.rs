@@ -0,0 +1,27 @@
+#[doc(hidden)]
+#[no_mangle]
+static __DA14531_SDK_USER_CONNECTION_PARAM_CONF: () = ();
+#[export_name = "user_connection_param_conf"]
+pub(crate) static USER_CONNECTION_PARAM_CONF: da14531_sdk::app_modules::ConnectionParamConfiguration = da14531_sdk::app_modules::ConnectionParamConfiguration {
+    intv_min: 12u16,
+    intv_max: 24u16,
+    latency: 4u16,
+    time_out: 200u16,
+    ce_len_min: 0u16,
+    ce_len_max: 4u16,
+};
+/// Handlers following the configured connection parameters.
+pub(crate) mod connection_params {
+    /// Answers connection parameter update requests of the peer, for use as
+    /// `app_on_update_params_request`.
+    pub fn on_update_params_request(
+        param: &da14531_sdk::ble_stack::host::gap::gapc::task::GapcParamUpdateReqInd,
+        cfm: &mut da14531_sdk::ble_stack::host::gap::gapc::task::GapcParamUpdateCfm,
+    ) {
+        cfm
+            .accept = param.intv_max >= 12u16 && param.intv_min <= 24u16
+            && param.latency <= 4u16;
+        cfm.ce_len_min = 0u16;
+        cfm.ce_len_max = 4u16;
+    }
+}
@@ -0,0 +1,258 @@
+#[doc(hidden)]
+#[no_mangle]
+static __DA14531_SDK_APP_CUSTS1: () = ();
+/// Attribute database of the custom service.
+pub(crate) static CUSTS1_ATT_DB: [crate::host_sim::AttDesc; 12usize] = [
+    crate::host_sim::AttDesc {
+        uuid: crate::host_sim::ble_stack::host::att::ATT_DECL_PRIMARY_SERVICE,
+        perm: 1u32,
+        max_length: crate::host_sim::ble_stack::host::att::ATT_UUID_16_LEN as u16,
+        length: crate::host_sim::ble_stack::host::att::ATT_UUID_16_LEN as u16,
+        value: &[0u8, 254u8],
+    },
+    crate::host_sim::AttDesc {
+        uuid: crate::host_sim::ble_stack::host::att::ATT_DECL_CHARACTERISTIC,
+        perm: 1u32,
+        max_length: 0,
+        length: 0,
+        value: &[],
+    },
+    ///Permissions: Permissions { read: Disabled, write: Enabled, indication: Disabled, notification: Disabled, extended_properties_present: false, broadcast_permission: false, encryption_key_length_16_bytes: false, write_command_accepted: false, write_signed_accepted: false, write_request_accepted: true, uuid_length: L16 }
+    ///Permissions: 0b000000000000100000000000001000
+    crate::host_sim::AttDesc {
+        uuid: 65025u16,
+        perm: 131080u32,
+        max_length: (1 << 15) | 1u16,
+        length: 0,
+        value: &[],
+    },
+    crate::host_sim::AttDesc {
+        uuid: crate::host_sim::ble_stack::host::att::ATT_DESC_CHAR_USER_DESCRIPTION,
+        perm: 1u32,
+        max_length: 4u16,
+        length: 4u16,
+        value: &[77u8, 111u8, 100u8, 101u8],
+    },
+    crate::host_sim::AttDesc {
+        uuid: crate::host_sim::ble_stack::host::att::ATT_DECL_CHARACTERISTIC,
+        perm: 1u32,
+        max_length: 0,
+        length: 0,
+        value: &[],
+    },
+    ///Permissions: Permissions { read: Auth, write: Disabled, indication: Disabled, notification: Enabled, extended_properties_present: false, broadcast_permission: false, encryption_key_length_16_bytes: false, write_command_accepted: false, write_signed_accepted: false, write_request_accepted: false, uuid_length: L16 }
+    ///Permissions: 0b000000000000000000001000000011
+    crate::host_sim::AttDesc {
+        uuid: 65026u16,
+        perm: 515u32,
+        max_length: (1 << 15) | crate::ble::STATUS_LEN,
+        length: 0,
+        value: &[],
+    },
+    crate::host_sim::AttDesc {
+        uuid: crate::host_sim::ble_stack::host::att::ATT_DECL_CHARACTERISTIC,
+        perm: 1u32,
+        max_length: 0,
+        length: 0,
+        value: &[],
+    },
+    ///Permissions: Permissions { read: Disabled, write: Secure, indication: Unauth, notification: Disabled, extended_properties_present: false, broadcast_permission: false, encryption_key_length_16_bytes: false, write_command_accepted: true, write_signed_accepted: false, write_request_accepted: false, uuid_length: L16 }
+    ///Permissions: 0b000000000000001000000010100000
+    crate::host_sim::AttDesc {
+        uuid: 65027u16,
+        perm: 32928u32,
+        max_length: (1 << 15) | 20u16,
+        length: 0,
+        value: &[],
+    },
+    crate::host_sim::AttDesc {
+        uuid: crate::host_sim::ble_stack::host::att::ATT_DESC_CLIENT_CHAR_CFG,
+        perm: 131081u32,
+        max_length: 0,
+        length: 0,
+        value: &[],
+    },
+    crate::host_sim::AttDesc {
+        uuid: crate::host_sim::ble_stack::host::att::ATT_DECL_PRIMARY_SERVICE,
+        perm: 1u32,
+        max_length: crate::host_sim::ble_stack::host::att::ATT_UUID_16_LEN as u16,
+        length: crate::host_sim::ble_stack::host::att::ATT_UUID_16_LEN as u16,
+        value: &[16u8, 254u8],
+    },
+    crate::host_sim::AttDesc {
+        uuid: crate::host_sim::ble_stack::host::att::ATT_DECL_CHARACTERISTIC,
+        perm: 1u32,
+        max_length: 0,
+        length: 0,
+        value: &[],
+    },
+    ///Permissions: Permissions { read: Unauth, write: Disabled, indication: Disabled, notification: Disabled, extended_properties_present: false, broadcast_permission: false, encryption_key_length_16_bytes: false, write_command_accepted: false, write_signed_accepted: false, write_request_accepted: false, uuid_length: L16 }
+    ///Permissions: 0b000000000000000000000000000010
+    crate::host_sim::AttDesc {
+        uuid: 65041u16,
+        perm: 2u32,
+        max_length: (1 << 15) | 64u16,
+        length: 0,
+        value: &[],
+    },
+];
+/// Handle of the first attribute, `0` if the stack assigns it.
+pub(crate) const CUSTS1_START_HANDLE: u16 = 0u16;
+/// Hash of the attribute layout. Store it alongside the bonding data and send a
+/// Service Changed indication to bonded peers when it differs after an update.
+pub(crate) const CUSTS1_ATT_DB_HASH: u32 = {
+    let lengths = [crate::ble::STATUS_LEN as u16];
+    let mut hash = 4118778864u32;
+    let mut i = 0;
+    while i < lengths.len() {
+        let bytes = lengths[i].to_le_bytes();
+        hash = (hash ^ bytes[0] as u32).wrapping_mul(16777619u32);
+        hash = (hash ^ bytes[1] as u32).wrapping_mul(16777619u32);
+        i += 1;
+    }
+    hash
+};
+pub(crate) const SERVICE_CONTROL_UUID: u16 = 65024u16;
+pub(crate) const SERVICE_LOG_UUID: u16 = 65040u16;
+/// Complete list of the service UUIDs marked `advertise: true`, as an AD structure
+/// ready to be included in the advertising data.
+pub(crate) const ADV_SERVICE_UUIDS: [u8; 4usize] = [3u8, 3u8, 0u8, 254u8];
+pub(crate) mod char_idx_map {
+    pub const CHAR_MODE_HANDLE: u16 = 2u16;
+    pub const CHAR_STATUS_HANDLE: u16 = 5u16;
+    pub const CHAR_COMMAND_HANDLE: u16 = 7u16;
+    pub const CHAR_ENTRIES_HANDLE: u16 = 11u16;
+}
+/// Handles the messages that are not handled by the SDK internal mechanisms.
+///
+/// # Arguments
+/// * `msg_id` - Id of the message received.
+/// * `param` - Pointer to the parameters of the message.
+/// * `dest_id` - ID of the receiving task instance.
+/// * `src_id` - ID of the sending task instance.
+extern "C" fn user_catch_rest_hndl(
+    msg_id: crate::host_sim::platform::core_modules::ke::msg::KeMsgId,
+    param: *const core::ffi::c_void,
+    dest_id: crate::host_sim::platform::core_modules::ke::task::KeTaskId,
+    src_id: crate::host_sim::platform::core_modules::ke::task::KeTaskId,
+) {
+    match msg_id as u32 {
+        crate::host_sim::ble_stack::profiles::custom::custs::custs1::task::CUSTS1_VAL_WRITE_IND => {
+            let param = param
+                as *const crate::host_sim::ble_stack::profiles::custom::custs::custs1::task::Custs1ValWriteInd;
+            let param = unsafe { &*param };
+            match param.handle {
+                2u16 => crate::ble::on_mode(param),
+                7u16 => crate::ble::on_command(param),
+                _ => {}
+            }
+        }
+        crate::host_sim::ble_stack::profiles::custom::custs::custs1::task::CUSTS1_ATT_INFO_REQ => {
+            let param = param
+                as *const crate::host_sim::ble_stack::profiles::custom::custs::custs1::task::Custs1AttInfoReq;
+            let param = unsafe { &*param };
+            let att_idx = param.att_idx;
+            match att_idx {
+                _ => {
+                    let mut response = crate::host_sim::ble_stack::profiles::custom::custs::custs1::task::KeMsgCusts1AttInfoRsp::new(
+                        src_id,
+                        dest_id,
+                    );
+                    let conidx = crate::host_sim::app_modules::app_env_get_conidx(
+                        param.conidx,
+                    );
+                    response.fields().conidx = conidx;
+                    response.fields().att_idx = param.att_idx;
+                    response.fields().length = 0;
+                    response
+                        .fields()
+                        .status = crate::host_sim::ble_stack::rwble_hl::error::HlErr::ATT_ERR_WRITE_NOT_PERMITTED
+                        as u8;
+                    response.send();
+                }
+            }
+        }
+        crate::host_sim::ble_stack::profiles::custom::custs::custs1::task::CUSTS1_VALUE_REQ_IND => {
+            let param = param
+                as *const crate::host_sim::ble_stack::profiles::custom::custs::custs1::task::Custs1ValueReqInd;
+            let param = unsafe { &*param };
+            let att_idx = param.att_idx;
+            match att_idx {
+                5u16 => crate::ble::on_status(param),
+                11u16 => crate::log::on_read(param),
+                _ => {
+                    let mut response = crate::host_sim::ble_stack::profiles::custom::custs::custs1::task::KeMsgCusts1ValueReqRsp::new(
+                        dest_id,
+                        src_id,
+                    );
+                    response
+                        .fields()
+                        .conidx = crate::host_sim::app_modules::app_env_get_conidx(
+                        param.conidx,
+                    );
+                    response.fields().att_idx = param.att_idx;
+                    response.fields().length = 0;
+                    response
+                        .fields()
+                        .status = crate::host_sim::ble_stack::rwble_hl::error::HlErr::ATT_ERR_APP_ERROR
+                        as u8;
+                    response.send();
+                }
+            }
+        }
+        _ => {}
+    }
+}
+/// Requests of a simulated peer, see `host_sim!`.
+///
+/// Each request returns the messages sent since the last `take_sent`, which include
+/// the response.
+pub(crate) mod custs1_sim {
+    use crate::host_sim::{
+        ble_stack::profiles::custom::custs::custs1::task::{
+            Custs1AttInfoReq, Custs1ValWriteInd, Custs1ValueReqInd, CUSTS1_ATT_INFO_REQ,
+            CUSTS1_VALUE_REQ_IND, CUSTS1_VAL_WRITE_IND,
+        },
+        platform::core_modules::rwip::{TASK_APP, TASK_CUSTS1},
+        Message, Value,
+    };
+    /// Writes `value` to the attribute at `att_idx`.
+    pub fn write(conidx: u8, att_idx: u16, value: &[u8]) -> std::vec::Vec<Message> {
+        let param = Custs1ValWriteInd {
+            conidx,
+            handle: att_idx,
+            length: value.len() as u16,
+            value: Value(value.to_vec()),
+        };
+        dispatch(CUSTS1_VAL_WRITE_IND, &param)
+    }
+    /// Reads the attribute at `att_idx`.
+    pub fn read(conidx: u8, att_idx: u16) -> std::vec::Vec<Message> {
+        dispatch(
+            CUSTS1_VALUE_REQ_IND,
+            &Custs1ValueReqInd {
+                conidx,
+                att_idx,
+            },
+        )
+    }
+    /// Requests the length of the attribute at `att_idx`.
+    pub fn att_info(conidx: u8, att_idx: u16) -> std::vec::Vec<Message> {
+        dispatch(
+            CUSTS1_ATT_INFO_REQ,
+            &Custs1AttInfoReq {
+                conidx,
+                att_idx,
+            },
+        )
+    }
+    fn dispatch<T>(msg_id: u32, param: &T) -> std::vec::Vec<Message> {
+        super::user_catch_rest_hndl(
+            msg_id as _,
+            param as *const T as *const _,
+            TASK_APP,
+            TASK_CUSTS1,
+        );
+        crate::host_sim::take_sent()
+    }
+}
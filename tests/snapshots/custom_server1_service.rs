@@ -0,0 +1,268 @@
+#[doc(hidden)]
+#[no_mangle]
+static __DA14531_SDK_APP_CUSTS1: () = ();
+#[export_name = "custs1_att_db"]
+pub(crate) static CUSTS1_ATT_DB: [da14531_sdk::ble_stack::host::att::attm::AttmDesc128; 12usize] = [
+    da14531_sdk::ble_stack::host::att::attm::AttmDesc128 {
+        uuid: &da14531_sdk::ble_stack::host::att::ATT_DECL_PRIMARY_SERVICE as *const _
+            as *const u8,
+        uuid_size: da14531_sdk::ble_stack::host::att::ATT_UUID_16_LEN as u8,
+        perm: 1u32,
+        max_length: da14531_sdk::ble_stack::host::att::ATT_UUID_16_LEN as u16,
+        length: da14531_sdk::ble_stack::host::att::ATT_UUID_16_LEN as u16,
+        value: &(65024u16) as *const _ as *const u8,
+    },
+    da14531_sdk::ble_stack::host::att::attm::AttmDesc128 {
+        uuid: &da14531_sdk::ble_stack::host::att::ATT_DECL_CHARACTERISTIC as *const _
+            as *const u8,
+        uuid_size: da14531_sdk::ble_stack::host::att::ATT_UUID_16_LEN as u8,
+        perm: 1u32,
+        max_length: 0,
+        length: 0,
+        value: core::ptr::null(),
+    },
+    ///Permissions: Permissions { read: Disabled, write: Enabled, indication: Disabled, notification: Disabled, extended_properties_present: false, broadcast_permission: false, encryption_key_length_16_bytes: false, write_command_accepted: false, write_signed_accepted: false, write_request_accepted: true, uuid_length: L16 }
+    ///Permissions: 0b000000000000100000000000001000
+    da14531_sdk::ble_stack::host::att::attm::AttmDesc128 {
+        uuid: &65025u16 as *const _ as *const u8,
+        uuid_size: da14531_sdk::ble_stack::host::att::ATT_UUID_16_LEN as u8,
+        perm: 131080u32,
+        max_length: (1 << 15) | 1u16,
+        length: 0,
+        value: core::ptr::null(),
+    },
+    da14531_sdk::ble_stack::host::att::attm::AttmDesc128 {
+        uuid: &da14531_sdk::ble_stack::host::att::ATT_DESC_CHAR_USER_DESCRIPTION
+            as *const _ as *const u8,
+        uuid_size: da14531_sdk::ble_stack::host::att::ATT_UUID_16_LEN as u8,
+        perm: 1u32,
+        max_length: 4u16,
+        length: 4u16,
+        value: &[77u8, 111u8, 100u8, 101u8] as *const _ as *const u8,
+    },
+    da14531_sdk::ble_stack::host::att::attm::AttmDesc128 {
+        uuid: &da14531_sdk::ble_stack::host::att::ATT_DECL_CHARACTERISTIC as *const _
+            as *const u8,
+        uuid_size: da14531_sdk::ble_stack::host::att::ATT_UUID_16_LEN as u8,
+        perm: 1u32,
+        max_length: 0,
+        length: 0,
+        value: core::ptr::null(),
+    },
+    ///Permissions: Permissions { read: Auth, write: Disabled, indication: Disabled, notification: Enabled, extended_properties_present: false, broadcast_permission: false, encryption_key_length_16_bytes: false, write_command_accepted: false, write_signed_accepted: false, write_request_accepted: false, uuid_length: L16 }
+    ///Permissions: 0b000000000000000000001000000011
+    da14531_sdk::ble_stack::host::att::attm::AttmDesc128 {
+        uuid: &65026u16 as *const _ as *const u8,
+        uuid_size: da14531_sdk::ble_stack::host::att::ATT_UUID_16_LEN as u8,
+        perm: 515u32,
+        max_length: (1 << 15) | crate::ble::STATUS_LEN,
+        length: 0,
+        value: core::ptr::null(),
+    },
+    da14531_sdk::ble_stack::host::att::attm::AttmDesc128 {
+        uuid: &da14531_sdk::ble_stack::host::att::ATT_DECL_CHARACTERISTIC as *const _
+            as *const u8,
+        uuid_size: da14531_sdk::ble_stack::host::att::ATT_UUID_16_LEN as u8,
+        perm: 1u32,
+        max_length: 0,
+        length: 0,
+        value: core::ptr::null(),
+    },
+    ///Permissions: Permissions { read: Disabled, write: Secure, indication: Unauth, notification: Disabled, extended_properties_present: false, broadcast_permission: false, encryption_key_length_16_bytes: false, write_command_accepted: true, write_signed_accepted: false, write_request_accepted: false, uuid_length: L16 }
+    ///Permissions: 0b000000000000001000000010100000
+    da14531_sdk::ble_stack::host::att::attm::AttmDesc128 {
+        uuid: &65027u16 as *const _ as *const u8,
+        uuid_size: da14531_sdk::ble_stack::host::att::ATT_UUID_16_LEN as u8,
+        perm: 32928u32,
+        max_length: (1 << 15) | 20u16,
+        length: 0,
+        value: core::ptr::null(),
+    },
+    da14531_sdk::ble_stack::host::att::attm::AttmDesc128 {
+        uuid: &da14531_sdk::ble_stack::host::att::ATT_DESC_CLIENT_CHAR_CFG as *const _
+            as *const u8,
+        uuid_size: da14531_sdk::ble_stack::host::att::ATT_UUID_16_LEN as u8,
+        perm: 131081u32,
+        max_length: 0,
+        length: 0,
+        value: core::ptr::null(),
+    },
+    da14531_sdk::ble_stack::host::att::attm::AttmDesc128 {
+        uuid: &da14531_sdk::ble_stack::host::att::ATT_DECL_PRIMARY_SERVICE as *const _
+            as *const u8,
+        uuid_size: da14531_sdk::ble_stack::host::att::ATT_UUID_16_LEN as u8,
+        perm: 1u32,
+        max_length: da14531_sdk::ble_stack::host::att::ATT_UUID_16_LEN as u16,
+        length: da14531_sdk::ble_stack::host::att::ATT_UUID_16_LEN as u16,
+        value: &(65040u16) as *const _ as *const u8,
+    },
+    da14531_sdk::ble_stack::host::att::attm::AttmDesc128 {
+        uuid: &da14531_sdk::ble_stack::host::att::ATT_DECL_CHARACTERISTIC as *const _
+            as *const u8,
+        uuid_size: da14531_sdk::ble_stack::host::att::ATT_UUID_16_LEN as u8,
+        perm: 1u32,
+        max_length: 0,
+        length: 0,
+        value: core::ptr::null(),
+    },
+    ///Permissions: Permissions { read: Unauth, write: Disabled, indication: Disabled, notification: Disabled, extended_properties_present: false, broadcast_permission: false, encryption_key_length_16_bytes: false, write_command_accepted: false, write_signed_accepted: false, write_request_accepted: false, uuid_length: L16 }
+    ///Permissions: 0b000000000000000000000000000010
+    da14531_sdk::ble_stack::host::att::attm::AttmDesc128 {
+        uuid: &65041u16 as *const _ as *const u8,
+        uuid_size: da14531_sdk::ble_stack::host::att::ATT_UUID_16_LEN as u8,
+        perm: 2u32,
+        max_length: (1 << 15) | 64u16,
+        length: 0,
+        value: core::ptr::null(),
+    },
+];
+const CUSTS1_ATT_DB_LEN: u8 = 12u8;
+#[export_name = "custs1_services"]
+static CUSTS1_SERVICES: [u8; 2usize + 1] = [0u8, 9u8, 12u8];
+#[export_name = "custs1_services_size"]
+static CUSTS1_SERVICES_SIZE: u32 = 2usize as u32;
+/// Handle of the first attribute, `0` if the stack assigns it.
+pub(crate) const CUSTS1_START_HANDLE: u16 = 0u16;
+/// Hash of the attribute layout. Store it alongside the bonding data and send a
+/// Service Changed indication to bonded peers when it differs after an update.
+pub(crate) const CUSTS1_ATT_DB_HASH: u32 = {
+    let lengths = [crate::ble::STATUS_LEN as u16];
+    let mut hash = 4118778864u32;
+    let mut i = 0;
+    while i < lengths.len() {
+        let bytes = lengths[i].to_le_bytes();
+        hash = (hash ^ bytes[0] as u32).wrapping_mul(16777619u32);
+        hash = (hash ^ bytes[1] as u32).wrapping_mul(16777619u32);
+        i += 1;
+    }
+    hash
+};
+pub(crate) const SERVICE_CONTROL_UUID: u16 = 65024u16;
+pub(crate) const SERVICE_LOG_UUID: u16 = 65040u16;
+/// Complete list of the service UUIDs marked `advertise: true`, as an AD structure
+/// ready to be included in the advertising data.
+pub(crate) const ADV_SERVICE_UUIDS: [u8; 4usize] = [3u8, 3u8, 0u8, 254u8];
+pub(crate) mod char_idx_map {
+    pub const CHAR_MODE_HANDLE: u16 = 2u16;
+    pub const CHAR_STATUS_HANDLE: u16 = 5u16;
+    pub const CHAR_COMMAND_HANDLE: u16 = 7u16;
+    pub const CHAR_ENTRIES_HANDLE: u16 = 11u16;
+}
+/// Setup custom profile funcs
+static CUST_PRF_FUNCS: [da14531_sdk::app_modules::app_custs::CustPrfFuncCallbacks; 1] = [
+    da14531_sdk::app_modules::app_custs::CustPrfFuncCallbacks {
+        task_id: da14531_sdk::platform::core_modules::rwip::TASK_ID_CUSTS1,
+        att_db: &CUSTS1_ATT_DB as *const _
+            as *const da14531_sdk::bindings::attm_desc_128,
+        max_nb_att: CUSTS1_ATT_DB_LEN,
+        db_create_func: Some(da14531_sdk::bindings::app_custs1_create_db),
+        enable_func: None,
+        init_func: None,
+        value_wr_validation_func: None,
+    },
+];
+#[no_mangle]
+extern "C" fn custs_get_func_callbacks(
+    task_id: da14531_sdk::platform::core_modules::rwip::KeApiId,
+) -> *const da14531_sdk::app_modules::app_custs::CustPrfFuncCallbacks {
+    for pfcb in &CUST_PRF_FUNCS {
+        if pfcb.task_id == task_id {
+            let pfcb_ptr = pfcb as *const _
+                as *const da14531_sdk::app_modules::app_custs::CustPrfFuncCallbacks;
+            return pfcb_ptr;
+        } else if pfcb.task_id
+            == da14531_sdk::platform::core_modules::rwip::TASK_ID_INVALID
+        {
+            break;
+        }
+    }
+    core::ptr::null()
+}
+#[export_name = "rom_cust_prf_cfg"]
+static ROM_CUST_PRF_CFG: da14531_sdk::ble_stack::profiles::custom::custs::RomCustPrfCfg = da14531_sdk::ble_stack::profiles::custom::custs::RomCustPrfCfg {
+    custs1_services: CUSTS1_SERVICES.as_ptr(),
+    custs1_services_size: &(2usize as u8),
+    custs1_att_db: CUSTS1_ATT_DB.as_ptr() as *mut _,
+    custs_get_func_callbacks: Some(custs_get_func_callbacks),
+};
+/// Handles the messages that are not handled by the SDK internal mechanisms.
+///
+/// # Arguments
+/// * `msg_id` - Id of the message received.
+/// * `param` - Pointer to the parameters of the message.
+/// * `dest_id` - ID of the receiving task instance.
+/// * `src_id` - ID of the sending task instance.
+#[no_mangle]
+extern "C" fn user_catch_rest_hndl(
+    msg_id: da14531_sdk::platform::core_modules::ke::msg::KeMsgId,
+    param: *const core::ffi::c_void,
+    dest_id: da14531_sdk::platform::core_modules::ke::task::KeTaskId,
+    src_id: da14531_sdk::platform::core_modules::ke::task::KeTaskId,
+) {
+    match msg_id as u32 {
+        da14531_sdk::ble_stack::profiles::custom::custs::custs1::task::CUSTS1_VAL_WRITE_IND => {
+            let param = param
+                as *const da14531_sdk::ble_stack::profiles::custom::custs::custs1::task::Custs1ValWriteInd;
+            let param = unsafe { &*param };
+            match param.handle {
+                2u16 => crate::ble::on_mode(param),
+                7u16 => crate::ble::on_command(param),
+                _ => {}
+            }
+        }
+        da14531_sdk::ble_stack::profiles::custom::custs::custs1::task::CUSTS1_ATT_INFO_REQ => {
+            let param = param
+                as *const da14531_sdk::ble_stack::profiles::custom::custs::custs1::task::Custs1AttInfoReq;
+            let param = unsafe { &*param };
+            let att_idx = param.att_idx;
+            match att_idx {
+                _ => {
+                    let mut response = da14531_sdk::ble_stack::profiles::custom::custs::custs1::task::KeMsgCusts1AttInfoRsp::new(
+                        src_id,
+                        dest_id,
+                    );
+                    let conidx = da14531_sdk::app_modules::app_env_get_conidx(
+                        param.conidx,
+                    );
+                    response.fields().conidx = conidx;
+                    response.fields().att_idx = param.att_idx;
+                    response.fields().length = 0;
+                    response
+                        .fields()
+                        .status = da14531_sdk::ble_stack::rwble_hl::error::HlErr::ATT_ERR_WRITE_NOT_PERMITTED
+                        as u8;
+                    response.send();
+                }
+            }
+        }
+        da14531_sdk::ble_stack::profiles::custom::custs::custs1::task::CUSTS1_VALUE_REQ_IND => {
+            let param = param
+                as *const da14531_sdk::ble_stack::profiles::custom::custs::custs1::task::Custs1ValueReqInd;
+            let param = unsafe { &*param };
+            let att_idx = param.att_idx;
+            match att_idx {
+                5u16 => crate::ble::on_status(param),
+                11u16 => crate::log::on_read(param),
+                _ => {
+                    let mut response = da14531_sdk::ble_stack::profiles::custom::custs::custs1::task::KeMsgCusts1ValueReqRsp::new(
+                        dest_id,
+                        src_id,
+                    );
+                    response
+                        .fields()
+                        .conidx = da14531_sdk::app_modules::app_env_get_conidx(
+                        param.conidx,
+                    );
+                    response.fields().att_idx = param.att_idx;
+                    response.fields().length = 0;
+                    response
+                        .fields()
+                        .status = da14531_sdk::ble_stack::rwble_hl::error::HlErr::ATT_ERR_APP_ERROR
+                        as u8;
+                    response.send();
+                }
+            }
+        }
+        _ => {}
+    }
+}
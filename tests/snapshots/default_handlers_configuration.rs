@@ -0,0 +1,9 @@
+#[doc(hidden)]
+#[no_mangle]
+static __DA14531_SDK_USER_DEFAULT_HND_CONF: () = ();
+#[export_name = "user_default_hnd_conf"]
+pub(crate) static USER_DEFAULT_HND_CONF: da14531_sdk::app_modules::DefaultHandlersConfiguration = da14531_sdk::app_modules::DefaultHandlersConfiguration {
+    adv_scenario: da14531_sdk::app_modules::DEF_ADV_WITH_TIMEOUT,
+    advertise_period: 3000u64 as _,
+    security_request_scenario: DEF_SEC_REQ_ON_CONNECT,
+};
@@ -0,0 +1,149 @@
+#[doc(hidden)]
+#[no_mangle]
+static __DA14531_SDK_APP_DISS: () = ();
+mod app_diss_task {
+    #[allow(unused_imports)]
+    use super::*;
+    use da14531_sdk::{
+        ble_stack::profiles::dis::diss::{
+            task::{DissValueReqInd, KeMsgDynDissValueCfm, DISS_VALUE_REQ_IND},
+            DIS_MANUFACTURER_NAME_CHAR, DIS_MODEL_NB_STR_CHAR, DIS_PNP_ID_CHAR,
+            DIS_SW_REV_STR_CHAR, DIS_SYSTEM_ID_CHAR, DIS_FIRM_REV_STR_CHAR,
+            DIS_HARD_REV_STR_CHAR, DIS_IEEE_CHAR, DIS_SERIAL_NB_STR_CHAR,
+        },
+        platform::core_modules::ke::{
+            msg::{KeMsgId, KE_MSG_CONSUMED},
+            task::KeTaskId,
+        },
+    };
+    extern "C" fn diss_value_req_ind_handler(
+        _msg_id: KeMsgId,
+        param: *const core::ffi::c_void,
+        dest_id: KeTaskId,
+        src_id: KeTaskId,
+    ) -> i32 {
+        let param = param as *const DissValueReqInd;
+        let param = unsafe { &*param };
+        let answer: bool = crate::dis::on_value_req(param, dest_id, src_id);
+        if !answer {
+            return KE_MSG_CONSUMED as i32;
+        }
+        match param.value as u32 {
+            DIS_MANUFACTURER_NAME_CHAR => {
+                let mut msg = KeMsgDynDissValueCfm::<4u16>::new(dest_id, src_id);
+                unsafe {
+                    msg
+                        .fields()
+                        .data
+                        .as_mut_slice(4u16 as usize)
+                        .copy_from_slice(&[65u8, 67u8, 77u8, 69u8])
+                };
+                msg.fields().length = 4u16 as u8;
+                msg.fields().value = param.value;
+                msg.send();
+            }
+            DIS_FIRM_REV_STR_CHAR => {
+                let mut msg = KeMsgDynDissValueCfm::<3u16>::new(dest_id, src_id);
+                unsafe {
+                    msg
+                        .fields()
+                        .data
+                        .as_mut_slice(3u16 as usize)
+                        .copy_from_slice(&[49u8, 46u8, 48u8])
+                };
+                msg.fields().length = 3u16 as u8;
+                msg.fields().value = param.value;
+                msg.send();
+            }
+            _ => {
+                let mut msg = KeMsgDynDissValueCfm::<0>::new(dest_id, src_id);
+                msg.fields().length = 0;
+                msg.fields().value = param.value;
+                msg.send();
+            }
+        };
+        KE_MSG_CONSUMED as i32
+    }
+    extern "C" fn __app_diss_msg_handler_0(
+        _msg_id: da14531_sdk::platform::core_modules::ke::msg::KeMsgId,
+        param: *const core::ffi::c_void,
+        dest_id: da14531_sdk::platform::core_modules::ke::task::KeTaskId,
+        src_id: da14531_sdk::platform::core_modules::ke::task::KeTaskId,
+    ) -> i32 {
+        crate::dis::on_set_value_rsp(unsafe { &*(param as *const _) }, dest_id, src_id);
+        da14531_sdk::platform::core_modules::ke::msg::KE_MSG_CONSUMED as i32
+    }
+    static APP_DISS_PROCESS_HANDLERS: [da14531_sdk::platform::core_modules::ke::msg::KeMsgHandler; 2usize] = [
+        da14531_sdk::platform::core_modules::ke::msg::KeMsgHandler {
+            id: DISS_VALUE_REQ_IND as u16,
+            func: Some(diss_value_req_ind_handler),
+        },
+        da14531_sdk::platform::core_modules::ke::msg::KeMsgHandler {
+            id: DISS_SET_VALUE_RSP as u16,
+            func: Some(__app_diss_msg_handler_0),
+        },
+    ];
+    #[no_mangle]
+    extern "C" fn app_diss_process_handler(
+        msg_id: da14531_sdk::platform::core_modules::ke::msg::KeMsgId,
+        param: *const core::ffi::c_void,
+        dest_id: da14531_sdk::platform::core_modules::ke::task::KeTaskId,
+        src_id: da14531_sdk::platform::core_modules::ke::task::KeTaskId,
+        msg_ret: *mut da14531_sdk::platform::core_modules::ke::msg::KeMsgStatusTag,
+    ) -> da14531_sdk::app_modules::ProcessEventResponse {
+        return unsafe {
+            da14531_sdk::bindings::app_std_process_event(
+                msg_id,
+                param,
+                src_id,
+                dest_id,
+                msg_ret,
+                APP_DISS_PROCESS_HANDLERS.as_ptr() as *mut _,
+                APP_DISS_PROCESS_HANDLERS.len() as i32,
+            )
+        };
+    }
+}
+mod app_diss {
+    use da14531_sdk::{
+        ble_stack::profiles::dis::diss::{
+            DissDbCfg, DIS_FIRM_REV_STR_CHAR_SUP, DIS_HARD_REV_STR_CHAR_CHAR_SUP,
+            DIS_IEEE_CHAR_CHAR_SUP, DIS_MANUFACTURER_NAME_CHAR_SUP,
+            DIS_MODEL_NB_STR_CHAR_SUP, DIS_PNP_ID_CHAR_SUP, DIS_SERIAL_NB_STR_CHAR_SUP,
+            DIS_SW_REV_STR_CHAR_SUP, DIS_SYSTEM_ID_CHAR_SUP,
+        },
+        platform::core_modules::rwip::TASK_ID_DISS,
+    };
+    #[no_mangle]
+    extern "C" fn app_dis_init() {}
+    #[no_mangle]
+    extern "C" fn app_diss_create_db() {
+        const SIZE: u16 = core::mem::size_of::<DissDbCfg>() as u16;
+        let mut msg = da14531_sdk::ble_stack::host::gap::gapm::task::KeMsgDynGapmProfileTaskAdd::<
+            SIZE,
+        >::new(
+            da14531_sdk::platform::core_modules::rwip::TASK_APP as u16,
+            da14531_sdk::platform::core_modules::rwip::TASK_GAPM as u16,
+        );
+        msg
+            .fields()
+            .operation = da14531_sdk::ble_stack::host::gap::gapm::task::GAPM_PROFILE_TASK_ADD
+            as u8;
+        msg
+            .fields()
+            .sec_lvl = da14531_sdk::app_modules::get_user_prf_srv_perm(TASK_ID_DISS)
+            as u8;
+        msg.fields().prf_task_id = TASK_ID_DISS as u16;
+        msg
+            .fields()
+            .app_task = da14531_sdk::platform::core_modules::rwip::TASK_APP as u16;
+        msg.fields().start_hdl = 0u16;
+        let db_cfg_ptr = &mut msg.fields().param as *mut _ as *mut DissDbCfg;
+        unsafe { core::ptr::write_bytes(db_cfg_ptr, 0, 1) };
+        let db_cfg = unsafe { db_cfg_ptr.as_mut().unwrap() };
+        db_cfg.features = 0;
+        db_cfg.features |= DIS_MANUFACTURER_NAME_CHAR_SUP as u16;
+        db_cfg.features |= DIS_FIRM_REV_STR_CHAR_SUP as u16;
+        msg.send();
+    }
+}
@@ -0,0 +1,40 @@
+#[doc(hidden)]
+#[no_mangle]
+static __DA14531_SDK_USER_GAPM_CONF: () = ();
+#[export_name = "user_gapm_conf"]
+pub(crate) static USER_GAPM_CONF: da14531_sdk::app_modules::GapmConfiguration = da14531_sdk::app_modules::GapmConfiguration {
+    role: da14531_sdk::ble_stack::host::gap::GAP_ROLE_PERIPHERAL as _,
+    max_mtu: 247u16,
+    addr_type: da14531_sdk::app_modules::APP_CFG_ADDR_STATIC as _,
+    renew_dur: 15000u16,
+    addr: da14531_sdk::platform::core_modules::common::BDAddr {
+        addr: [0u8, 0u8, 0u8, 0u8, 0u8, 0u8],
+    },
+    irk: da14531_sdk::ble_stack::host::gap::GapSecKey {
+        key: [
+            0u8,
+            0u8,
+            0u8,
+            0u8,
+            0u8,
+            0u8,
+            0u8,
+            0u8,
+            0u8,
+            0u8,
+            0u8,
+            0u8,
+            0u8,
+            0u8,
+            0u8,
+            0u8,
+        ],
+    },
+    att_cfg: (GAPM_MASK_ATT_SVC_CHG_EN | 4) as _,
+    gap_start_hdl: 0u16,
+    gatt_start_hdl: 0u16,
+    max_mps: 247u16,
+    max_txoctets: 251u16,
+    max_txtime: 2120u16,
+    priv1_2: 0u8 as _,
+};
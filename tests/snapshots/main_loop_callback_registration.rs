@@ -0,0 +1,6 @@
+fn init() {}
+const _: () = {
+    #[used]
+    #[link_section = "da14531_cb_app_on_init"]
+    static ENTRY: Option<fn()> = Some(init);
+};
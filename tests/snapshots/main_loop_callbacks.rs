@@ -0,0 +1,34 @@
+#[doc(hidden)]
+#[no_mangle]
+static __DA14531_SDK_USER_APP_MAIN_LOOP_CALLBACKS: () = ();
+extern "C" fn __app_on_init() {
+    {
+        crate::ble::init();
+    }
+}
+extern "C" fn __app_validate_sleep(
+    mode: da14531_sdk::platform::arch::SleepMode,
+) -> da14531_sdk::platform::arch::SleepMode {
+    {
+        const APP_VALIDATE_SLEEP: fn(
+            da14531_sdk::platform::arch::SleepMode,
+        ) -> da14531_sdk::platform::arch::SleepMode = |mode| mode;
+        APP_VALIDATE_SLEEP(mode)
+    }
+}
+extern "C" fn __app_going_to_sleep(mode: da14531_sdk::platform::arch::SleepMode) {
+    {
+        const APP_GOING_TO_SLEEP: fn(da14531_sdk::platform::arch::SleepMode) = crate::ble::sleep;
+        APP_GOING_TO_SLEEP(mode)
+    }
+}
+#[export_name = "user_app_main_loop_callbacks"]
+pub(crate) static USER_APP_MAIN_LOOP_CALLBACKS: da14531_sdk::platform::arch::ArchMainLoopCallbacks = da14531_sdk::platform::arch::ArchMainLoopCallbacks {
+    app_on_init: Some(__app_on_init),
+    app_on_ble_powered: None,
+    app_on_system_powered: None,
+    app_before_sleep: None,
+    app_validate_sleep: Some(__app_validate_sleep),
+    app_going_to_sleep: Some(__app_going_to_sleep),
+    app_resume_from_sleep: None,
+};
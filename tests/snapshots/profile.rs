@@ -0,0 +1,76 @@
+#[doc(hidden)]
+#[no_mangle]
+static __DA14531_SDK_APP_FINDT: () = ();
+mod app_findt_task {
+    #[allow(unused_imports)]
+    use super::*;
+    extern "C" fn __app_findt_msg_handler_0(
+        _msg_id: da14531_sdk::platform::core_modules::ke::msg::KeMsgId,
+        param: *const core::ffi::c_void,
+        dest_id: da14531_sdk::platform::core_modules::ke::task::KeTaskId,
+        src_id: da14531_sdk::platform::core_modules::ke::task::KeTaskId,
+    ) -> i32 {
+        crate::ble::on_alert(unsafe { &*(param as *const _) }, dest_id, src_id);
+        da14531_sdk::platform::core_modules::ke::msg::KE_MSG_CONSUMED as i32
+    }
+    static APP_FINDT_PROCESS_HANDLERS: [da14531_sdk::platform::core_modules::ke::msg::KeMsgHandler; 1usize] = [
+        da14531_sdk::platform::core_modules::ke::msg::KeMsgHandler {
+            id: FINDT_ALERT_IND as u16,
+            func: Some(__app_findt_msg_handler_0),
+        },
+    ];
+    #[no_mangle]
+    extern "C" fn app_findt_process_handler(
+        msg_id: da14531_sdk::platform::core_modules::ke::msg::KeMsgId,
+        param: *const core::ffi::c_void,
+        dest_id: da14531_sdk::platform::core_modules::ke::task::KeTaskId,
+        src_id: da14531_sdk::platform::core_modules::ke::task::KeTaskId,
+        msg_ret: *mut da14531_sdk::platform::core_modules::ke::msg::KeMsgStatusTag,
+    ) -> da14531_sdk::app_modules::ProcessEventResponse {
+        return unsafe {
+            da14531_sdk::bindings::app_std_process_event(
+                msg_id,
+                param,
+                src_id,
+                dest_id,
+                msg_ret,
+                APP_FINDT_PROCESS_HANDLERS.as_ptr() as *mut _,
+                APP_FINDT_PROCESS_HANDLERS.len() as i32,
+            )
+        };
+    }
+}
+mod app_findt {
+    #[allow(unused_imports)]
+    use super::*;
+    #[no_mangle]
+    extern "C" fn app_findt_init() {}
+    #[no_mangle]
+    extern "C" fn app_findt_create_db() {
+        const SIZE: u16 = core::mem::size_of::<FindtDbCfg>() as u16;
+        let mut msg = da14531_sdk::ble_stack::host::gap::gapm::task::KeMsgDynGapmProfileTaskAdd::<
+            SIZE,
+        >::new(
+            da14531_sdk::platform::core_modules::rwip::TASK_APP as u16,
+            da14531_sdk::platform::core_modules::rwip::TASK_GAPM as u16,
+        );
+        msg
+            .fields()
+            .operation = da14531_sdk::ble_stack::host::gap::gapm::task::GAPM_PROFILE_TASK_ADD
+            as u8;
+        msg
+            .fields()
+            .sec_lvl = da14531_sdk::app_modules::get_user_prf_srv_perm(TASK_ID_FINDT)
+            as u8;
+        msg.fields().prf_task_id = TASK_ID_FINDT as u16;
+        msg
+            .fields()
+            .app_task = da14531_sdk::platform::core_modules::rwip::TASK_APP as u16;
+        msg.fields().start_hdl = 0u16;
+        let db_cfg_ptr = &mut msg.fields().param as *mut _ as *mut FindtDbCfg;
+        unsafe { core::ptr::write_bytes(db_cfg_ptr, 0, 1) };
+        let db_cfg = unsafe { db_cfg_ptr.as_mut().unwrap() };
+        let _ = db_cfg;
+        msg.send();
+    }
+}
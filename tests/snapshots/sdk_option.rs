@@ -0,0 +1,50 @@
+#[doc(hidden)]
+#[no_mangle]
+static __DA14531_SDK_USER_APP_BOND_DB_CALLBACKS: () = ();
+extern "C" fn __app_bdb_search_entry(
+    search_type: firmware::sdk::app_modules::BdbSearchByType,
+    search_param: *mut core::ffi::c_void,
+    search_param_length: u8,
+) -> *const firmware::sdk::app_modules::AppSecBondDataEnvTag {
+    match {
+        {
+            const APP_BDB_SEARCH_ENTRY: fn(
+                firmware::sdk::app_modules::BdbSearchByType,
+                &[u8],
+            ) -> Option<&'static firmware::sdk::app_modules::AppSecBondDataEnvTag> = crate::bond_db::search;
+            APP_BDB_SEARCH_ENTRY(
+                search_type,
+                if search_param.is_null() {
+                    &[]
+                } else {
+                    unsafe {
+                        core::slice::from_raw_parts(
+                            search_param as *const u8,
+                            search_param_length as usize,
+                        )
+                    }
+                },
+            )
+        }
+    } {
+        Some(referenced) => referenced as *const _,
+        None => core::ptr::null(),
+    }
+}
+#[export_name = "user_app_bond_db_callbacks"]
+pub(crate) static USER_APP_BOND_DB_CALLBACKS: firmware::sdk::app_modules::AppBondDbCallbacks = firmware::sdk::app_modules::AppBondDbCallbacks {
+    app_bdb_init: Some(firmware::sdk::bindings::default_app_bdb_init),
+    app_bdb_get_size: Some(firmware::sdk::bindings::default_app_bdb_get_size),
+    app_bdb_add_entry: Some(firmware::sdk::bindings::default_app_bdb_add_entry),
+    app_bdb_remove_entry: Some(firmware::sdk::bindings::default_app_bdb_remove_entry),
+    app_bdb_search_entry: Some(__app_bdb_search_entry),
+    app_bdb_get_number_of_stored_irks: Some(
+        firmware::sdk::bindings::default_app_bdb_get_number_of_stored_irks,
+    ),
+    app_bdb_get_stored_irks: Some(
+        firmware::sdk::bindings::default_app_bdb_get_stored_irks,
+    ),
+    app_bdb_get_device_info_from_slot: Some(
+        firmware::sdk::bindings::default_app_bdb_get_device_info_from_slot,
+    ),
+};
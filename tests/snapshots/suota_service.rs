@@ -0,0 +1,269 @@
+#[doc(hidden)]
+#[no_mangle]
+static __DA14531_SDK_APP_SUOTAR: () = ();
+/// Patch memory region and block size the SUOTA service was configured with.
+pub(crate) mod suota {
+    /// First address of the patch memory region.
+    pub const PATCH_REGION_START: u32 = 131072u32;
+    /// Address following the last byte of the patch memory region.
+    pub const PATCH_REGION_END: u32 = 229376u32;
+    /// Largest block the peer may announce through `SUOTAR_PATCH_LEN_IND`.
+    pub const MAX_PATCH_LEN: u16 = 240u16;
+}
+mod app_suotar_task {
+    use super::suota::{MAX_PATCH_LEN, PATCH_REGION_END, PATCH_REGION_START};
+    use da14531_sdk::{
+        bindings,
+        ble_stack::profiles::{
+            prf_get_task_from_id,
+            suota::suotar::{
+                task::{
+                    KeMsgSuotarStatusUpdateReq, SuotarPatchDataInd, SuotarPatchLenInd,
+                    SuotarPatchMemDevInd, SUOTAR_GPIO_MAP_IND, SUOTAR_PATCH_DATA_IND,
+                    SUOTAR_PATCH_LEN_IND, SUOTAR_PATCH_MEM_DEV_IND,
+                },
+                SUOTAR_CMP_OK, SUOTAR_CRC_ERR, SUOTAR_EXT_MEM_WRITE_ERR, SUOTAR_IMG_END,
+                SUOTAR_IMG_STARTED, SUOTAR_INVAL_MEM_TYPE, SUOTAR_MEM_SERVICE_EXIT,
+                SUOTAR_PATCH_LEN_ERR, SUOTAR_REBOOT, SUOTAR_SRV_EXIT,
+                SUOTAR_IMG_SPI_FLASH,
+            },
+        },
+        platform::core_modules::{
+            ke::{
+                msg::{KeMsgId, KE_MSG_CONSUMED},
+                task::KeTaskId,
+            },
+            rwip::{TASK_APP, TASK_ID_SUOTAR},
+        },
+    };
+    struct SuotaState {
+        active: bool,
+        address: u32,
+        patch_len: u16,
+        block_received: u16,
+        crc: u8,
+    }
+    static mut SUOTA_STATE: SuotaState = SuotaState {
+        active: false,
+        address: PATCH_REGION_START,
+        patch_len: 0,
+        block_received: 0,
+        crc: 0,
+    };
+    /// State of the update. The kernel runs one message handler at a time and each
+    /// handler takes it once, so the reference is never aliased.
+    fn suota_state() -> &'static mut SuotaState {
+        unsafe { &mut *core::ptr::addr_of_mut!(SUOTA_STATE) }
+    }
+    fn send_status(conidx: u8, status: u8) {
+        let mut msg = KeMsgSuotarStatusUpdateReq::new(
+            TASK_APP as u16,
+            prf_get_task_from_id(TASK_ID_SUOTAR as u16),
+        );
+        msg.fields().conidx = conidx;
+        msg.fields().status = status;
+        msg.send();
+        crate::ota::on_status(status);
+    }
+    fn finish(state: &mut SuotaState, conidx: u8, success: bool, status: u8) {
+        state.active = false;
+        unsafe { bindings::app_suotar_stop() };
+        send_status(conidx, status);
+        let _ = success;
+    }
+    fn write_data(address: u32, data: &[u8]) -> bool {
+        let mut sector = (address + 4096u32 - 1) / 4096u32 * 4096u32;
+        while sector < address + data.len() as u32 {
+            unsafe {
+                bindings::spi_flash_block_erase(sector, bindings::SPI_FLASH_OP_SE);
+            }
+            sector += 4096u32;
+        }
+        let mut written = 0u32;
+        let result = unsafe {
+            bindings::spi_flash_write_data(
+                data.as_ptr() as *mut u8,
+                address,
+                data.len() as u32,
+                &mut written,
+            )
+        };
+        result >= 0 && written == data.len() as u32
+    }
+    extern "C" fn suotar_patch_mem_dev_ind_handler(
+        _msg_id: KeMsgId,
+        param: *const core::ffi::c_void,
+        _dest_id: KeTaskId,
+        _src_id: KeTaskId,
+    ) -> i32 {
+        let param = param as *const SuotarPatchMemDevInd;
+        let param = unsafe { &*param };
+        let command = (param.mem_dev >> 24) as u8;
+        let base_address = param.mem_dev & 0x00FF_FFFF;
+        let state = suota_state();
+        match command as u32 {
+            SUOTAR_IMG_SPI_FLASH => {
+                if !((PATCH_REGION_START..PATCH_REGION_END).contains(&base_address)
+                    && base_address % 4096u32 == 0)
+                {
+                    send_status(param.conidx, SUOTAR_INVAL_MEM_TYPE as u8);
+                } else {
+                    unsafe { bindings::app_suotar_start() };
+                    state.active = true;
+                    state.address = base_address;
+                    state.patch_len = 0;
+                    state.block_received = 0;
+                    state.crc = 0;
+                    send_status(param.conidx, SUOTAR_IMG_STARTED as u8);
+                }
+            }
+            SUOTAR_IMG_END if state.active => {
+                if state.crc == 0 {
+                    finish(state, param.conidx, true, SUOTAR_CMP_OK as u8);
+                } else {
+                    finish(state, param.conidx, false, SUOTAR_CRC_ERR as u8);
+                }
+            }
+            SUOTAR_MEM_SERVICE_EXIT => {
+                finish(state, param.conidx, false, SUOTAR_SRV_EXIT as u8);
+            }
+            SUOTAR_REBOOT => {
+                unsafe {
+                    bindings::platform_reset(bindings::RESET_AFTER_SUOTA_UPDATE);
+                }
+            }
+            _ => {
+                send_status(param.conidx, SUOTAR_INVAL_MEM_TYPE as u8);
+            }
+        }
+        KE_MSG_CONSUMED as i32
+    }
+    extern "C" fn suotar_patch_len_ind_handler(
+        _msg_id: KeMsgId,
+        param: *const core::ffi::c_void,
+        _dest_id: KeTaskId,
+        _src_id: KeTaskId,
+    ) -> i32 {
+        let param = param as *const SuotarPatchLenInd;
+        let param = unsafe { &*param };
+        let state = suota_state();
+        if param.len == 0 || param.len > MAX_PATCH_LEN {
+            send_status(param.conidx, SUOTAR_PATCH_LEN_ERR as u8);
+        } else {
+            state.patch_len = param.len;
+            state.block_received = 0;
+        }
+        KE_MSG_CONSUMED as i32
+    }
+    extern "C" fn suotar_patch_data_ind_handler(
+        _msg_id: KeMsgId,
+        param: *const core::ffi::c_void,
+        _dest_id: KeTaskId,
+        _src_id: KeTaskId,
+    ) -> i32 {
+        let param = param as *const SuotarPatchDataInd;
+        let param = unsafe { &*param };
+        let data = unsafe { param.pd.as_slice(param.len as usize) };
+        let state = suota_state();
+        if !state.active || state.patch_len == 0 {
+            send_status(param.conidx, SUOTAR_PATCH_LEN_ERR as u8);
+        } else if state.address + data.len() as u32 > PATCH_REGION_END
+            || state.block_received as usize + data.len() > state.patch_len as usize
+        {
+            finish(state, param.conidx, false, SUOTAR_PATCH_LEN_ERR as u8);
+        } else if !write_data(state.address, data) {
+            finish(state, param.conidx, false, SUOTAR_EXT_MEM_WRITE_ERR as u8);
+        } else {
+            state.crc = data.iter().fold(state.crc, |crc, byte| crc ^ byte);
+            state.address += data.len() as u32;
+            state.block_received += data.len() as u16;
+            if state.block_received == state.patch_len {
+                state.block_received = 0;
+                send_status(param.conidx, SUOTAR_CMP_OK as u8);
+            }
+        }
+        KE_MSG_CONSUMED as i32
+    }
+    extern "C" fn suotar_gpio_map_ind_handler(
+        _msg_id: KeMsgId,
+        _param: *const core::ffi::c_void,
+        _dest_id: KeTaskId,
+        _src_id: KeTaskId,
+    ) -> i32 {
+        KE_MSG_CONSUMED as i32
+    }
+    static APP_SUOTAR_PROCESS_HANDLERS: [da14531_sdk::platform::core_modules::ke::msg::KeMsgHandler; 4usize] = [
+        da14531_sdk::platform::core_modules::ke::msg::KeMsgHandler {
+            id: SUOTAR_PATCH_MEM_DEV_IND as u16,
+            func: Some(suotar_patch_mem_dev_ind_handler),
+        },
+        da14531_sdk::platform::core_modules::ke::msg::KeMsgHandler {
+            id: SUOTAR_GPIO_MAP_IND as u16,
+            func: Some(suotar_gpio_map_ind_handler),
+        },
+        da14531_sdk::platform::core_modules::ke::msg::KeMsgHandler {
+            id: SUOTAR_PATCH_LEN_IND as u16,
+            func: Some(suotar_patch_len_ind_handler),
+        },
+        da14531_sdk::platform::core_modules::ke::msg::KeMsgHandler {
+            id: SUOTAR_PATCH_DATA_IND as u16,
+            func: Some(suotar_patch_data_ind_handler),
+        },
+    ];
+    #[no_mangle]
+    extern "C" fn app_suotar_process_handler(
+        msg_id: da14531_sdk::platform::core_modules::ke::msg::KeMsgId,
+        param: *const core::ffi::c_void,
+        dest_id: da14531_sdk::platform::core_modules::ke::task::KeTaskId,
+        src_id: da14531_sdk::platform::core_modules::ke::task::KeTaskId,
+        msg_ret: *mut da14531_sdk::platform::core_modules::ke::msg::KeMsgStatusTag,
+    ) -> da14531_sdk::app_modules::ProcessEventResponse {
+        return unsafe {
+            da14531_sdk::bindings::app_std_process_event(
+                msg_id,
+                param,
+                src_id,
+                dest_id,
+                msg_ret,
+                APP_SUOTAR_PROCESS_HANDLERS.as_ptr() as *mut _,
+                APP_SUOTAR_PROCESS_HANDLERS.len() as i32,
+            )
+        };
+    }
+}
+mod app_suotar {
+    use da14531_sdk::{
+        ble_stack::profiles::suota::suotar::{SuotarDbCfg, SUOTAR_SPI_SUP},
+        platform::core_modules::rwip::TASK_ID_SUOTAR,
+    };
+    #[no_mangle]
+    extern "C" fn app_suotar_init() {}
+    #[no_mangle]
+    extern "C" fn app_suotar_create_db() {
+        const SIZE: u16 = core::mem::size_of::<SuotarDbCfg>() as u16;
+        let mut msg = da14531_sdk::ble_stack::host::gap::gapm::task::KeMsgDynGapmProfileTaskAdd::<
+            SIZE,
+        >::new(
+            da14531_sdk::platform::core_modules::rwip::TASK_APP as u16,
+            da14531_sdk::platform::core_modules::rwip::TASK_GAPM as u16,
+        );
+        msg
+            .fields()
+            .operation = da14531_sdk::ble_stack::host::gap::gapm::task::GAPM_PROFILE_TASK_ADD
+            as u8;
+        msg
+            .fields()
+            .sec_lvl = da14531_sdk::app_modules::get_user_prf_srv_perm(TASK_ID_SUOTAR)
+            as u8;
+        msg.fields().prf_task_id = TASK_ID_SUOTAR as u16;
+        msg
+            .fields()
+            .app_task = da14531_sdk::platform::core_modules::rwip::TASK_APP as u16;
+        msg.fields().start_hdl = 0u16;
+        let db_cfg_ptr = &mut msg.fields().param as *mut _ as *mut SuotarDbCfg;
+        unsafe { core::ptr::write_bytes(db_cfg_ptr, 0, 1) };
+        let db_cfg = unsafe { db_cfg_ptr.as_mut().unwrap() };
+        db_cfg.features = SUOTAR_SPI_SUP as u8;
+        msg.send();
+    }
+}
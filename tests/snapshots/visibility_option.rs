@@ -0,0 +1,25 @@
+#[doc(hidden)]
+#[no_mangle]
+static __DA14531_SDK_USER_CONNECTION_PARAM_CONF: () = ();
+#[export_name = "user_connection_param_conf"]
+pub(crate) static USER_CONNECTION_PARAM_CONF: firmware::sdk::app_modules::ConnectionParamConfiguration = firmware::sdk::app_modules::ConnectionParamConfiguration {
+    intv_min: 8u16,
+    intv_max: 16u16,
+    latency: 0u16,
+    time_out: 125u16,
+    ce_len_min: 0u16,
+    ce_len_max: 0u16,
+};
+/// Handlers following the configured connection parameters.
+pub(crate) mod connection_params {
+    /// Answers connection parameter update requests of the peer, for use as
+    /// `app_on_update_params_request`.
+    pub fn on_update_params_request(
+        param: &firmware::sdk::ble_stack::host::gap::gapc::task::GapcParamUpdateReqInd,
+        cfm: &mut firmware::sdk::ble_stack::host::gap::gapc::task::GapcParamUpdateCfm,
+    ) {
+        cfm.accept = false;
+        cfm.ce_len_min = 0u16;
+        cfm.ce_len_max = 0u16;
+    }
+}
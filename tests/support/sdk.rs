@@ -0,0 +1,969 @@
+//! Stand-in for the `da14531_sdk` items that the macro expansions refer to, for the cases in
+//! `tests/pass`.
+//!
+//! Only the names, the paths and the shapes of the types come from the SDK. Constants, layouts
+//! and function bodies are placeholders, so the pass cases check that the expansions are
+//! well-formed Rust that resolves and type-checks, not that they agree with da14531-sdk.
+//!
+//! Each case includes it at its crate root together with `extern crate self as da14531_sdk;`,
+//! so the default `da14531_sdk::` paths of the expansions resolve to it.
+
+#![allow(dead_code, non_camel_case_types, unused_imports, clippy::missing_safety_doc)]
+
+/// Trailing array of variable length of the SDK's messages.
+#[derive(Debug, Default)]
+pub struct Value(Vec<u8>);
+
+impl Value {
+    pub unsafe fn as_slice(&self, len: usize) -> &[u8] {
+        &self.0[..len]
+    }
+
+    pub unsafe fn as_mut_slice(&mut self, len: usize) -> &mut [u8] {
+        &mut self.0[..len]
+    }
+}
+
+/// Declares the SDK's wrapper of a kernel message, `dyn` for messages with a trailing array.
+macro_rules! ke_msg {
+    ($name:ident($fields:ident)) => {
+        pub struct $name($fields);
+
+        impl $name {
+            pub fn new(
+                _src_id: crate::platform::core_modules::ke::task::KeTaskId,
+                _dest_id: crate::platform::core_modules::ke::task::KeTaskId,
+            ) -> Self {
+                Self($fields::default())
+            }
+
+            pub fn fields(&mut self) -> &mut $fields {
+                &mut self.0
+            }
+
+            pub fn send(self) {}
+        }
+    };
+    (dyn $name:ident($fields:ident)) => {
+        pub struct $name<const N: u16>($fields);
+
+        impl<const N: u16> $name<N> {
+            pub fn new(
+                _src_id: crate::platform::core_modules::ke::task::KeTaskId,
+                _dest_id: crate::platform::core_modules::ke::task::KeTaskId,
+            ) -> Self {
+                Self($fields::default())
+            }
+
+            pub fn fields(&mut self) -> &mut $fields {
+                &mut self.0
+            }
+
+            pub fn send(self) {}
+        }
+    };
+}
+
+pub mod bindings {
+    use core::ffi::c_void;
+
+    use crate::{
+        app_modules::{
+            AppDeviceName, AppSecBondDataEnvTag, BdbRemoveType, BdbSearchByType,
+            ProcessEventResponse,
+        },
+        ble_stack::host::{
+            att::attm::AttmDesc128,
+            gap::{
+                gapc::task::{
+                    GapcBondReqInd, GapcConnectionReqInd, GapcEncryptReqInd, GapcParamUpdateCfm,
+                    GapcParamUpdateReqInd, GapcSetDevInfoReqInd,
+                },
+                GapRalDevInfo, GapSecKey, GapSlvPref,
+            },
+        },
+        platform::core_modules::{
+            common::BDAddr,
+            ke::{
+                msg::{KeMsgHandler, KeMsgId, KeMsgStatusTag},
+                task::KeTaskId,
+            },
+        },
+    };
+
+    pub type attm_desc_128 = AttmDesc128;
+
+    pub const SPI_FLASH_OP_SE: u32 = 0x20;
+    pub const I2C_NO_ERROR: u32 = 0;
+    pub const RESET_AFTER_SUOTA_UPDATE: u32 = 0xAA;
+
+    pub unsafe extern "C" fn app_std_process_event(
+        _msg_id: KeMsgId,
+        _param: *const c_void,
+        _src_id: KeTaskId,
+        _dest_id: KeTaskId,
+        _msg_ret: *mut KeMsgStatusTag,
+        _handlers: *const KeMsgHandler,
+        _handler_num: i32,
+    ) -> ProcessEventResponse {
+        unimplemented!()
+    }
+
+    pub unsafe extern "C" fn app_custs1_create_db() {
+        unimplemented!()
+    }
+
+    pub unsafe extern "C" fn app_suotar_start() {
+        unimplemented!()
+    }
+
+    pub unsafe extern "C" fn app_suotar_stop() {
+        unimplemented!()
+    }
+
+    pub unsafe extern "C" fn spi_flash_block_erase(_addr: u32, _op: u32) -> i8 {
+        unimplemented!()
+    }
+
+    pub unsafe extern "C" fn spi_flash_write_data(
+        _wr_data_ptr: *mut u8,
+        _address: u32,
+        _size: u32,
+        _actual_size: *mut u32,
+    ) -> i8 {
+        unimplemented!()
+    }
+
+    pub unsafe extern "C" fn i2c_eeprom_write_data(
+        _wr_data_ptr: *mut u8,
+        _address: u32,
+        _size: u32,
+        _bytes_written: *mut u32,
+    ) -> u32 {
+        unimplemented!()
+    }
+
+    pub unsafe extern "C" fn platform_reset(_reason: u32) {
+        unimplemented!()
+    }
+
+    pub unsafe extern "C" fn default_app_on_connection(_: u8, _: *const GapcConnectionReqInd) {
+        unimplemented!()
+    }
+
+    pub unsafe extern "C" fn default_app_on_set_dev_config_complete() {
+        unimplemented!()
+    }
+
+    pub unsafe extern "C" fn default_app_on_db_init_complete() {
+        unimplemented!()
+    }
+
+    pub unsafe extern "C" fn default_app_on_get_dev_name(_: *mut AppDeviceName) {
+        unimplemented!()
+    }
+
+    pub unsafe extern "C" fn default_app_on_get_dev_appearance(_: *mut u16) {
+        unimplemented!()
+    }
+
+    pub unsafe extern "C" fn default_app_on_get_dev_slv_pref_params(_: *mut GapSlvPref) {
+        unimplemented!()
+    }
+
+    pub unsafe extern "C" fn default_app_on_set_dev_info(
+        _: *const GapcSetDevInfoReqInd,
+        _: *mut u8,
+    ) {
+        unimplemented!()
+    }
+
+    pub unsafe extern "C" fn default_app_update_params_request(
+        _: *const GapcParamUpdateReqInd,
+        _: *mut GapcParamUpdateCfm,
+    ) {
+        unimplemented!()
+    }
+
+    pub unsafe extern "C" fn default_app_generate_static_random_addr(_: *mut BDAddr) {
+        unimplemented!()
+    }
+
+    pub unsafe extern "C" fn default_app_on_pairing_request(_: u8, _: *const GapcBondReqInd) {
+        unimplemented!()
+    }
+
+    pub unsafe extern "C" fn default_app_on_tk_exch(_: u8, _: *const GapcBondReqInd) {
+        unimplemented!()
+    }
+
+    pub unsafe extern "C" fn default_app_on_csrk_exch(_: u8, _: *const GapcBondReqInd) {
+        unimplemented!()
+    }
+
+    pub unsafe extern "C" fn default_app_on_ltk_exch(_: u8, _: *const GapcBondReqInd) {
+        unimplemented!()
+    }
+
+    pub unsafe extern "C" fn default_app_on_encrypt_req_ind(_: u8, _: *const GapcEncryptReqInd) {
+        unimplemented!()
+    }
+
+    pub unsafe extern "C" fn default_app_bdb_init() {
+        unimplemented!()
+    }
+
+    pub unsafe extern "C" fn default_app_bdb_get_size() -> u8 {
+        unimplemented!()
+    }
+
+    pub unsafe extern "C" fn default_app_bdb_add_entry(_: *mut AppSecBondDataEnvTag) {
+        unimplemented!()
+    }
+
+    pub unsafe extern "C" fn default_app_bdb_remove_entry(
+        _: BdbSearchByType,
+        _: BdbRemoveType,
+        _: *mut c_void,
+        _: u8,
+    ) {
+        unimplemented!()
+    }
+
+    pub unsafe extern "C" fn default_app_bdb_search_entry(
+        _: BdbSearchByType,
+        _: *mut c_void,
+        _: u8,
+    ) -> *const AppSecBondDataEnvTag {
+        unimplemented!()
+    }
+
+    pub unsafe extern "C" fn default_app_bdb_get_number_of_stored_irks() -> u8 {
+        unimplemented!()
+    }
+
+    pub unsafe extern "C" fn default_app_bdb_get_stored_irks(_: *mut GapSecKey) -> u8 {
+        unimplemented!()
+    }
+
+    pub unsafe extern "C" fn default_app_bdb_get_device_info_from_slot(
+        _: u8,
+        _: *mut GapRalDevInfo,
+    ) -> bool {
+        unimplemented!()
+    }
+}
+
+pub mod app_modules {
+    use core::ffi::c_void;
+
+    use crate::{
+        ble_stack::host::gap::{
+            gapc::task::{
+                GapcBondReqInd, GapcConnectionReqInd, GapcDisconnectInd, GapcEncryptReqInd,
+                GapcLePktSizeInd, GapcParamUpdateCfm, GapcParamUpdateReqInd, GapcPeerFeaturesInd,
+                GapcSetDevInfoReqInd,
+            },
+            gapm::task::{GapmAddrSolvedInd, GapmAdvReportInd, GapmCmpEvt},
+            GapRalDevInfo, GapSecKey, GapSlvPref,
+        },
+        platform::core_modules::{common::BDAddr, rwip::KeApiId},
+    };
+
+    pub type ProcessEventResponse = u32;
+    pub type BdbSearchByType = u32;
+    pub type BdbRemoveType = u32;
+
+    pub const DEF_ADV_FOREVER: u32 = 0;
+    pub const DEF_ADV_WITH_TIMEOUT: u32 = 1;
+    pub const DEF_SEC_REQ_NEVER: u32 = 0;
+    pub const DEF_SEC_REQ_ON_CONNECT: u32 = 1;
+    pub const APP_CFG_ADDR_PUB: u32 = 0;
+    pub const APP_CFG_ADDR_STATIC: u32 = 1;
+    pub const APP_CFG_HOST_PRIV_RPA: u32 = 2;
+    pub const APP_CFG_HOST_PRIV_NRPA: u32 = 3;
+    pub const APP_CFG_CNTL_PRIV_RPA_PUB: u32 = 4;
+    pub const APP_CFG_CNTL_PRIV_RPA_RAND: u32 = 5;
+    pub const APP_BOND_DB_MAX_BONDED_PEERS: u32 = 5;
+
+    pub fn app_env_get_conidx(conidx: u8) -> u8 {
+        conidx
+    }
+
+    pub fn get_user_prf_srv_perm(_task_id: KeApiId) -> u32 {
+        0
+    }
+
+    #[repr(C)]
+    pub struct AppDeviceName {
+        pub length: u8,
+        pub name: [u8; 32],
+    }
+
+    #[repr(C)]
+    pub struct AppSecBondDataEnvTag {
+        pub valid: bool,
+        pub peer_addr: BDAddr,
+        pub irk: GapSecKey,
+    }
+
+    #[repr(C)]
+    pub struct AppCallbacks {
+        pub app_on_connection: Option<unsafe extern "C" fn(u8, *const GapcConnectionReqInd)>,
+        pub app_on_disconnect: Option<unsafe extern "C" fn(*const GapcDisconnectInd)>,
+        pub app_on_update_params_rejected: Option<unsafe extern "C" fn(u8)>,
+        pub app_on_update_params_complete: Option<unsafe extern "C" fn()>,
+        pub app_on_set_dev_config_complete: Option<unsafe extern "C" fn()>,
+        pub app_on_adv_nonconn_complete: Option<unsafe extern "C" fn(u8)>,
+        pub app_on_adv_undirect_complete: Option<unsafe extern "C" fn(u8)>,
+        pub app_on_adv_direct_complete: Option<unsafe extern "C" fn(u8)>,
+        pub app_on_db_init_complete: Option<unsafe extern "C" fn()>,
+        pub app_on_scanning_completed: Option<unsafe extern "C" fn(u8)>,
+        pub app_on_adv_report_ind: Option<unsafe extern "C" fn(*const GapmAdvReportInd)>,
+        pub app_on_connect_failed: Option<unsafe extern "C" fn()>,
+        pub app_on_get_dev_name: Option<unsafe extern "C" fn(*mut AppDeviceName)>,
+        pub app_on_get_dev_appearance: Option<unsafe extern "C" fn(*mut u16)>,
+        pub app_on_get_dev_slv_pref_params: Option<unsafe extern "C" fn(*mut GapSlvPref)>,
+        pub app_on_set_dev_info:
+            Option<unsafe extern "C" fn(*const GapcSetDevInfoReqInd, *mut u8)>,
+        pub app_on_data_length_change: Option<unsafe extern "C" fn(u8, *const GapcLePktSizeInd)>,
+        pub app_on_update_params_request:
+            Option<unsafe extern "C" fn(*const GapcParamUpdateReqInd, *mut GapcParamUpdateCfm)>,
+        pub app_on_generate_static_random_addr: Option<unsafe extern "C" fn(*mut BDAddr)>,
+        pub app_on_svc_changed_cfg_ind: Option<unsafe extern "C" fn(u8, u16)>,
+        pub app_on_get_peer_features:
+            Option<unsafe extern "C" fn(u8, *const GapcPeerFeaturesInd)>,
+        #[cfg(feature = "ble-app-sec")]
+        pub app_on_pairing_request: Option<unsafe extern "C" fn(u8, *const GapcBondReqInd)>,
+        #[cfg(feature = "ble-app-sec")]
+        pub app_on_tk_exch: Option<unsafe extern "C" fn(u8, *const GapcBondReqInd)>,
+        #[cfg(feature = "ble-app-sec")]
+        pub app_on_irk_exch: Option<unsafe extern "C" fn(*const GapcBondReqInd)>,
+        #[cfg(feature = "ble-app-sec")]
+        pub app_on_csrk_exch: Option<unsafe extern "C" fn(u8, *const GapcBondReqInd)>,
+        #[cfg(feature = "ble-app-sec")]
+        pub app_on_ltk_exch: Option<unsafe extern "C" fn(u8, *const GapcBondReqInd)>,
+        #[cfg(feature = "ble-app-sec")]
+        pub app_on_pairing_succeeded: Option<unsafe extern "C" fn(u8)>,
+        #[cfg(feature = "ble-app-sec")]
+        pub app_on_encrypt_ind: Option<unsafe extern "C" fn(u8, u8)>,
+        #[cfg(feature = "ble-app-sec")]
+        pub app_on_encrypt_req_ind: Option<unsafe extern "C" fn(u8, *const GapcEncryptReqInd)>,
+        #[cfg(feature = "ble-app-sec")]
+        pub app_on_security_req_ind: Option<unsafe extern "C" fn(u8)>,
+        #[cfg(feature = "ble-app-sec")]
+        pub app_on_addr_solved_ind: Option<unsafe extern "C" fn(u8, *const GapmAddrSolvedInd)>,
+        #[cfg(feature = "ble-app-sec")]
+        pub app_on_addr_resolve_failed: Option<unsafe extern "C" fn(u8)>,
+        #[cfg(feature = "ble-app-ral")]
+        pub app_on_ral_cmp_evt: Option<unsafe extern "C" fn(*const GapmCmpEvt)>,
+        #[cfg(feature = "ble-app-ral")]
+        pub app_on_ral_size_ind: Option<unsafe extern "C" fn(u8)>,
+        #[cfg(feature = "ble-app-ral")]
+        pub app_on_ral_addr_ind: Option<unsafe extern "C" fn(u8, *mut c_void)>,
+    }
+
+    #[repr(C)]
+    pub struct AppBondDbCallbacks {
+        pub app_bdb_init: Option<unsafe extern "C" fn()>,
+        pub app_bdb_get_size: Option<unsafe extern "C" fn() -> u8>,
+        pub app_bdb_add_entry: Option<unsafe extern "C" fn(*mut AppSecBondDataEnvTag)>,
+        pub app_bdb_remove_entry:
+            Option<unsafe extern "C" fn(BdbSearchByType, BdbRemoveType, *mut c_void, u8)>,
+        pub app_bdb_search_entry: Option<
+            unsafe extern "C" fn(BdbSearchByType, *mut c_void, u8) -> *const AppSecBondDataEnvTag,
+        >,
+        pub app_bdb_get_number_of_stored_irks: Option<unsafe extern "C" fn() -> u8>,
+        pub app_bdb_get_stored_irks: Option<unsafe extern "C" fn(*mut GapSecKey) -> u8>,
+        pub app_bdb_get_device_info_from_slot:
+            Option<unsafe extern "C" fn(u8, *mut GapRalDevInfo) -> bool>,
+    }
+
+    #[repr(C)]
+    pub struct DefaultHandlersConfiguration {
+        pub adv_scenario: u32,
+        pub advertise_period: u32,
+        pub security_request_scenario: u32,
+    }
+
+    #[repr(C)]
+    pub struct AdvertiseConfiguration {
+        pub intv_min: u16,
+        pub intv_max: u16,
+        pub channel_map: u8,
+        pub mode: u8,
+        pub adv_filt_policy: u8,
+        pub peer_addr: [u8; 6],
+        pub peer_addr_type: u8,
+    }
+
+    #[repr(C)]
+    pub struct ConnectionParamConfiguration {
+        pub intv_min: u16,
+        pub intv_max: u16,
+        pub latency: u16,
+        pub time_out: u16,
+        pub ce_len_min: u16,
+        pub ce_len_max: u16,
+    }
+
+    #[repr(C)]
+    pub struct GapmConfiguration {
+        pub role: u8,
+        pub max_mtu: u16,
+        pub addr_type: u8,
+        pub renew_dur: u16,
+        pub addr: BDAddr,
+        pub irk: GapSecKey,
+        pub att_cfg: u8,
+        pub gap_start_hdl: u16,
+        pub gatt_start_hdl: u16,
+        pub max_mps: u16,
+        pub max_txoctets: u16,
+        pub max_txtime: u16,
+        pub priv1_2: u8,
+    }
+
+    pub mod app_custs {
+        use crate::{bindings::attm_desc_128, platform::core_modules::rwip::KeApiId};
+
+        #[repr(C)]
+        pub struct CustPrfFuncCallbacks {
+            pub task_id: KeApiId,
+            pub att_db: *const attm_desc_128,
+            pub max_nb_att: u8,
+            pub db_create_func: Option<unsafe extern "C" fn()>,
+            pub enable_func: Option<unsafe extern "C" fn(u8)>,
+            pub init_func: Option<unsafe extern "C" fn()>,
+            pub value_wr_validation_func:
+                Option<unsafe extern "C" fn(u16, bool, u16, u16, *const u8) -> u8>,
+        }
+
+        unsafe impl Sync for CustPrfFuncCallbacks {}
+    }
+}
+
+pub mod platform {
+    pub mod arch {
+        pub type SleepMode = u32;
+        pub type ArchMainLoopCallbackRet = u32;
+
+        pub const GOTO_SLEEP: ArchMainLoopCallbackRet = 0;
+        pub const KEEP_POWERED: ArchMainLoopCallbackRet = 1;
+
+        #[repr(C)]
+        pub struct ArchMainLoopCallbacks {
+            pub app_on_init: Option<unsafe extern "C" fn()>,
+            pub app_on_ble_powered: Option<unsafe extern "C" fn() -> ArchMainLoopCallbackRet>,
+            pub app_on_system_powered: Option<unsafe extern "C" fn() -> ArchMainLoopCallbackRet>,
+            pub app_before_sleep: Option<unsafe extern "C" fn()>,
+            pub app_validate_sleep: Option<unsafe extern "C" fn(SleepMode) -> SleepMode>,
+            pub app_going_to_sleep: Option<unsafe extern "C" fn(SleepMode)>,
+            pub app_resume_from_sleep: Option<unsafe extern "C" fn()>,
+        }
+    }
+
+    pub mod core_modules {
+        pub mod common {
+            pub const ADDR_PUBLIC: u32 = 0;
+            pub const ADDR_RAND: u32 = 1;
+            pub const ADV_ALLOW_SCAN_ANY_CON_ANY: u32 = 0;
+            pub const ADV_ALLOW_SCAN_WLST_CON_ANY: u32 = 1;
+            pub const ADV_ALLOW_SCAN_ANY_CON_WLST: u32 = 2;
+            pub const ADV_ALLOW_SCAN_WLST_CON_WLST: u32 = 3;
+
+            #[repr(C)]
+            #[derive(Default)]
+            pub struct BDAddr {
+                pub addr: [u8; 6],
+            }
+        }
+
+        pub mod ke {
+            pub mod msg {
+                use core::ffi::c_void;
+
+                use super::task::KeTaskId;
+
+                pub type KeMsgId = u16;
+                pub type KeMsgStatusTag = u32;
+
+                pub const KE_MSG_CONSUMED: u32 = 0;
+
+                #[repr(C)]
+                pub struct KeMsgHandler {
+                    pub id: KeMsgId,
+                    pub func:
+                        Option<unsafe extern "C" fn(KeMsgId, *const c_void, KeTaskId, KeTaskId) -> i32>,
+                }
+            }
+
+            pub mod task {
+                pub type KeTaskId = u16;
+            }
+        }
+
+        pub mod rwip {
+            use super::ke::task::KeTaskId;
+
+            pub type KeApiId = u32;
+
+            pub const TASK_APP: KeTaskId = 0;
+            pub const TASK_CUSTS1: KeTaskId = 1;
+            pub const TASK_GAPM: KeTaskId = 2;
+            pub const TASK_ID_DISS: KeApiId = 20;
+            pub const TASK_ID_BASS: KeApiId = 22;
+            pub const TASK_ID_SUOTAR: KeApiId = 54;
+            pub const TASK_ID_CUSTS1: KeApiId = 60;
+            pub const TASK_ID_INVALID: KeApiId = 0xFF;
+        }
+    }
+}
+
+pub mod ble_stack {
+    pub mod host {
+        pub mod att {
+            pub const ATT_UUID_16_LEN: u32 = 2;
+            pub const ATT_DECL_PRIMARY_SERVICE: u16 = 0x2800;
+            pub const ATT_DECL_CHARACTERISTIC: u16 = 0x2803;
+            pub const ATT_DESC_CHAR_USER_DESCRIPTION: u16 = 0x2901;
+            pub const ATT_DESC_CLIENT_CHAR_CFG: u16 = 0x2902;
+
+            pub mod attm {
+                #[repr(C)]
+                pub struct AttmDesc128 {
+                    pub uuid: *const u8,
+                    pub uuid_size: u8,
+                    pub perm: u32,
+                    pub max_length: u16,
+                    pub length: u16,
+                    pub value: *const u8,
+                }
+
+                unsafe impl Sync for AttmDesc128 {}
+            }
+        }
+
+        pub mod gap {
+            pub const GAP_ROLE_OBSERVER: u32 = 0x01;
+            pub const GAP_ROLE_BROADCASTER: u32 = 0x02;
+            pub const GAP_ROLE_CENTRAL: u32 = 0x05;
+            pub const GAP_ROLE_PERIPHERAL: u32 = 0x0A;
+            pub const GAP_ROLE_ALL: u32 = 0x0F;
+
+            #[repr(C)]
+            #[derive(Default)]
+            pub struct GapSecKey {
+                pub key: [u8; 16],
+            }
+
+            #[repr(C)]
+            pub struct GapSlvPref {
+                pub con_intv_min: u16,
+                pub con_intv_max: u16,
+                pub slave_latency: u16,
+                pub conn_timeout: u16,
+            }
+
+            #[repr(C)]
+            pub struct GapRalDevInfo {
+                pub addr_type: u8,
+                pub addr: [u8; 6],
+                pub peer_irk: [u8; 16],
+                pub local_irk: [u8; 16],
+            }
+
+            pub mod gapc {
+                pub mod task {
+                    use crate::platform::core_modules::common::BDAddr;
+
+                    #[repr(C)]
+                    pub struct GapcConnectionReqInd {
+                        pub conhdl: u16,
+                        pub con_interval: u16,
+                        pub con_latency: u16,
+                        pub sup_to: u16,
+                        pub peer_addr_type: u8,
+                        pub peer_addr: BDAddr,
+                    }
+
+                    #[repr(C)]
+                    pub struct GapcDisconnectInd {
+                        pub conhdl: u16,
+                        pub reason: u8,
+                    }
+
+                    #[repr(C)]
+                    pub struct GapcSetDevInfoReqInd {
+                        pub req: u8,
+                    }
+
+                    #[repr(C)]
+                    pub struct GapcLePktSizeInd {
+                        pub max_tx_octets: u16,
+                        pub max_tx_time: u16,
+                        pub max_rx_octets: u16,
+                        pub max_rx_time: u16,
+                    }
+
+                    #[repr(C)]
+                    pub struct GapcParamUpdateReqInd {
+                        pub intv_min: u16,
+                        pub intv_max: u16,
+                        pub latency: u16,
+                        pub time_out: u16,
+                    }
+
+                    #[repr(C)]
+                    pub struct GapcParamUpdateCfm {
+                        pub accept: bool,
+                        pub ce_len_min: u16,
+                        pub ce_len_max: u16,
+                    }
+
+                    #[repr(C)]
+                    pub struct GapcPeerFeaturesInd {
+                        pub features: [u8; 8],
+                    }
+
+                    #[repr(C)]
+                    pub struct GapcBondReqInd {
+                        pub request: u8,
+                    }
+
+                    #[repr(C)]
+                    pub struct GapcEncryptReqInd {
+                        pub ediv: u16,
+                        pub rand_nb: [u8; 8],
+                    }
+                }
+            }
+
+            pub mod gapm {
+                pub const GAPM_MASK_ATT_SVC_CHG_EN: u32 = 0x20;
+
+                pub mod task {
+                    use crate::{ble_stack::host::gap::GapSecKey, platform::core_modules::common::BDAddr};
+
+                    pub const GAPM_PROFILE_TASK_ADD: u32 = 0x1B;
+                    pub const GAP_NON_DISCOVERABLE: u32 = 0;
+                    pub const GAP_GEN_DISCOVERABLE: u32 = 1;
+                    pub const GAP_LIM_DISCOVERABLE: u32 = 2;
+                    pub const GAP_BROADCASTER_MODE: u32 = 3;
+
+                    #[repr(C)]
+                    pub struct GapmCmpEvt {
+                        pub operation: u8,
+                        pub status: u8,
+                    }
+
+                    #[repr(C)]
+                    pub struct GapmAddrSolvedInd {
+                        pub addr: BDAddr,
+                        pub irk: GapSecKey,
+                    }
+
+                    #[repr(C)]
+                    pub struct GapmAdvReportInd {
+                        pub evt_type: u8,
+                        pub adv_addr_type: u8,
+                        pub adv_addr: BDAddr,
+                        pub data_len: u8,
+                        pub data: [u8; 31],
+                        pub rssi: i8,
+                    }
+
+                    #[derive(Default)]
+                    pub struct GapmProfileTaskAdd {
+                        pub operation: u8,
+                        pub sec_lvl: u8,
+                        pub prf_task_id: u16,
+                        pub app_task: u16,
+                        pub start_hdl: u16,
+                        pub param: crate::Value,
+                    }
+
+                    ke_msg!(dyn KeMsgDynGapmProfileTaskAdd(GapmProfileTaskAdd));
+                }
+            }
+        }
+    }
+
+    pub mod rwble_hl {
+        pub mod error {
+            #[repr(u8)]
+            pub enum HlErr {
+                ATT_ERR_NO_ERROR = 0x00,
+                ATT_ERR_INVALID_HANDLE = 0x01,
+                ATT_ERR_READ_NOT_PERMITTED = 0x02,
+                ATT_ERR_WRITE_NOT_PERMITTED = 0x03,
+                ATT_ERR_INSUFF_AUTHEN = 0x05,
+                ATT_ERR_INVALID_OFFSET = 0x07,
+                ATT_ERR_INSUFF_AUTHOR = 0x08,
+                ATT_ERR_INVALID_ATTRIBUTE_VAL_LEN = 0x0D,
+                ATT_ERR_INSUFF_ENC = 0x0F,
+                ATT_ERR_APP_ERROR = 0x80,
+            }
+        }
+    }
+
+    pub mod profiles {
+        use crate::platform::core_modules::ke::task::KeTaskId;
+
+        pub const PRF_CLI_STOP_NTFIND: u32 = 0;
+
+        #[repr(C)]
+        #[derive(Default)]
+        pub struct PrfCharPresFmt {
+            pub unit: u16,
+            pub description: u16,
+            pub format: u8,
+            pub exponent: i8,
+            pub name_space: u8,
+        }
+
+        pub fn prf_get_task_from_id(task_id: u16) -> KeTaskId {
+            task_id
+        }
+
+        pub mod bas {
+            pub mod bass {
+                use crate::ble_stack::profiles::PrfCharPresFmt;
+
+                pub const BAS_BATT_LVL_NTF_NOT_SUP: u32 = 0;
+                pub const BAS_BATT_LVL_NTF_SUP: u32 = 1;
+
+                #[repr(C)]
+                #[derive(Default)]
+                pub struct BassDbCfg {
+                    pub bas_nb: u8,
+                    pub features: [u8; 2],
+                    pub batt_level_pres_format: [PrfCharPresFmt; 2],
+                }
+
+                pub mod task {
+                    pub const BASS_ENABLE_RSP: u32 = 0x2201;
+                    pub const BASS_BATT_LEVEL_UPD_RSP: u32 = 0x2203;
+                    pub const BASS_BATT_LEVEL_NTF_CFG_IND: u32 = 0x2204;
+
+                    #[derive(Default)]
+                    pub struct BassEnableReq {
+                        pub conidx: u8,
+                        pub ntf_cfg: u8,
+                        pub old_batt_lvl: [u8; 2],
+                    }
+
+                    #[derive(Default)]
+                    pub struct BassBattLevelUpdReq {
+                        pub bas_instance: u8,
+                        pub batt_level: u8,
+                    }
+
+                    pub struct BassBattLevelNtfCfgInd {
+                        pub conidx: u8,
+                        pub ntf_cfg: u8,
+                    }
+
+                    ke_msg!(KeMsgBassEnableReq(BassEnableReq));
+                    ke_msg!(KeMsgBassBattLevelUpdReq(BassBattLevelUpdReq));
+                }
+            }
+        }
+
+        pub mod dis {
+            pub mod diss {
+                pub const DIS_MANUFACTURER_NAME_CHAR: u32 = 0;
+                pub const DIS_MODEL_NB_STR_CHAR: u32 = 1;
+                pub const DIS_SERIAL_NB_STR_CHAR: u32 = 2;
+                pub const DIS_HARD_REV_STR_CHAR: u32 = 3;
+                pub const DIS_FIRM_REV_STR_CHAR: u32 = 4;
+                pub const DIS_SW_REV_STR_CHAR: u32 = 5;
+                pub const DIS_SYSTEM_ID_CHAR: u32 = 6;
+                pub const DIS_IEEE_CHAR: u32 = 7;
+                pub const DIS_PNP_ID_CHAR: u32 = 8;
+                pub const DIS_MANUFACTURER_NAME_CHAR_SUP: u32 = 0x0001;
+                pub const DIS_MODEL_NB_STR_CHAR_SUP: u32 = 0x0002;
+                pub const DIS_SERIAL_NB_STR_CHAR_SUP: u32 = 0x0004;
+                pub const DIS_HARD_REV_STR_CHAR_CHAR_SUP: u32 = 0x0008;
+                pub const DIS_FIRM_REV_STR_CHAR_SUP: u32 = 0x0010;
+                pub const DIS_SW_REV_STR_CHAR_SUP: u32 = 0x0020;
+                pub const DIS_SYSTEM_ID_CHAR_SUP: u32 = 0x0040;
+                pub const DIS_IEEE_CHAR_CHAR_SUP: u32 = 0x0080;
+                pub const DIS_PNP_ID_CHAR_SUP: u32 = 0x0100;
+
+                #[repr(C)]
+                #[derive(Default)]
+                pub struct DissDbCfg {
+                    pub features: u16,
+                }
+
+                pub mod task {
+                    pub const DISS_SET_VALUE_RSP: u32 = 0x1401;
+                    pub const DISS_VALUE_REQ_IND: u32 = 0x1402;
+
+                    pub struct DissValueReqInd {
+                        pub value: u8,
+                    }
+
+                    pub struct DissSetValueRsp {
+                        pub value: u8,
+                        pub status: u8,
+                    }
+
+                    #[derive(Default)]
+                    pub struct DissValueCfm {
+                        pub value: u8,
+                        pub length: u8,
+                        pub data: crate::Value,
+                    }
+
+                    ke_msg!(dyn KeMsgDynDissValueCfm(DissValueCfm));
+                }
+            }
+        }
+
+        pub mod suota {
+            pub mod suotar {
+                pub const SUOTAR_SPI_SUP: u32 = 0x01;
+                pub const SUOTAR_I2C_SUP: u32 = 0x02;
+                pub const SUOTAR_IMG_I2C_EEPROM: u32 = 0x12;
+                pub const SUOTAR_IMG_SPI_FLASH: u32 = 0x13;
+                pub const SUOTAR_MEM_SERVICE_EXIT: u32 = 0xFD;
+                pub const SUOTAR_IMG_END: u32 = 0xFE;
+                pub const SUOTAR_REBOOT: u32 = 0xFF;
+                pub const SUOTAR_SRV_STARTED: u32 = 0x01;
+                pub const SUOTAR_CMP_OK: u32 = 0x02;
+                pub const SUOTAR_SRV_EXIT: u32 = 0x03;
+                pub const SUOTAR_CRC_ERR: u32 = 0x04;
+                pub const SUOTAR_PATCH_LEN_ERR: u32 = 0x05;
+                pub const SUOTAR_EXT_MEM_WRITE_ERR: u32 = 0x06;
+                pub const SUOTAR_INVAL_MEM_TYPE: u32 = 0x09;
+                pub const SUOTAR_IMG_STARTED: u32 = 0x10;
+
+                #[repr(C)]
+                #[derive(Default)]
+                pub struct SuotarDbCfg {
+                    pub features: u8,
+                }
+
+                pub mod task {
+                    pub const SUOTAR_PATCH_MEM_DEV_IND: u32 = 0x3601;
+                    pub const SUOTAR_GPIO_MAP_IND: u32 = 0x3602;
+                    pub const SUOTAR_PATCH_LEN_IND: u32 = 0x3603;
+                    pub const SUOTAR_PATCH_DATA_IND: u32 = 0x3604;
+
+                    pub struct SuotarPatchMemDevInd {
+                        pub conidx: u8,
+                        pub mem_dev: u32,
+                    }
+
+                    pub struct SuotarPatchLenInd {
+                        pub conidx: u8,
+                        pub len: u16,
+                    }
+
+                    pub struct SuotarPatchDataInd {
+                        pub conidx: u8,
+                        pub len: u8,
+                        pub pd: crate::Value,
+                    }
+
+                    #[derive(Default)]
+                    pub struct SuotarStatusUpdateReq {
+                        pub conidx: u8,
+                        pub status: u8,
+                    }
+
+                    ke_msg!(KeMsgSuotarStatusUpdateReq(SuotarStatusUpdateReq));
+                }
+            }
+        }
+
+        pub mod custom {
+            pub mod custs {
+                use crate::{
+                    app_modules::app_custs::CustPrfFuncCallbacks,
+                    ble_stack::host::att::attm::AttmDesc128, platform::core_modules::rwip::KeApiId,
+                };
+
+                #[repr(C)]
+                pub struct RomCustPrfCfg {
+                    pub custs1_services: *const u8,
+                    pub custs1_services_size: *const u8,
+                    pub custs1_att_db: *mut AttmDesc128,
+                    pub custs_get_func_callbacks:
+                        Option<unsafe extern "C" fn(KeApiId) -> *const CustPrfFuncCallbacks>,
+                }
+
+                unsafe impl Sync for RomCustPrfCfg {}
+
+                pub mod custs1 {
+                    use crate::ble_stack::host::att::attm::AttmDesc128;
+
+                    #[repr(C)]
+                    pub struct Custs1DbCfg {
+                        pub att_tbl: *const AttmDesc128,
+                        pub cfg_flag: u8,
+                        pub max_nb_att: u16,
+                    }
+
+                    pub mod task {
+                        pub const CUSTS1_VAL_WRITE_IND: u32 = 0;
+                        pub const CUSTS1_VALUE_REQ_IND: u32 = 1;
+                        pub const CUSTS1_ATT_INFO_REQ: u32 = 2;
+
+                        pub struct Custs1ValWriteInd {
+                            pub conidx: u8,
+                            pub handle: u16,
+                            pub length: u16,
+                            pub value: crate::Value,
+                        }
+
+                        pub struct Custs1ValueReqInd {
+                            pub conidx: u8,
+                            pub att_idx: u16,
+                        }
+
+                        pub struct Custs1AttInfoReq {
+                            pub conidx: u8,
+                            pub att_idx: u16,
+                        }
+
+                        #[derive(Default)]
+                        pub struct Custs1ValueReqRsp {
+                            pub conidx: u8,
+                            pub att_idx: u16,
+                            pub length: u16,
+                            pub status: u8,
+                            pub value: crate::Value,
+                        }
+
+                        #[derive(Default)]
+                        pub struct Custs1AttInfoRsp {
+                            pub conidx: u8,
+                            pub att_idx: u16,
+                            pub length: u16,
+                            pub status: u8,
+                        }
+
+                        #[derive(Default)]
+                        pub struct Custs1ValNtfIndReq {
+                            pub conidx: u8,
+                            pub notification: bool,
+                            pub handle: u16,
+                            pub length: u16,
+                            pub value: crate::Value,
+                        }
+
+                        ke_msg!(dyn KeMsgDynCusts1ValueReqRsp(Custs1ValueReqRsp));
+                        ke_msg!(dyn KeMsgDynCusts1ValNtfReq(Custs1ValNtfIndReq));
+                        ke_msg!(dyn KeMsgDynCusts1ValIndReq(Custs1ValNtfIndReq));
+                        ke_msg!(KeMsgCusts1AttInfoRsp(Custs1AttInfoRsp));
+
+                        pub type KeMsgCusts1ValueReqRsp = KeMsgDynCusts1ValueReqRsp<0>;
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,9 @@
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    // Expansions of every macro, built against the stand-in of the SDK in tests/support. They
+    // only check that the generated code is well-formed and resolves, not that it agrees with
+    // the real SDK.
+    t.pass("tests/pass/*.rs");
+    t.compile_fail("tests/ui/*.rs");
+}
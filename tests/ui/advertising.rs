@@ -0,0 +1,24 @@
+use da14531_sdk_macros::configure_advertising;
+
+configure_advertising! {
+    advertise_data: {
+        flags: 0x100,
+        complete_name: b"Tag",
+        uuid16_list: [],
+        uuid128_list: ["0000fe59-0000-1000-8000", 0x1234],
+        manufacturer_data: 0x0A7A,
+        appearance: -1,
+        tx_power: 128,
+        raw: "data",
+        apearance: 0x0200,
+    },
+    scan_response_data: {
+        manufacturer_data: (0x0A7A, "data"),
+        uuid16_list: 0x180F,
+        raw: [DATA, 1],
+        short_name: "Tag",
+        short_name: "Tag",
+    },
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use da14531_sdk_macros::configure_advertising_params;
+
+configure_advertising_params! {
+    channel_map: [37, 37],
+}
+
+configure_advertising_params! {
+    channel_map: 0,
+}
+
+configure_advertising_params! {
+    channel_map: 0x08,
+}
+
+fn main() {}
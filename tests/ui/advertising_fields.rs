@@ -0,0 +1,9 @@
+use da14531_sdk_macros::configure_advertising;
+
+configure_advertising! {
+    advertise_data: {},
+    advertise_data: {},
+    scan_data: {},
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use da14531_sdk_macros::configure_advertising;
+
+configure_advertising! {
+    advertise_data: {
+        complete_name: "A device name that does not fit",
+    },
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use da14531_sdk_macros::configure_advertising_params;
+
+configure_advertising_params! {
+    mode: GeneralDiscoverble,
+    interval_min: 500ms,
+    interval_max: 100ms,
+    channel_map: [37, 40],
+    filter_policy: AllowAll,
+    peer_addr: [0x01; 6],
+    peer_addr_type: Private,
+}
+
+fn main() {}
@@ -0,0 +1,22 @@
+use da14531_sdk_macros::register_app_callbacks;
+
+register_app_callbacks! {
+    app_on_conection: crate::ble::on_connection,
+    app_on_disconnect: [],
+    app_on_update_params_rejected: [crate::ble::a, [crate::ble::b]],
+    app_on_adv_undirect_complete: { crate::ble::on_adv_done(); },
+    app_on_connect_failed: 1,
+    #[after_default]
+    app_on_scanning_completed: crate::ble::on_scan_done,
+    #[before]
+    app_on_db_init_complete: crate::ble::on_db_init,
+    #[before_default(now)]
+    app_on_set_dev_config_complete: crate::ble::on_config,
+    #[before_default]
+    #[after_default]
+    app_on_connection: crate::ble::on_connection,
+    app_on_connection: crate::ble::on_connection,
+    0: crate::ble::on_connection,
+}
+
+fn main() {}
@@ -0,0 +1,28 @@
+use da14531_sdk_macros::configure_battery_service;
+
+configure_battery_service! {
+    battery_level: [],
+    notification: 1,
+    on_notification_config: || {},
+    notifications: true,
+}
+
+configure_battery_service! {
+    battery_level: [crate::bat::main, crate::bat::aux, crate::bat::backup],
+    notification: "yes",
+}
+
+configure_battery_service! {
+    battery_level: [crate::bat::main, 1],
+    on_notification_config: crate::bat::on_ntf_cfg,
+}
+
+configure_battery_service! {
+    battery_level: 100,
+}
+
+configure_battery_service! {
+    notification: true,
+}
+
+fn main() {}
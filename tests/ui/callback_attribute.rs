@@ -0,0 +1,9 @@
+use da14531_sdk_macros::{app_callback, main_loop_callback};
+
+#[app_callback(on_conection)]
+fn on_connection() {}
+
+#[main_loop_callback(on_idle)]
+fn on_idle() {}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use da14531_sdk_macros::configure_connection_params;
+
+configure_connection_params! {
+    interval_min: 50ms,
+    interval_max: 30ms,
+    latency: 500,
+    timeout: 50ms,
+    ce_len_min: 10ms,
+    ce_len_max: 5ms,
+    peer_requests: Ignore,
+    interval: 7.5ms,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+use da14531_sdk_macros::configure_connection_params;
+
+configure_connection_params! {
+    interval_min: 50ms,
+}
+
+fn main() {}
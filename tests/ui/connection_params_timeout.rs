@@ -0,0 +1,9 @@
+use da14531_sdk_macros::configure_connection_params;
+
+configure_connection_params! {
+    interval_max: 100ms,
+    latency: 4,
+    timeout: 1s,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use da14531_sdk_macros::configure_connection_params;
+
+configure_connection_params! {
+    crate = "da14531_sdk",
+    latency: 0,
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use da14531_sdk_macros::configure_custom_server1_service;
+
+configure_custom_server1_service! {
+    CONTROL: {
+        uuid: 0xFE00,
+        characteristics: {
+            STATUS: {
+                uuid: 0xFE01,
+                permissions: { READ_ENABLED },
+                length: 1,
+            },
+        },
+    },
+}
+
+fn main() {}
@@ -0,0 +1,45 @@
+use da14531_sdk_macros::configure_custom_server1_service;
+
+configure_custom_server1_service! {
+    CONTROL: {
+        uuid: 0xFE00,
+        characteristics: {
+            MULTIPLE_READ: {
+                uuid: 0xFE01,
+                permissions: { READ_ENABLED | READ_AUTH },
+                length: 1,
+            },
+            MULTIPLE_WRITE: {
+                uuid: 0xFE02,
+                permissions: { WRITE_UNAUTH | WRITE_SECURE | WRITE_COMMAND_ACCEPTED },
+                length: 1,
+            },
+            MULTIPLE_INDICATION: {
+                uuid: 0xFE03,
+                permissions: { INDICATION_ENABLED | INDICATION_SECURE },
+                length: 1,
+            },
+            MULTIPLE_NOTIFICATION: {
+                uuid: 0xFE04,
+                permissions: { NOTIFICATION_AUTH | NOTIFICATION_UNAUTH },
+                length: 1,
+            },
+            UNKNOWN_FLAG: {
+                uuid: 0xFE05,
+                permissions: { READ_ENABLED | BROADCAST },
+                length: 1,
+            },
+            NOT_FLAGS: {
+                uuid: 0xFE06,
+                permissions: 0x01,
+                length: 1,
+            },
+            MISSING: {
+                uuid: 0xFE07,
+                length: 1,
+            },
+        },
+    },
+}
+
+fn main() {}
@@ -0,0 +1,45 @@
+use da14531_sdk_macros::configure_custom_server1_service;
+
+configure_custom_server1_service! {
+    start_handle: 0,
+    NOT_A_SERVICE: 0xFE00,
+    MISSING_UUID: {
+        characteristics: {},
+    },
+    MISSING_CHARACTERISTICS: {
+        uuid: 0xFE10,
+    },
+    INVALID: {
+        uuid: "FE20",
+        advertise: 1,
+        characteristics: 0xFE21,
+    },
+    CONTROL: {
+        uuid: 0xFE30,
+        adverstise: true,
+        characteristics: {
+            NOT_A_CHARACTERISTIC: 0xFE31,
+            MISSING_FIELDS: {
+                permissions: { WRITE_ENABLED },
+            },
+            INVALID: {
+                uuid: "FE33",
+                permissions: { READ_ENABLED | WRITE_ENABLED },
+                length: "1",
+                user_description: 1,
+                read_handler: "on_read",
+                write_handler: 1,
+            },
+            DUPLICATE: {
+                uuid: 0xFE34,
+                uuid: 0xFE35,
+                permissions: { WRITE_ENABLED },
+                length: 1,
+                write_handler: on_write,
+                write_handle: on_write,
+            },
+        },
+    },
+}
+
+fn main() {}
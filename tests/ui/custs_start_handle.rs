@@ -0,0 +1,11 @@
+use da14531_sdk_macros::configure_custom_server1_service;
+
+configure_custom_server1_service! {
+    start_handle: 0x1_0000,
+    CONTROL: {
+        uuid: 0xFE00,
+        characteristics: {},
+    },
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+use da14531_sdk_macros::configure_custom_server1_service;
+
+configure_custom_server1_service! {
+    start_handle: 0xFFFE,
+    CONTROL: {
+        uuid: 0xFE00,
+        characteristics: {
+            MODE: {
+                uuid: 0xFE01,
+                permissions: { WRITE_ENABLED },
+                length: 1,
+                write_handler: on_mode,
+            },
+        },
+    },
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+use da14531_sdk_macros::configure_custom_server1_service;
+
+configure_custom_server1_service! {
+    CONTROL: &1,
+}
+
+fn main() {}
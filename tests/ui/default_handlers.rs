@@ -0,0 +1,14 @@
+use da14531_sdk_macros::default_handlers_configuration;
+
+default_handlers_configuration! {
+    adv_scenario: AdvertiseForevr,
+    advertise_period: "30s",
+    security_request_scenario: 1 + 1,
+    advertise_periode: 30s,
+}
+
+default_handlers_configuration! {
+    advertise_period: 0s,
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use da14531_sdk_macros::configure_device_information_service;
+
+configure_device_information_service! {
+    manufacturer_name: "ACME",
+    manufacturer_name: "ACME",
+    model_nb_str: 1,
+    serial_nb: "1234",
+    handlers: {
+        DISS_VALUE_REQ_IND => crate::dis::on_value_req,
+        DISS_SET_VALUE_RSP => crate::dis::on_set_value_rsp,
+        DISS_SET_VALUE_RSP => crate::dis::on_set_value_rsp,
+    },
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use da14531_sdk_macros::configure_advertising;
+
+mod ble {
+    da14531_sdk_macros::configure_advertising! {
+        advertise_data: { complete_name: "first" },
+    }
+}
+
+configure_advertising! {
+    advertise_data: { complete_name: "second" },
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use da14531_sdk_macros::configure_connection_params;
+
+configure_connection_params! {
+    interval_min: 15min,
+    interval_max: 7.5,
+    timeout: 1.0000005s,
+    ce_len_min: "1ms",
+    ce_len_max: 1e30s,
+}
+
+fn main() {}
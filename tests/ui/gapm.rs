@@ -0,0 +1,25 @@
+use da14531_sdk_macros::configure_gapm;
+
+configure_gapm! {
+    role: Peripheal,
+    max_mtu: 600,
+    addr_type: Random,
+    renew_dur: 20min,
+    addr: [0x01, 0x02, 0x03],
+    irk: "key",
+    att_cfg: GAPM_MASK_ATT_SVC_CHG_EN | flags(),
+    gap_start_hdl: -1,
+    gatt_start_hdl: 0x1_0000,
+    max_txoctets: 300,
+    max_txtime: 10us,
+    privacy: 1,
+    privacy: false,
+    max_mu: 23,
+    0: 1,
+}
+
+configure_gapm! {
+    att_cfg: 0x100,
+}
+
+fn main() {}
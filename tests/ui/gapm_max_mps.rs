@@ -0,0 +1,8 @@
+use da14531_sdk_macros::configure_gapm;
+
+configure_gapm! {
+    max_mtu: 23,
+    max_mps: 247,
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+use da14531_sdk_macros::register_profile;
+
+register_profile! {
+    name: crate::findt,
+    task_id: 1,
+    db_cfg: FindtDbCfg,
+    db_cfg: FindtDbCfg,
+    db_cfg_init: "init",
+    start_handle: "1",
+    task: TASK_ID_FINDT,
+    handlers: {
+        FINDT_ALERT_IND => crate::ble::on_alert,
+        FINDT_ALERT_IND => crate::ble::on_alert,
+    },
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use da14531_sdk_macros::register_profile;
+
+register_profile! {
+    task_id: TASK_ID_FINDT,
+    db_cfg: FindtDbCfg,
+}
+
+fn main() {}
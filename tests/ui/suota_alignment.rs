@@ -0,0 +1,9 @@
+use da14531_sdk_macros::configure_suota_service;
+
+configure_suota_service! {
+    patch_memory: SpiFlash,
+    patch_region: 0x20100..0x38000,
+    max_patch_len: 240,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use da14531_sdk_macros::configure_suota_service;
+
+configure_suota_service! {
+    patch_memory: I2cEeprom,
+    patch_region: 0x100..0x200,
+    max_patch_len: 512,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use da14531_sdk_macros::configure_suota_service;
+
+configure_suota_service! {
+    patch_region: 0x20000..0x38000,
+    max_patch_len: 240,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use da14531_sdk_macros::configure_suota_service;
+
+configure_suota_service! {
+    patch_memory: SpiFlash,
+    max_patch_len: 240,
+}
+
+fn main() {}
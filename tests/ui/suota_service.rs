@@ -0,0 +1,43 @@
+use da14531_sdk_macros::configure_suota_service;
+
+configure_suota_service! {
+    patch_memory: Flash,
+    patch_region: 0x20000,
+    max_patch_len: 0,
+    on_suota_start: || {},
+    on_suota_status: 1,
+    on_suota_finished: "done",
+    on_suota_end: crate::ota::on_end,
+}
+
+configure_suota_service! {
+    patch_memory: I2cEeprom,
+    patch_region: 0x38000..0x20000,
+    max_patch_len: "240",
+}
+
+configure_suota_service! {
+    patch_memory: I2cEeprom,
+    patch_region: ..0x38000,
+    max_patch_len: 240,
+}
+
+configure_suota_service! {
+    patch_memory: I2cEeprom,
+    patch_region: 0x20000..,
+    max_patch_len: 240,
+}
+
+configure_suota_service! {
+    patch_memory: I2cEeprom,
+    patch_region: 0x20000..0x1_0000_0000,
+    max_patch_len: 240,
+}
+
+configure_suota_service! {
+    patch_memory: I2cEeprom,
+    patch_region: 0x20000..=0xFFFF_FFFF,
+    max_patch_len: 240,
+}
+
+fn main() {}
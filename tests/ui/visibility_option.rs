@@ -0,0 +1,22 @@
+use da14531_sdk_macros::{
+    configure_connection_params, configure_device_information_service, register_profile,
+};
+
+configure_connection_params! {
+    visibility: 0,
+    latency: 0,
+}
+
+configure_device_information_service! {
+    visibility: pub,
+    manufacturer_name: "Rapitag",
+}
+
+register_profile! {
+    visibility: pub(crate),
+    name: findt,
+    task_id: TASK_ID_FINDT,
+    db_cfg: FindtDbCfg,
+}
+
+fn main() {}